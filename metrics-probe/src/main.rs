@@ -377,6 +377,18 @@ fn print_scenario_diff(cur: &Scenario, base: Option<&Scenario>) {
         base.and_then(|b| b.change_frame_allocs.map(|v| v as i64)),
         cur.change_frame_allocs.map(|v| v as i64),
     );
+    if let Some(p) = cur.page {
+        irow(
+            "page_nodes",
+            base.and_then(|b| b.page).map(|p| p.total as i64),
+            p.total as i64,
+        );
+        irow(
+            "page_heap_bytes",
+            base.and_then(|b| b.page).map(|p| p.heap_bytes as i64),
+            p.heap_bytes as i64,
+        );
+    }
     if let Some(l) = cur.layout {
         irow(
             "layout_visits",
@@ -423,6 +435,19 @@ fn print_snapshot(snap: &Snapshot) {
                 .map(|v| v.to_string())
                 .unwrap_or("n/a".into()),
         );
+        if let Some(p) = s.page {
+            println!(
+                "    page: nodes={} (sig {}, memo {}, eff {}, probe {}, stored \
+                 {}) heap~{}B",
+                p.total,
+                p.signals,
+                p.memos,
+                p.effects,
+                p.probes,
+                p.stored,
+                p.heap_bytes,
+            );
+        }
     }
     for sz in &snap.section_sizes {
         println!(
@@ -70,6 +70,22 @@ fn profile_counts() -> crate::snapshot::NodeCounts {
     }
 }
 
+fn page_counts(
+    ui: &mut UI<NullWtf, WithPages>,
+) -> crate::snapshot::ScopeCounts {
+    let p = ui.current_page().profile();
+    crate::snapshot::ScopeCounts {
+        stored: p.stored,
+        signals: p.signals,
+        effects: p.effects,
+        memos: p.memos,
+        computed: p.computed,
+        probes: p.probes,
+        total: p.total(),
+        heap_bytes: p.heap_bytes,
+    }
+}
+
 /// Pure-reactive scenario: one outer observe + `n` child observes over `n`
 /// signals — the shape of a page's redraw gate, with zero UI/render code. This
 /// is the "reactive-only bin" whose thumbv6m `.text` baseline is ~16.8 KiB.
@@ -123,6 +139,7 @@ fn reactive_only(n: usize) -> Scenario {
             idle_frame_allocs: Some(idle),
             change_frame_allocs: Some(change),
             layout: None,
+            page: None,
         }
     })
 }
@@ -160,6 +177,7 @@ fn ui_scenario(
 
         // Measure node population / heap in the steady state, after first paint.
         let counts = profile_counts();
+        let page = page_counts(&mut ui);
         // Saturating: a scenario that nets a free (base measured mid-churn)
         // must not underflow-panic and abort the whole recording.
         let heap_live_bytes = alloc::live().saturating_sub(base_live);
@@ -203,6 +221,7 @@ fn ui_scenario(
             idle_frame_allocs: idle,
             change_frame_allocs: change,
             layout,
+            page: Some(page),
         }
     })
 }
@@ -333,6 +352,29 @@ mod tests {
             Some(0),
             "ui_labels_10 idle frame must be allocation-free"
         );
+        // The page scope owns 25 of the 49: not the label signals (created
+        // by the test before the page), the arena or the element render
+        // probes (created on first paint, outside the scope).
+        let page = ui10.page.expect("page counts of a page scenario");
+        assert_eq!(page.total, 25, "ui_labels_10 page scope total moved");
+        assert_eq!(page.probes, 1, "the page render probe");
+        assert!(page.heap_bytes > 0);
+    }
+
+    // The 10-label page must fit the default `fixed-capacity` storage: an
+    // overflowed node would have a dead handle and the page would not render.
+    // Runs only with `--features fixed-capacity` and no `RSACT_NODES` set.
+    #[cfg(feature = "fixed-capacity")]
+    #[test]
+    fn ui_labels_fit_default_capacity() {
+        use rsact_reactive::slab::{DEFAULT_NODES, NODES};
+
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        assert_eq!(NODES, DEFAULT_NODES, "built with RSACT_NODES set");
+        let ui10 = ui_labels(10);
+        assert_eq!(ui10.counts.total, 49, "ui_labels_10 node total moved");
+        assert!(ui10.counts.total <= NODES);
+        assert_eq!(ui10.idle_frame_allocs, Some(0));
     }
 
     // WS0.5 layout-counter baseline. Locks the whole-tree relayout cost of a
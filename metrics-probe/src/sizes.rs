@@ -14,14 +14,16 @@ use std::{path::PathBuf, process::Command};
 /// Floor pair: Blue Pill (thumbv7m) + the thumbv6m compile-only baseline. Add
 /// thumbv7em-none-eabihf (Black Pill) here when its budgets are wanted.
 const TARGETS: &[&str] = &["thumbv7m-none-eabi", "thumbv6m-none-eabi"];
-const BINS: &[&str] = &["reactive", "ui"];
+/// `(binary, size-probe features)`.
+const BINS: &[(&str, &str)] =
+    &[("reactive", ""), ("ui", ""), ("reactive-fixed", "fixed-capacity")];
 const TARGET_DIR: &str = "target/size-probe";
 
 pub fn measure_all() -> Vec<SectionSizes> {
     let mut out = Vec::new();
     for &target in TARGETS {
-        for &bin in BINS {
-            match build_and_read(target, bin) {
+        for &(bin, features) in BINS {
+            match build_and_read(target, bin, features) {
                 Ok(s) => {
                     println!(
                         "  size {bin}/{target}: .text={} .rodata={} .bss={}",
@@ -36,7 +38,11 @@ pub fn measure_all() -> Vec<SectionSizes> {
     out
 }
 
-fn build_and_read(target: &str, bin: &str) -> Result<SectionSizes, String> {
+fn build_and_read(
+    target: &str,
+    bin: &str,
+    features: &str,
+) -> Result<SectionSizes, String> {
     let status = Command::new("cargo")
         .args([
             "build",
@@ -49,6 +55,8 @@ fn build_and_read(target: &str, bin: &str) -> Result<SectionSizes, String> {
             bin,
             "--target-dir",
             TARGET_DIR,
+            "--features",
+            features,
         ])
         .status()
         .map_err(|e| format!("spawn cargo: {e}"))?;
@@ -38,6 +38,23 @@ pub struct NodeCounts {
     pub values_vacant: usize,
 }
 
+/// Nodes owned by a scope (child scopes and owned nodes included), by kind,
+/// from `ScopeHandle::profile()`.
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScopeCounts {
+    pub stored: usize,
+    pub signals: usize,
+    pub effects: usize,
+    pub memos: usize,
+    pub computed: usize,
+    pub probes: usize,
+    pub total: usize,
+    /// Approximate heap bytes of the nodes' values and closures, not counting
+    /// what the values point to.
+    pub heap_bytes: usize,
+}
+
 /// Layout-pass work counters (WS0.5), populated when built with the
 /// `rsact-ui/layout-counters` feature.
 #[derive(Clone, Copy, Default, Serialize, Deserialize)]
@@ -63,6 +80,9 @@ pub struct Scenario {
     pub idle_frame_allocs: Option<usize>,
     pub change_frame_allocs: Option<usize>,
     pub layout: Option<LayoutCounters>,
+    /// Nodes of the page scope, after first paint. `None` for scenarios
+    /// without a page.
+    pub page: Option<ScopeCounts>,
 }
 
 /// `.text/.rodata/.bss` for one measured binary on one target (Layer 2).
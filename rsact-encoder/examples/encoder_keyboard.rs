@@ -1,69 +1,63 @@
+use embedded_graphics::{pixelcolor::Rgb888, prelude::Dimensions};
 use embedded_graphics_simulator::{
     OutputSettingsBuilder, SimulatorDisplay, Window,
 };
-use rsact_encoder::widget::encoder_keyboard::EncoderKeyboard;
+use rsact_encoder::prelude::*;
+use rsact_reactive::prelude::*;
 use rsact_ui::{
-    embedded_graphics::{pixelcolor::Rgb888, prelude::Dimensions as _},
     event::simulator::simulator_single_encoder,
     page::id::SinglePage,
-    prelude::{
-        Button, Container, Flex, IntoInert as _, Length, MonoText, Scrollable,
-        SignalMap, Size, create_signal,
-    },
-    render::draw_target::{AntiAliasing, LayeringRendererOptions},
+    prelude::{Flex, Label, Size, View},
+    render::eg::renderer::EGRenderer,
+    style::theme::Theme,
     ui::UI,
-    widget::{SizedWidget, Widget as _},
+    widget::{BlockModelWidget, SizedWidget},
 };
 
 fn main() {
     let output_settings = OutputSettingsBuilder::new().scale(3).build();
 
-    let mut window = Window::new("SANDBOX", &output_settings);
+    let mut window = Window::new("ENCODER KEYBOARD", &output_settings);
 
     let mut display =
-        SimulatorDisplay::<Rgb888>::new(Size::new(480, 270).into());
+        SimulatorDisplay::<Rgb888>::new(Size::new(160, 80).into());
 
     window.update(&display);
 
     let value = create_signal(String::new());
-
-    let page = Flex::row([
-        Container::new(MonoText::new(
-            value.map(|value| format!("Value: {value}")),
-        ))
-        .center()
-        .height(40u32)
-        .fill_width()
-        .el(),
-        EncoderKeyboard::col(value).fill().el(),
-        // Scrollable::horizontal(Flex::row(
-        //     ('a'..='z')
-        //         .map(|char| Button::new(char).on_click(move || {}).el())
-        //         .collect::<Vec<_>>(),
-        // ))
-        // .fill_width()
-        // .el(),
-    ])
-    .fill();
-
-    let mut ui = UI::new(display.bounding_box().size.inert(), NullStyler)
-        .auto_focus()
-        .with_page(SinglePage, page.el())
-        .with_renderer_options(
-            LayeringRendererOptions::new().anti_aliasing(AntiAliasing::Enabled),
-        )
-        .on_exit(|| std::process::exit(0));
+    let mut entered = create_signal(String::new());
+
+    let page = move || {
+        Flex::col([
+            Label::new(value.map(|value| format!("Value: {value}"))).into_el(),
+            Label::new(entered.map(|entered| format!("Entered: {entered}")))
+                .into_el(),
+            EncoderKeyboard::new(value)
+                .on_enter(move || entered.set(value.get_cloned()))
+                .into_el(),
+        ])
+        .gap(5u32)
+        .padding(5u32)
+        .fill()
+    };
+
+    let mut ui = UI::new(
+        Theme::default(),
+        EGRenderer::new(display.bounding_box().size.into()),
+    )
+    .auto_focus()
+    .on_exit(|| std::process::exit(0))
+    .with_page(SinglePage, page);
 
     loop {
         ui.tick(
             window
                 .events()
-                .map(simulator_single_encoder)
-                .filter_map(|e| e)
+                .filter_map(simulator_single_encoder)
                 .inspect(|e| println!("Event: {e:?}")),
         );
+        ui.render(&mut display);
 
-        ui.draw(&mut display);
         window.update(&display);
     }
 }
@@ -1,370 +1,701 @@
-// use core::marker::PhantomData;
-// use layout::{flex::flex_content_size, size::RectangleExt};
-// use rsact_icons::system::SystemIcon;
-// use rsact_ui::{
-//     embedded_graphics::prelude::{Point, Transform},
-//     prelude::*,
-//     render::Renderable,
-// };
-
-// // pub trait EncoderKeyboardEvent {
-// //     fn as_encoder_keyboard_select(&self) -> Option<i32>;
-// // }
-
-// #[derive(Debug, Clone, Copy, PartialEq)]
-// pub struct EncoderKeyboardState {
-//     pressed: bool,
-//     active: bool,
-//     // TODO: Merge with active?
-//     selected: usize,
-// }
-
-// impl EncoderKeyboardState {
-//     fn none() -> Self {
-//         Self { pressed: false, active: false, selected: 0 }
-//     }
-// }
-
-// declare_widget_style! {
-//     EncoderKeyboardStyle (EncoderKeyboardState) {
-//         container: container,
-//         selected: container {
-//             selected_background_color: background_color,
-//             selected_border_color: border_color,
-//             selected_border_radius: border_radius,
-//         },
-//     }
-// }
-
-// impl<C: Color> EncoderKeyboardStyle<C> {
-//     pub fn base() -> Self {
-//         Self {
-//             container: BlockStyle::base(),
-//             selected: BlockStyle::base().border(
-//                 BorderStyle::base().radius(5).color(C::default_foreground()),
-//             ),
-//         }
-//     }
-// }
-
-// // pub enum SelectKeyboardBtn {
-// //     Push(char),
-// //     Pop,
-// //     Enter,
-// // }
-
-// // pub trait Alphabet {
-// //     type Key: PartialEq;
-
-// //     fn to_string(keys: Vec<Self::Key>) -> String;
-
-// //     // fn buttons<W: WidgetCtx>() -> impl Iterator<Item = (char, Button<W>)>;
-// //     fn buttons() -> impl Iterator<Item = &'static str>;
-// // }
-
-// pub enum EncoderKeyboardKind {
-//     Char(char),
-//     Delete,
-//     Done,
-//     // TODO: CapsLock
-// }
-
-// pub struct EncoderKeyboardKey<W: WidgetCtx> {
-//     el: El<W>,
-//     kind: EncoderKeyboardKind,
-// }
-
-// impl<W: WidgetCtx> EncoderKeyboardKey<W>
-// where
-//     W::Styler: WidgetStylist<MonoTextStyle<W::Color>>
-//         + WidgetStylist<IconStyle<W::Color>>,
-// {
-//     fn new(inner: El<W>, kind: EncoderKeyboardKind) -> Self {
-//         Self { el: Container::new(inner).padding(5u32).el(), kind }
-//     }
-
-//     fn char(char: char) -> Self {
-//         Self::new(MonoText::new(char).el(), EncoderKeyboardKind::Char(char))
-//     }
-
-//     fn delete() -> Self {
-//         Self::new(
-//             Icon::new(SystemIcon::Cancel).el(),
-//             EncoderKeyboardKind::Delete,
-//         )
-//     }
-
-//     fn done() -> Self {
-//         Self::new(Icon::new(SystemIcon::Check).el(), EncoderKeyboardKind::Done)
-//     }
-// }
-
-// /**
-//  * Keyboard for use with encoder to input text.
-//  *
-//  * Looks something like this:
-//  * `a b c [d] e ... x ✓`
-//  * Where `[d]` is a selected key. `✓` exits edit mode and `x` deletes last character.
-//  *
-//  * Capabilities and logic:
-//  * - Single encoder + button control.
-//  * - On pressing focused EncoderKeyboard, it enters edit mode.
-//  * - On key press, it is added to the value string
-//  * - On `x` press, the last character is deleted from string.
-//  * - The only way to exit edit mode is to press `✓` button.
-//  *
-//  */
-// pub struct EncoderKeyboard<W: WidgetCtx, Dir: Direction> {
-//     id: ElId,
-//     layout: Layout,
-//     keys: Vec<EncoderKeyboardKey<W>>,
-//     value: Signal<String>,
-//     state: Signal<EncoderKeyboardState>,
-//     style: MemoChain<EncoderKeyboardStyle<W::Color>>,
-//     dir: PhantomData<Dir>,
-// }
-
-// impl<W: WidgetCtx> EncoderKeyboard<W, RowDir>
-// where
-//     W::Styler: WidgetStylist<MonoTextStyle<W::Color>>
-//         + WidgetStylist<IconStyle<W::Color>>,
-// {
-//     pub fn row(value: Signal<String>) -> Self {
-//         Self::new(value)
-//     }
-// }
-
-// impl<W: WidgetCtx> EncoderKeyboard<W, ColDir>
-// where
-//     W::Styler: WidgetStylist<MonoTextStyle<W::Color>>
-//         + WidgetStylist<IconStyle<W::Color>>,
-// {
-//     pub fn col(value: Signal<String>) -> Self {
-//         Self::new(value)
-//     }
-// }
-
-// impl<W: WidgetCtx, Dir: Direction + 'static> EncoderKeyboard<W, Dir>
-// where
-//     W::Styler: WidgetStylist<MonoTextStyle<W::Color>>
-//         + WidgetStylist<IconStyle<W::Color>>,
-// {
-//     pub fn new(value: Signal<String>) -> Self {
-//         let state = EncoderKeyboardState::none().signal();
-//         let style = EncoderKeyboardStyle::base().memo_chain();
-
-//         let mut keys = ('a'..='z')
-//             .map(|char| EncoderKeyboardKey::char(char))
-//             .collect::<Vec<_>>();
-
-//         keys.push(EncoderKeyboardKey::delete());
-//         keys.push(EncoderKeyboardKey::done());
-
-//         let layout = Layout::shrink(LayoutKind::Flex(
-//             FlexLayout::base(
-//                 Dir::AXIS,
-//                 flex_content_size(Dir::AXIS, keys.iter().map(|key| &key.el))
-//                     .inert()
-//                     .memo(),
-//             )
-//             .block_model(BlockModel::zero().padding(2)),
-//         ))
-//         .signal();
-
-//         Self {
-//             id: ElId::unique(),
-//             layout,
-//             keys,
-//             value,
-//             state,
-//             style,
-//             dir: PhantomData,
-//         }
-//     }
-
-//     pub fn style(
-//         self,
-//         styler: impl Fn(
-//                 EncoderKeyboardStyle<W::Color>,
-//                 EncoderKeyboardState,
-//             ) -> EncoderKeyboardStyle<W::Color>
-//             + 'static,
-//     ) -> Self {
-//         let state = self.state;
-//         self.style.last(move |base| styler(*base, state.get())).unwrap();
-//         self
-//     }
-// }
-
-// impl<W: WidgetCtx, Dir: Direction> SizedWidget<W> for EncoderKeyboard<W, Dir> where
-//     W::Styler: WidgetStylist<EncoderKeyboardStyle<W::Color>>
-// {
-// }
-
-// impl<W: WidgetCtx, Dir: Direction> BlockModelWidget<W>
-//     for EncoderKeyboard<W, Dir>
-// where
-//     W::Styler: WidgetStylist<EncoderKeyboardStyle<W::Color>>,
-// {
-// }
-
-// impl<W: WidgetCtx, Dir: Direction> Widget<W> for EncoderKeyboard<W, Dir>
-// where
-//     W::Styler: WidgetStylist<EncoderKeyboardStyle<W::Color>>,
-// {
-//     fn meta(&self) -> rsact_ui::widget::MetaTree {
-//         MetaTree::childless(Meta::focusable(self.id).inert())
-//     }
-
-//     fn on_mount(&mut self, ctx: rsact_ui::widget::MountCtx<W>) {
-//         ctx.accept_styles(self.style, self.state);
-//     }
-
-//     fn layout(&self) -> Layout {
-//         self.layout
-//     }
-
-//     fn build_layout_tree(&self) -> MemoTree<Layout> {
-//         MemoTree {
-//             data: self.layout.memo(),
-//             children: self
-//                 .keys
-//                 .iter()
-//                 .map(|key| key.el.build_layout_tree())
-//                 .collect::<Vec<_>>()
-//                 .inert()
-//                 .memo(),
-//         }
-//     }
-
-//     fn draw(&self, ctx: &mut DrawCtx<'_, W>) -> DrawResult {
-//         let state = self.state.get();
-//         let style = self.style.get();
-
-//         if !state.active {
-//             ctx.draw_focus_outline(self.id)?;
-//         }
-
-//         let keys_offset = {
-//             let selected_key_layout =
-//                 ctx.layout.children().nth(state.selected).unwrap();
-
-//             let selected_key_offset =
-//                 ctx.layout.inner.center_offset_of(selected_key_layout.inner);
-
-//             if state.active {
-//                 Block::from_layout_style(
-//                     selected_key_layout.outer.translate(selected_key_offset),
-//                     BlockModel::zero().border_width(1),
-//                     style.selected,
-//                 )
-//                 .render(ctx.renderer)?;
-//             }
-
-//             selected_key_offset
-//         };
-
-//         ctx.renderer.clipped(ctx.layout.inner, |renderer| {
-//             self.keys.iter().zip(ctx.layout.children()).try_for_each(
-//                 |(key, key_layout)| {
-//                     let mut ctx = DrawCtx {
-//                         state: ctx.state,
-//                         renderer,
-//                         layout: &key_layout.translate(keys_offset),
-//                         tree_style: ctx.tree_style,
-//                     };
-
-//                     key.el.draw(&mut ctx)
-//                 },
-//             )
-//         })
-//     }
-
-//     fn on_event(&mut self, ctx: &mut EventCtx<'_, W>) -> EventResponse<W> {
-//         let state = self.state.get();
-
-//         if state.active && ctx.is_focused(self.id) {
-//             if let Some(offset) = ctx.event.as_focus_move() {
-//                 let current = state.selected;
-
-//                 let new = (current as i32 + offset)
-//                     .clamp(0, self.keys.len().saturating_sub(1) as i32)
-//                     as usize;
-
-//                 if new != current {
-//                     self.state.update(|state| {
-//                         state.selected = new;
-//                     });
-//                 }
-
-//                 return ctx.capture();
-//             }
-//         }
-
-//         ctx.handle_focusable(self.id, |ctx, pressed| {
-//             if state.pressed != pressed {
-//                 if state.active {
-//                     let key = &self.keys[state.selected];
-
-//                     match key.kind {
-//                         EncoderKeyboardKind::Char(char) => {
-//                             self.value.update(|value| value.push(char))
-//                         },
-//                         EncoderKeyboardKind::Delete => {
-//                             self.value.update(|value| {
-//                                 value.pop();
-//                             })
-//                         },
-//                         EncoderKeyboardKind::Done => {
-//                             self.state.update(|state| state.active = false);
-//                         },
-//                     }
-
-//                     ctx.capture()
-//                 } else if !state.pressed && pressed {
-//                     // Enter edit mode
-//                     self.state.update(|state| {
-//                         state.active = true;
-//                     });
-
-//                     ctx.capture()
-//                 } else {
-//                     ctx.ignore()
-//                 }
-//             } else {
-//                 ctx.ignore()
-//             }
-//         })
-//     }
-// }
-
-// // pub fn select_keyboard<Dir: Direction + 'static, A: Alphabet, W: WidgetCtx>(
-// //     value: Signal<String>,
-// // ) -> El<W>
-// // where
-// //     W::Styler: Styler<ButtonStyle<W::Color>, Class = ()>,
-// //     W::Event: ButtonEvent,
-// //     W::Styler: Styler<MonoTextStyle<W::Color>, Class = ()>,
-// // {
-// //     Flex::<W, Dir>::new(
-// //         A::buttons()
-// //             .map(|btn| {
-// //                 Button::new(MonoText::new(btn).el())
-// //                     .on_click(move || {
-// //                         value.update(|value| value.push_str(btn));
-// //                         None
-// //                     })
-// //                     .el()
-// //             })
-// //             .chain([Button::new("del").on_click(move || {
-// //                 value.update(|value| value.pop());
-// //                 None
-// //             }).el(), Button::new("enter").on_click(move || {
-// //                 value.update(|value| value.)
-// //               None
-// //             })].into_iter())
-// //             .collect::<Vec<_>>(),
-// //     )
-// //     .shrink()
-// //     .el()
-// // }
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+#[cfg(feature = "embedded-graphics")]
+use embedded_graphics::pixelcolor::BinaryColor;
+#[cfg(feature = "embedded-graphics")]
+use rsact_ui::style::theme::BinaryTheme;
+use rsact_ui::{
+    font::{FontCtx, TextOverflow},
+    layout::ContentLayout,
+    prelude::*,
+    render::{color::RgbColor, renderer::NullColor},
+};
+
+/// Space between a key's edges and its label.
+const KEY_PADDING: u32 = 2;
+
+/// Width of the border around the key strip.
+const BORDER_WIDTH: u32 = 1;
+
+/// Label of the space character, which draws as nothing.
+const SPACE_LABEL: &str = "spc";
+const SHIFT_LABEL: &str = "^";
+const BACKSPACE_LABEL: &str = "<";
+const ENTER_LABEL: &str = "OK";
+
+/// A set of characters an [`EncoderKeyboard`] types, one key per character.
+///
+/// The name labels the key that switches to the alphabet. Alphabets with
+/// lowercase characters get a shift key typing their uppercase versions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Alphabet {
+    name: &'static str,
+    chars: &'static str,
+}
+
+impl Alphabet {
+    pub const LETTERS: Self = Self::new("abc", "abcdefghijklmnopqrstuvwxyz");
+    pub const DIGITS: Self = Self::new("123", "0123456789");
+    pub const HEX: Self = Self::new("hex", "0123456789abcdef");
+    pub const SYMBOLS: Self = Self::new("#+=", " .,:;!?'\"-_+*/=()<>[]@#$%&");
+
+    pub const fn new(name: &'static str, chars: &'static str) -> Self {
+        Self { name, chars }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn chars(&self) -> &'static str {
+        self.chars
+    }
+
+    fn is_shiftable(&self) -> bool {
+        self.chars.chars().any(char::is_lowercase)
+    }
+}
+
+/// `char` typed by a shifted key: its uppercase version, unless that takes
+/// more than one character.
+fn shifted(char: char) -> char {
+    let mut upper = char.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) => upper,
+        _ => char,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Key {
+    Char(char),
+    Shift,
+    /// Switches to the next alphabet.
+    Switch,
+    Backspace,
+    Enter,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EncoderKeyboardState {
+    /// Whether the keyboard is in edit mode, taking rotation to select keys.
+    pub active: bool,
+    pub selected: usize,
+    pub shift: bool,
+    /// Index of the current alphabet.
+    pub alphabet: usize,
+}
+
+impl EncoderKeyboardState {
+    pub fn none() -> Self {
+        Self { active: false, selected: 0, shift: false, alphabet: 0 }
+    }
+}
+
+declare_widget_style! {
+    EncoderKeyboardStyle (EncoderKeyboardState) {
+        container: container,
+        selected: container {
+            selected_background_color: background_color,
+            selected_border_color: border_color,
+            selected_border_radius: border_radius,
+        },
+        selected_text_color: color {
+            transparent_selected_text_color: transparent,
+        },
+        text_color: color {
+            transparent_text_color: transparent,
+        },
+    }
+}
+
+impl<C: Color> EncoderKeyboardStyle<C> {
+    /// Keys are styled by a theme like the options of a [`Select`], so the
+    /// keyboard follows whichever theme the app uses.
+    ///
+    /// [`Select`]: rsact_ui::widget::select::Select
+    fn styled_as_select(
+        &self,
+        stylist: &impl Stylist<SelectStyle<C>>,
+        selector: &StyleSelector,
+    ) -> Self {
+        let select = stylist.style(
+            &SelectStyle {
+                container: self.container,
+                selected: self.selected,
+                selected_text_color: self.selected_text_color,
+                text_color: self.text_color,
+            },
+            selector,
+        );
+
+        Self {
+            container: select.container,
+            selected: select.selected,
+            selected_text_color: select.selected_text_color,
+            text_color: select.text_color,
+        }
+    }
+}
+
+impl<C: RgbColor> Stylist<EncoderKeyboardStyle<C>> for Theme<C> {
+    fn style(
+        &self,
+        base: &EncoderKeyboardStyle<C>,
+        selector: &StyleSelector,
+    ) -> EncoderKeyboardStyle<C> {
+        base.styled_as_select(self, selector)
+    }
+}
+
+#[cfg(feature = "embedded-graphics")]
+impl Stylist<EncoderKeyboardStyle<BinaryColor>> for BinaryTheme {
+    fn style(
+        &self,
+        base: &EncoderKeyboardStyle<BinaryColor>,
+        selector: &StyleSelector,
+    ) -> EncoderKeyboardStyle<BinaryColor> {
+        base.styled_as_select(self, selector)
+    }
+}
+
+impl Stylist<EncoderKeyboardStyle<NullColor>> for () {
+    fn style(
+        &self,
+        base: &EncoderKeyboardStyle<NullColor>,
+        selector: &StyleSelector,
+    ) -> EncoderKeyboardStyle<NullColor> {
+        base.styled_as_select(self, selector)
+    }
+}
+
+/// One line of key labels: gives the keyboard its height, the keys themselves
+/// are drawn by [`EncoderKeyboard`].
+#[derive(View)]
+struct KeyLine<W: WidgetCtx> {
+    layout: Layout,
+    ctx: PhantomData<W>,
+}
+
+impl<W: WidgetCtx + 'static> KeyLine<W> {
+    fn new() -> Self {
+        let layout = Layout::shrink(LayoutKind::Content(ContentLayout::text(
+            MaybeReactive::new_inert(String::from(ENTER_LABEL)),
+        )));
+
+        Self { layout, ctx: PhantomData }
+    }
+}
+
+impl<W: WidgetCtx + 'static> Widget<W> for KeyLine<W> {
+    fn debug_name(&self) -> &'static str {
+        "KeyLine"
+    }
+
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn render(&self, _ctx: RenderCtx<'_, W>) -> RenderResult {
+        Ok(())
+    }
+
+    fn on_event(&mut self, ctx: EventCtx<'_, W>) -> EventResponse {
+        ctx.ignore()
+    }
+}
+
+/// Keyboard for text entry with a single encoder and a button.
+///
+/// Draws a strip of keys centered on the selected one:
+/// `a b c [d] e ... ^ 123 < OK`, where `^` is shift, `123` switches to the
+/// next alphabet, `<` deletes the last character and `OK` is enter.
+///
+/// - Pressing the focused keyboard enters edit mode.
+/// - In edit mode rotation selects a key and a press types it or runs it.
+/// - Shift toggles uppercase for the alphabets that have it.
+/// - Enter, or `Exit`, leaves edit mode.
+///
+/// ```ignore
+/// let name = create_signal(String::new());
+/// EncoderKeyboard::new(name).alphabets([Alphabet::LETTERS, Alphabet::DIGITS])
+/// ```
+#[derive(View)]
+pub struct EncoderKeyboard<W: WidgetCtx>
+where
+    W::Stylist: Stylist<EncoderKeyboardStyle<W::Color>>,
+{
+    layout: Layout,
+    /// The key line, moved into the arena on build.
+    line: Option<El<W>>,
+    value: Signal<String>,
+    alphabets: Vec<Alphabet>,
+    state: Signal<EncoderKeyboardState>,
+    on_enter: Option<Box<dyn FnMut()>>,
+    style: WidgetStyleFn<EncoderKeyboardStyle<W::Color>>,
+}
+
+impl<W: WidgetCtx + 'static> EncoderKeyboard<W>
+where
+    W::Stylist: Stylist<EncoderKeyboardStyle<W::Color>>,
+{
+    /// Keyboard typing into `value` with letters, digits and symbols.
+    pub fn new(value: Signal<String>) -> Self {
+        let line = KeyLine::new().into_el();
+
+        let layout = Layout::shrink(LayoutKind::Container(
+            ContainerLayout::base(line.layout()).block_model(
+                BlockModel::zero()
+                    .border_width(BORDER_WIDTH)
+                    .padding(KEY_PADDING),
+            ),
+        ));
+
+        Self {
+            layout,
+            line: Some(line),
+            value,
+            alphabets: Vec::from([
+                Alphabet::LETTERS,
+                Alphabet::DIGITS,
+                Alphabet::SYMBOLS,
+            ]),
+            state: create_signal(EncoderKeyboardState::none()),
+            on_enter: None,
+            style: None,
+        }
+        .width_fill()
+    }
+
+    /// Alphabets to type from, the first one is shown first. An empty list
+    /// is ignored.
+    pub fn alphabets(
+        mut self,
+        alphabets: impl IntoIterator<Item = Alphabet>,
+    ) -> Self {
+        let alphabets = alphabets.into_iter().collect::<Vec<_>>();
+
+        if alphabets.is_empty() {
+            log::warn!("EncoderKeyboard needs at least one alphabet");
+            return self;
+        }
+
+        self.alphabets = alphabets;
+        self
+    }
+
+    /// Called when the enter key is pressed, after leaving edit mode.
+    pub fn on_enter(mut self, on_enter: impl FnMut() + 'static) -> Self {
+        self.on_enter = Some(Box::new(on_enter));
+        self
+    }
+
+    pub fn style(
+        mut self,
+        style: impl StyleFn<EncoderKeyboardStyle<W::Color>>,
+    ) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+
+    fn keys(&self, state: EncoderKeyboardState) -> impl Iterator<Item = Key> {
+        let alphabet = self.alphabets[state.alphabet];
+
+        alphabet
+            .chars
+            .chars()
+            .map(move |char| {
+                Key::Char(if state.shift { shifted(char) } else { char })
+            })
+            .chain(alphabet.is_shiftable().then_some(Key::Shift))
+            .chain((self.alphabets.len() > 1).then_some(Key::Switch))
+            .chain([Key::Backspace, Key::Enter])
+    }
+
+    fn label<'a>(
+        &self,
+        key: Key,
+        state: EncoderKeyboardState,
+        buf: &'a mut [u8; 4],
+    ) -> &'a str {
+        match key {
+            Key::Char(' ') => SPACE_LABEL,
+            Key::Char(char) => char.encode_utf8(buf),
+            Key::Shift => SHIFT_LABEL,
+            Key::Switch => {
+                self.alphabets[(state.alphabet + 1) % self.alphabets.len()].name
+            },
+            Key::Backspace => BACKSPACE_LABEL,
+            Key::Enter => ENTER_LABEL,
+        }
+    }
+
+    fn press_key(&mut self, state: EncoderKeyboardState) {
+        let Some(key) = self.keys(state).nth(state.selected) else {
+            return;
+        };
+
+        match key {
+            Key::Char(char) => self.value.update(|value| value.push(char)),
+            Key::Shift => self.state.update(|state| state.shift = !state.shift),
+            Key::Switch => {
+                let mut next = state;
+                next.alphabet = (state.alphabet + 1) % self.alphabets.len();
+                // Stay on the switch key to cycle through the alphabets.
+                next.selected = self
+                    .keys(next)
+                    .position(|key| key == Key::Switch)
+                    .unwrap_or(0);
+                self.state.set(next);
+            },
+            Key::Backspace => self.value.update(|value| {
+                value.pop();
+            }),
+            Key::Enter => {
+                self.state.update(|state| state.active = false);
+                if let Some(on_enter) = self.on_enter.as_mut() {
+                    on_enter();
+                }
+            },
+        }
+    }
+}
+
+impl<W: WidgetCtx + 'static> LayoutWidget<W> for EncoderKeyboard<W>
+where
+    W::Stylist: Stylist<EncoderKeyboardStyle<W::Color>>,
+{
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+impl<W: WidgetCtx + 'static> SizedWidget<W> for EncoderKeyboard<W> where
+    W::Stylist: Stylist<EncoderKeyboardStyle<W::Color>>
+{
+}
+impl<W: WidgetCtx + 'static> FontSettingWidget<W> for EncoderKeyboard<W> where
+    W::Stylist: Stylist<EncoderKeyboardStyle<W::Color>>
+{
+}
+
+impl<W: WidgetCtx + 'static> Widget<W> for EncoderKeyboard<W>
+where
+    W::Stylist: Stylist<EncoderKeyboardStyle<W::Color>>,
+{
+    fn flags(&self) -> WidgetFlags {
+        WidgetFlags::default().focusable()
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "EncoderKeyboard"
+    }
+
+    fn build(&mut self, mut ctx: BuildCtx<W>) {
+        if let Some(mut line) = self.line.take() {
+            ctx.set_single_child(&mut line);
+        }
+    }
+
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|mut ctx| {
+            let style = ctx.get_style(self.style.as_deref());
+            let state = self.state.get();
+
+            Block::from_layout_style(
+                ctx.layout.outer,
+                self.layout.with(|layout| layout.block_model()),
+                style.container,
+            )
+            .render(ctx.renderer)?;
+
+            let props = ctx.visual.font_props;
+            let font = props.font();
+            let props = props.resolve(ctx.shared.viewport.get());
+
+            let keys = self.keys(state).collect::<Vec<_>>();
+            let measure = |fonts: &FontCtx, key: Key| {
+                fonts.measure_text(
+                    font,
+                    self.label(key, state, &mut [0; 4]),
+                    props,
+                    TextOverflow::Clip,
+                )
+            };
+            let (widths, line_height) = ctx
+                .shared
+                .fonts
+                .try_with(|fonts| {
+                    keys.iter().fold(
+                        (Vec::with_capacity(keys.len()), 0),
+                        |(mut widths, height), &key| {
+                            let label = measure(fonts, key);
+                            widths.push(
+                                label.max_content_width + KEY_PADDING * 2,
+                            );
+                            (widths, height.max(label.line_height))
+                        },
+                    )
+                })
+                .unwrap_or_else(|| {
+                    log::error!(
+                        "keyboard measure skipped: font provider was disposed"
+                    );
+                    (Vec::new(), 0)
+                });
+
+            // The keys fill the padding box, the selected one in its middle.
+            let outer = ctx.layout.outer;
+            let strip = Rect::new(
+                outer.top_left
+                    + Point::new(BORDER_WIDTH as i32, BORDER_WIDTH as i32),
+                Size::new(
+                    outer.size.width.saturating_sub(BORDER_WIDTH * 2),
+                    outer.size.height.saturating_sub(BORDER_WIDTH * 2),
+                ),
+            );
+            let selected_start =
+                widths.iter().take(state.selected).sum::<u32>() as i32;
+            let selected_width =
+                widths.get(state.selected).copied().unwrap_or(0) as i32;
+            let mut x = strip.top_left.x + strip.size.width as i32 / 2
+                - selected_start
+                - selected_width / 2;
+
+            let text_color =
+                style.text_color.get().unwrap_or_else(W::default_foreground);
+            let selected_text_color = style
+                .selected_text_color
+                .get()
+                .unwrap_or_else(W::default_foreground);
+            let text_y = strip.top_left.y
+                + (strip.size.height.saturating_sub(line_height) / 2) as i32;
+
+            let fonts = ctx.shared.fonts;
+            ctx.renderer.clipped(strip, |renderer| {
+                for (index, (&key, &width)) in
+                    keys.iter().zip(&widths).enumerate()
+                {
+                    let bounds = Rect::new(
+                        Point::new(x, strip.top_left.y),
+                        Size::new(width, strip.size.height),
+                    );
+                    x += width as i32;
+
+                    let is_selected = state.active && index == state.selected;
+                    if is_selected {
+                        Block::from_layout_style(
+                            bounds,
+                            BlockModel::zero().border_width(1),
+                            style.selected,
+                        )
+                        .render(renderer)?;
+                    }
+
+                    let text = Rect::new(
+                        Point::new(
+                            bounds.top_left.x + KEY_PADDING as i32,
+                            text_y,
+                        ),
+                        Size::new(width - KEY_PADDING * 2, line_height),
+                    );
+                    let color = if is_selected {
+                        selected_text_color
+                    } else {
+                        text_color
+                    };
+                    fonts
+                        .try_with(|fonts| {
+                            fonts.render::<W>(
+                                font,
+                                self.label(key, state, &mut [0; 4]),
+                                props,
+                                text,
+                                color,
+                                renderer,
+                            )
+                        })
+                        .unwrap_or(Ok(()))?;
+                }
+
+                Ok(())
+            })?;
+
+            if !state.active {
+                ctx.render_focus_outline(ctx.id)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn on_event(&mut self, mut ctx: EventCtx<'_, W>) -> EventResponse {
+        let state = self.state.get();
+
+        if state.active && ctx.is_focused() {
+            if let Some(delta) = ctx.event.interpret_as_rotation() {
+                let last = self.keys(state).count().saturating_sub(1);
+                let selected = (state.selected as i64 + delta as i64)
+                    .clamp(0, last as i64)
+                    as usize;
+
+                if selected != state.selected {
+                    self.state.update(|state| state.selected = selected);
+                }
+
+                return ctx.capture();
+            }
+
+            if let Event::Exit = ctx.event {
+                self.state.update(|state| state.active = false);
+                return ctx.capture();
+            }
+        }
+
+        ctx.handle()?;
+        ctx.handle_click(|ctx| {
+            if state.active {
+                self.press_key(state);
+            } else {
+                self.state.update(|state| state.active = true);
+            }
+            ctx.capture()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alphabet, EncoderKeyboard};
+    use alloc::string::String;
+    use rsact_reactive::prelude::*;
+    use rsact_ui::{
+        event::{Event, MoveDir, PressEvent},
+        prelude::*,
+        test_support::focused_page,
+    };
+
+    const VIEWPORT: Size = Size::new_equal(128);
+
+    fn press() -> [Event; 2] {
+        [Event::Press(PressEvent::Press), Event::Press(PressEvent::Release)]
+    }
+
+    fn rotate(delta: i16) -> Event {
+        Event::hor_move(delta)
+    }
+
+    #[test]
+    fn types_shifts_and_deletes() {
+        with_new_runtime(|_| {
+            let value = create_signal(String::new());
+            let mut page = focused_page(
+                EncoderKeyboard::new(value).alphabets([Alphabet::HEX]),
+                VIEWPORT,
+            );
+
+            // Not in edit mode yet: rotation is left to focus navigation.
+            let _ = page.handle_events([rotate(3)].into_iter());
+            let _ = page.handle_events(press().into_iter());
+            assert_eq!(
+                value.get_cloned(),
+                "",
+                "the first press enters edit mode"
+            );
+            // Keys are measured and drawn in render only.
+            let _ = page.use_renderer(|_| {});
+
+            let _ = page.handle_events(press().into_iter());
+            let _ = page.handle_events([rotate(10)].into_iter().chain(press()));
+            assert_eq!(value.get_cloned(), "0a");
+
+            // 16 characters, then shift, backspace and enter.
+            let _ = page.handle_events([rotate(6)].into_iter().chain(press()));
+            let _ = page.handle_events([rotate(-5)].into_iter().chain(press()));
+            assert_eq!(value.get_cloned(), "0aB");
+
+            let _ = page.handle_events(
+                [rotate(100)].into_iter().chain([rotate(-1)]).chain(press()),
+            );
+            assert_eq!(
+                value.get_cloned(),
+                "0a",
+                "rotation is clamped to the keys"
+            );
+        });
+    }
+
+    #[test]
+    fn switch_cycles_alphabets() {
+        with_new_runtime(|_| {
+            let value = create_signal(String::new());
+            let mut page = focused_page(
+                EncoderKeyboard::new(value)
+                    .alphabets([Alphabet::HEX, Alphabet::DIGITS]),
+                VIEWPORT,
+            );
+
+            // Hex: 16 characters, shift, then the switch key.
+            let _ = page.handle_events(
+                press().into_iter().chain([rotate(17)]).chain(press()),
+            );
+            // Digits have no shift: the switch key right after them.
+            let _ = page.handle_events([rotate(-9)].into_iter().chain(press()));
+            assert_eq!(value.get_cloned(), "1");
+
+            // Back to hex, on its switch key, next to `f` and shift.
+            let _ = page.handle_events(
+                [rotate(9)]
+                    .into_iter()
+                    .chain(press())
+                    .chain([
+                        Event::move_1(MoveDir::Left),
+                        Event::move_1(MoveDir::Left),
+                    ])
+                    .chain(press()),
+            );
+            assert_eq!(value.get_cloned(), "1f");
+        });
+    }
+
+    #[test]
+    fn enter_and_exit_leave_edit_mode() {
+        with_new_runtime(|_| {
+            let value = create_signal(String::new());
+            let mut entered = create_signal(0);
+            let mut page = focused_page(
+                EncoderKeyboard::new(value)
+                    .alphabets([Alphabet::DIGITS])
+                    .on_enter(move || entered.update(|entered| *entered += 1)),
+                VIEWPORT,
+            );
+
+            // Digits: 10 characters, backspace, then enter.
+            let _ = page.handle_events(
+                press().into_iter().chain([rotate(11)]).chain(press()),
+            );
+            assert_eq!(entered.get(), 1);
+
+            // Edit mode is left on the enter key: the next press re-enters it
+            // and a press after that presses enter again.
+            let _ = page.handle_events(press().into_iter().chain(press()));
+            assert_eq!(entered.get(), 2);
+            assert_eq!(value.get_cloned(), "");
+
+            let _ = page.handle_events(
+                press().into_iter().chain([Event::Exit]).chain(press()),
+            );
+            let _ =
+                page.handle_events([rotate(-11)].into_iter().chain(press()));
+            assert_eq!(
+                value.get_cloned(),
+                "0",
+                "exit leaves edit mode without typing"
+            );
+            assert_eq!(entered.get(), 2);
+        });
+    }
+}
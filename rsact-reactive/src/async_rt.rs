@@ -1,24 +1,33 @@
 use core::{
     cell::{Cell, RefCell},
+    convert::Infallible,
     task::{Context, Poll, Waker},
 };
 
 /// The lifecycle state of an asynchronously-computed value.
 ///
-/// Transitions: `Uninitialized` → `Loading` → `Ready(T)`.
+/// Transitions: `Uninitialized` → `Loading` → `Ready(T)` or `Error(E)`.
 /// When the reactive source changes, the state goes back to `Loading` while
-/// the new fetch is in progress.
+/// the new fetch is in progress — or to `Reloading(previous)` if the resource
+/// keeps its previous value while revalidating.
+///
+/// `E` defaults to [`Infallible`] for fetches that cannot fail.
 #[derive(Clone, PartialEq)]
-pub enum AsyncState<T> {
+pub enum AsyncState<T, E = Infallible> {
     /// No fetch has been started yet (before the first reactive source read).
     Uninitialized,
     /// A fetch is currently in progress.
     Loading,
+    /// A fetch is in progress; the previous value is kept meanwhile
+    /// (stale-while-revalidate).
+    Reloading(T),
     /// The most recent fetch completed successfully.
     Ready(T),
+    /// The most recent fetch failed. It may still be retried.
+    Error(E),
 }
 
-impl<T> AsyncState<T> {
+impl<T, E> AsyncState<T, E> {
     pub fn ready(&self) -> Option<&T> {
         match self {
             Self::Ready(v) => Some(v),
@@ -26,14 +35,35 @@ impl<T> AsyncState<T> {
         }
     }
 
+    /// The most recent value: the ready one, or the stale one kept while
+    /// reloading.
+    pub fn latest(&self) -> Option<&T> {
+        match self {
+            Self::Ready(v) | Self::Reloading(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&E> {
+        match self {
+            Self::Error(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Whether a fetch is in progress, with or without a stale value.
     pub fn is_loading(&self) -> bool {
-        matches!(self, Self::Loading)
+        matches!(self, Self::Loading | Self::Reloading(_))
     }
 
     pub fn is_ready(&self) -> bool {
         matches!(self, Self::Ready(_))
     }
 
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::Error(_))
+    }
+
     pub fn is_uninitialized(&self) -> bool {
         matches!(self, Self::Uninitialized)
     }
@@ -77,6 +107,12 @@ impl AsyncNotify {
         }
     }
 
+    /// Register the driver's waker without consuming a pending notification,
+    /// so a notification wakes the driver while it awaits something else.
+    pub fn register(&self, cx: &mut Context<'_>) {
+        *self.waker.borrow_mut() = Some(cx.waker().clone());
+    }
+
     /// Called inside the driver future's `poll` to wait for the next
     /// notification.
     ///
@@ -17,7 +17,7 @@ use crate::{
     memo::{Memo, create_memo},
     read::{ReadSignal, SignalMap},
     runtime::with_current_runtime,
-    storage::ValueId,
+    storage::{ComputedId, ValueId},
 };
 use core::{marker::PhantomData, panic::Location};
 
@@ -46,13 +46,14 @@ impl<T: 'static> Computed<T> {
     pub fn new<P: 'static>(f: impl CallbackFn<T, P>) -> Self {
         let caller = Location::caller();
         Self {
-            id: with_current_runtime(|rt| rt.create_computed(f, caller)),
-            ty: PhantomData,
+            id: ComputedId::new(with_current_runtime(|rt| {
+                rt.create_computed(f, caller)
+            })),
         }
     }
 
     pub fn id(&self) -> ValueId {
-        self.id
+        self.id.untyped()
     }
 }
 
@@ -86,8 +87,7 @@ where
 /// See the [module-level documentation](self) for a comparison with
 /// [`crate::memo::Memo`]. Construct with [`create_computed`].
 pub struct Computed<T> {
-    id: ValueId,
-    ty: PhantomData<T>,
+    id: ComputedId<T>,
 }
 
 impl<T: 'static, U: PartialEq + 'static> SignalMap<T, U> for Computed<T> {
@@ -103,15 +103,15 @@ impl<T: 'static> ReactiveValue for Computed<T> {
     type Value = T;
 
     fn id(&self) -> Option<ValueId> {
-        Some(self.id)
+        Some(self.id.untyped())
     }
 
     fn is_alive(&self) -> bool {
-        with_current_runtime(|rt| rt.is_alive(self.id))
+        with_current_runtime(|rt| self.id.is_alive(rt))
     }
 
     unsafe fn dispose(self) {
-        unsafe { with_current_runtime(|rt| rt.dispose(self.id)) }
+        unsafe { with_current_runtime(|rt| self.id.dispose(rt)) }
     }
 }
 
@@ -140,7 +140,7 @@ impl<T: 'static> ReadSignal<T> for Computed<T> {
 
 impl<T> Clone for Computed<T> {
     fn clone(&self) -> Self {
-        Self { id: self.id.clone(), ty: self.ty.clone() }
+        *self
     }
 }
 impl<T> Copy for Computed<T> {}
@@ -1,6 +1,9 @@
 use crate::{
-    ReactiveValue, callback::AnyCallback, read::ReadSignal,
-    runtime::with_current_runtime, storage::ValueId,
+    ReactiveValue,
+    callback::AnyCallback,
+    read::ReadSignal,
+    runtime::with_current_runtime,
+    storage::{EffectId, ValueId},
 };
 use alloc::rc::Rc;
 use core::{any::Any, cell::RefCell, marker::PhantomData, panic::Location};
@@ -43,9 +46,8 @@ where
     let caller = Location::caller();
     let effect = Effect::new(f);
 
-    with_current_runtime(|rt| {
-        rt.maybe_update(effect.id, Some(effect.id), caller)
-    });
+    let id = effect.id.untyped();
+    with_current_runtime(|rt| rt.maybe_update(id, Some(id), caller));
 
     effect
 }
@@ -59,23 +61,22 @@ where
 /// `Effect<T>` can be used to query liveness via [`Effect::is_alive`], but
 /// normally you do not need to interact with it directly after creation.
 pub struct Effect<T> {
-    id: ValueId,
-    ty: PhantomData<T>,
+    id: EffectId<T>,
 }
 
 impl<T> ReactiveValue for Effect<T> {
     type Value = T;
 
     fn id(&self) -> Option<ValueId> {
-        Some(self.id)
+        Some(self.id.untyped())
     }
 
     fn is_alive(&self) -> bool {
-        with_current_runtime(|rt| rt.is_alive(self.id))
+        with_current_runtime(|rt| self.id.is_alive(rt))
     }
 
     unsafe fn dispose(self) {
-        with_current_runtime(|rt| unsafe { rt.dispose(self.id) })
+        with_current_runtime(|rt| unsafe { self.id.dispose(rt) })
     }
 }
 
@@ -89,7 +90,7 @@ impl<T: 'static> Effect<T> {
         let caller = Location::caller();
         let effect = with_current_runtime(|rt| rt.create_effect(f, caller));
 
-        Self { id: effect, ty: PhantomData }
+        Self { id: EffectId::new(effect) }
     }
 
     #[track_caller]
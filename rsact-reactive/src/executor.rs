@@ -0,0 +1,391 @@
+//! A tiny single-threaded executor, for running [`Resource`] drivers and other
+//! local tasks without bringing an async runtime.
+//!
+//! It is polled from the application's main loop, next to `UI::tick`:
+//!
+//! ```rust,ignore
+//! let executor = LocalExecutor::new();
+//! let (resource, driver) = create_resource(source, fetch);
+//! executor.spawn(driver);
+//!
+//! loop {
+//!     ui.tick_time(now_millis());
+//!     executor.poll();
+//!     ui.tick(events());
+//!     // ...render...
+//!     if !executor.has_ready() {
+//!         sleep_until_interrupt(ui.next_deadline());
+//!     }
+//! }
+//! ```
+//!
+//! Tasks are `!Send` futures. Their wakers only flag the task as ready: the
+//! executor never blocks or sleeps itself, so it works unchanged on `no_std`.
+//! Wakers are thread-safe like any [`Waker`], so an interrupt handler may wake
+//! a task directly.
+//!
+//! [`Resource`]: crate::resource::Resource
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Waker},
+};
+use portable_atomic::{AtomicBool, Ordering};
+use portable_atomic_util::{Arc, task::Wake};
+
+/// Identifies a task spawned on a [`LocalExecutor`]. The id of a completed or
+/// cancelled task never refers to a task spawned later in the same slot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TaskId {
+    index: usize,
+    version: u32,
+}
+
+/// Ready flag of a task, set by its wakers.
+///
+/// Wakers are `Send + Sync` and may be woken from another thread or an
+/// interrupt handler (e.g. a DMA completion), hence the atomic flag, even
+/// though the executor and its tasks are single-threaded.
+struct ReadyFlag(AtomicBool);
+
+impl ReadyFlag {
+    fn take(&self) -> bool {
+        self.0.swap(false, Ordering::AcqRel)
+    }
+
+    fn is_set(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
+
+impl Wake for ReadyFlag {
+    fn wake(this: Arc<Self>) {
+        Self::wake_by_ref(&this);
+    }
+
+    fn wake_by_ref(this: &Arc<Self>) {
+        this.0.store(true, Ordering::Release);
+    }
+}
+
+struct Task {
+    future: Pin<Box<dyn Future<Output = ()>>>,
+    ready: Arc<ReadyFlag>,
+}
+
+#[derive(Default)]
+struct Slot {
+    /// Bumped on every spawn into the slot, so a stale [`TaskId`] does not
+    /// match the task spawned after it.
+    version: u32,
+    /// `None` once the task completes or is cancelled (the slot is then
+    /// reused).
+    ready: Option<Arc<ReadyFlag>>,
+}
+
+/// Single-threaded executor polled by hand. See the [module docs](self).
+#[derive(Default)]
+pub struct LocalExecutor {
+    /// Task futures. A slot is `None` once its task completes and,
+    /// temporarily, while its task is being polled.
+    tasks: RefCell<Vec<Option<Task>>>,
+    /// Ready flags and versions, parallel to `tasks`. Kept apart so a task
+    /// polled right now (taken out of its slot) can still be woken.
+    slots: RefCell<Vec<Slot>>,
+}
+
+impl LocalExecutor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawn a task. It is first polled on the next [`LocalExecutor::poll`].
+    /// Tasks may spawn other tasks while being polled.
+    pub fn spawn(&self, future: impl Future<Output = ()> + 'static) -> TaskId {
+        let ready = Arc::new(ReadyFlag(AtomicBool::new(true)));
+        let task = Task { future: Box::pin(future), ready: ready.clone() };
+
+        let mut tasks = self.tasks.borrow_mut();
+        let mut slots = self.slots.borrow_mut();
+        let index = match slots.iter().position(|slot| slot.ready.is_none()) {
+            Some(free) => free,
+            None => {
+                tasks.push(None);
+                slots.push(Slot::default());
+                slots.len() - 1
+            },
+        };
+
+        let slot = &mut slots[index];
+        slot.version = slot.version.wrapping_add(1);
+        slot.ready = Some(ready);
+        tasks[index] = Some(task);
+
+        TaskId { index, version: slot.version }
+    }
+
+    /// Drop a task without completing it. Returns whether it was still
+    /// running.
+    pub fn cancel(&self, task: TaskId) -> bool {
+        // The future is dropped after the borrows are released, as its `Drop`
+        // may touch the executor. A task cancelled while being polled is not
+        // in its slot: `poll` notices its flag is gone and drops it.
+        let (running, future) = {
+            let mut tasks = self.tasks.borrow_mut();
+            let mut slots = self.slots.borrow_mut();
+            match slots.get_mut(task.index) {
+                Some(slot) if slot.version == task.version => {
+                    match slot.ready.take() {
+                        Some(_) => (true, tasks[task.index].take()),
+                        None => (false, None),
+                    }
+                },
+                _ => (false, None),
+            }
+        };
+        drop(future);
+        running
+    }
+
+    /// Poll every ready task once. Returns whether some task is ready again
+    /// (woken while polling, or spawned), i.e. whether the loop should poll
+    /// again rather than sleep.
+    pub fn poll(&self) -> bool {
+        let count = self.tasks.borrow().len();
+
+        for index in 0..count {
+            let task = {
+                let mut tasks = self.tasks.borrow_mut();
+                match &tasks[index] {
+                    Some(task) if task.ready.take() => tasks[index].take(),
+                    _ => None,
+                }
+            };
+            let Some(mut task) = task else { continue };
+
+            let waker = Waker::from(task.ready.clone());
+            let mut cx = Context::from_waker(&waker);
+            let done = task.future.as_mut().poll(&mut cx).is_ready();
+
+            // The task may have been cancelled while polled, and its slot even
+            // reused by a task spawned since.
+            let cancelled = !self.slots.borrow()[index]
+                .ready
+                .as_ref()
+                .is_some_and(|flag| Arc::ptr_eq(flag, &task.ready));
+
+            if done || cancelled {
+                if done && !cancelled {
+                    self.slots.borrow_mut()[index].ready = None;
+                }
+                drop(task);
+            } else {
+                self.tasks.borrow_mut()[index] = Some(task);
+            }
+        }
+
+        self.has_ready()
+    }
+
+    /// Whether some task is ready to make progress.
+    pub fn has_ready(&self) -> bool {
+        self.slots
+            .borrow()
+            .iter()
+            .filter_map(|slot| slot.ready.as_ref())
+            .any(|ready| ready.is_set())
+    }
+
+    /// Number of tasks not completed yet.
+    pub fn len(&self) -> usize {
+        self.slots
+            .borrow()
+            .iter()
+            .filter(|slot| slot.ready.is_some())
+            .count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LocalExecutor;
+    use crate::{
+        prelude::*,
+        resource::create_resource,
+        timer::{Timer, next_timer_deadline, tick_timers},
+    };
+    use alloc::rc::Rc;
+    use core::{
+        cell::{Cell, RefCell},
+        future::{Future, poll_fn},
+        task::{Poll, Waker},
+    };
+
+    /// Simulated timer future: ready once the reactive timer clock reaches
+    /// `now + millis` (advanced by `tick_timers`, as `UI::tick_time` does).
+    fn sleep(millis: u32) -> impl Future<Output = ()> {
+        let fired = Rc::new(Cell::new(false));
+        let waker = Rc::new(RefCell::new(None::<Waker>));
+
+        let (on_fire, wake) = (fired.clone(), waker.clone());
+        let timer = Timer::new(
+            millis,
+            false,
+            move || {
+                on_fire.set(true);
+                if let Some(waker) = wake.borrow_mut().take() {
+                    waker.wake();
+                }
+            },
+            true,
+        );
+
+        poll_fn(move |cx| {
+            if fired.get() {
+                unsafe { timer.dispose() };
+                Poll::Ready(())
+            } else {
+                *waker.borrow_mut() = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+    }
+
+    #[test]
+    fn runs_tasks_woken_by_timers() {
+        with_new_runtime(|_| {
+            let executor = LocalExecutor::new();
+            let log = Rc::new(RefCell::new(alloc::vec::Vec::new()));
+
+            for (name, millis) in [("slow", 200), ("fast", 100)] {
+                let log = log.clone();
+                executor.spawn(async move {
+                    sleep(millis).await;
+                    log.borrow_mut().push(name);
+                });
+            }
+
+            assert!(!executor.poll(), "both tasks wait for their timers");
+            assert_eq!(executor.len(), 2);
+            assert_eq!(next_timer_deadline(), Some(100));
+
+            tick_timers(100);
+            assert!(executor.has_ready());
+            executor.poll();
+            tick_timers(200);
+            executor.poll();
+
+            assert_eq!(*log.borrow(), ["fast", "slow"]);
+            assert!(executor.is_empty());
+        });
+    }
+
+    #[test]
+    fn drives_resources_and_nested_spawns() {
+        with_new_runtime(|_| {
+            let executor = Rc::new(LocalExecutor::new());
+            let mut source = create_signal(1u32);
+
+            let (resource, driver) = create_resource(
+                move || source.get(),
+                |n| async move {
+                    sleep(50).await;
+                    n * 10
+                },
+            );
+            executor.spawn(driver);
+
+            let spawned = Rc::new(Cell::new(false));
+            let (inner, flag) = (executor.clone(), spawned.clone());
+            executor.spawn(async move {
+                inner.spawn(async move { flag.set(true) });
+            });
+
+            assert!(executor.poll(), "the nested task is ready");
+            executor.poll();
+            assert!(spawned.get());
+            assert!(resource.is_loading());
+
+            tick_timers(50);
+            executor.poll();
+            assert_eq!(resource.ready(), Some(10));
+
+            source.set(2);
+            assert!(executor.has_ready(), "source change wakes the driver");
+            executor.poll();
+            tick_timers(100);
+            executor.poll();
+            assert_eq!(resource.ready(), Some(20));
+            assert_eq!(executor.len(), 1, "the driver runs until disposed");
+        });
+    }
+
+    #[test]
+    fn cancelled_task_is_dropped() {
+        with_new_runtime(|_| {
+            let executor = LocalExecutor::new();
+            let task = executor.spawn(sleep(10));
+            executor.poll();
+
+            assert!(executor.cancel(task));
+            assert!(!executor.cancel(task));
+            assert!(executor.is_empty());
+            tick_timers(10);
+            assert!(!executor.poll());
+        });
+    }
+
+    #[test]
+    fn stale_task_id_does_not_cancel_slot_reuser() {
+        with_new_runtime(|_| {
+            let executor = LocalExecutor::new();
+            let done = executor.spawn(async {});
+            executor.poll();
+            assert!(executor.is_empty());
+
+            // Spawned into the slot `done` had.
+            let ran = Rc::new(Cell::new(false));
+            let flag = ran.clone();
+            let reuser = executor.spawn(async move { flag.set(true) });
+            assert_ne!(done, reuser);
+
+            assert!(!executor.cancel(done));
+            assert_eq!(executor.len(), 1);
+            executor.poll();
+            assert!(ran.get());
+        });
+    }
+
+    #[test]
+    fn wakers_are_send_and_wake_from_another_thread() {
+        extern crate std;
+
+        with_new_runtime(|_| {
+            let executor = LocalExecutor::new();
+            let waker = Rc::new(RefCell::new(None::<Waker>));
+            let (stored, polls) = (waker.clone(), Rc::new(Cell::new(0)));
+            let counted = polls.clone();
+            executor.spawn(poll_fn(move |cx| {
+                counted.set(counted.get() + 1);
+                *stored.borrow_mut() = Some(cx.waker().clone());
+                Poll::<()>::Pending
+            }));
+            executor.poll();
+            assert!(!executor.has_ready());
+
+            let waker = waker.borrow_mut().take().unwrap();
+            std::thread::spawn(move || waker.wake()).join().unwrap();
+
+            assert!(executor.has_ready());
+            executor.poll();
+            assert_eq!(polls.get(), 2);
+        });
+    }
+}
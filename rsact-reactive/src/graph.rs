@@ -0,0 +1,274 @@
+//! Structured export of the reactive graph, for tooling and for checking the
+//! graph shape in tests.
+//!
+//! [`reactive_graph`] captures the nodes of the current runtime (all of them,
+//! those of a scope, or those around a value, see [`GraphFilter`]) with their
+//! kind, name, creation location, state, owner and edges. The capture renders
+//! as Graphviz DOT ([`ReactiveGraph::to_dot`]) or JSON
+//! ([`ReactiveGraph::to_json`]); unlike the mermaid graphs it is plain data,
+//! so it can also be inspected directly.
+
+use crate::{
+    runtime::with_current_runtime,
+    scope::{ScopeHandle, ScopeId},
+    storage::{ValueId, ValueKindTag, ValueState},
+};
+use alloc::{format, string::String, vec::Vec};
+use core::{fmt::Write, panic::Location};
+
+/// Which nodes [`reactive_graph`] captures.
+pub enum GraphFilter<'a> {
+    All,
+    /// Nodes created in the scope or its child scopes, and the nodes owned
+    /// by those (e.g. created by their effects).
+    Scope(&'a ScopeHandle),
+    /// Nodes at most `depth` edges (in either direction) away from `root`.
+    Around {
+        root: ValueId,
+        depth: usize,
+    },
+}
+
+/// What disposes a node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphOwner {
+    Scope(ScopeId),
+    /// An observer (effect, memo...) whose run created the node.
+    Value(ValueId),
+}
+
+#[derive(Clone, Debug)]
+pub struct GraphNode {
+    pub id: ValueId,
+    pub kind: ValueKindTag,
+    pub name: Option<&'static str>,
+    pub ty: &'static str,
+    pub created_at: &'static Location<'static>,
+    pub state: ValueState,
+    pub owner: Option<GraphOwner>,
+    /// Nodes this one tracked on its last run.
+    pub sources: Vec<ValueId>,
+    /// Nodes tracking this one.
+    pub subscribers: Vec<ValueId>,
+}
+
+/// A capture of (part of) the reactive graph, nodes sorted by id.
+#[derive(Clone, Debug, Default)]
+pub struct ReactiveGraph {
+    pub nodes: Vec<GraphNode>,
+}
+
+/// Capture the graph of the current runtime.
+pub fn reactive_graph(filter: GraphFilter) -> ReactiveGraph {
+    with_current_runtime(|rt| rt.reactive_graph(filter))
+}
+
+fn kind_name(kind: ValueKindTag) -> &'static str {
+    match kind {
+        ValueKindTag::Stored => "stored",
+        ValueKindTag::Signal => "signal",
+        ValueKindTag::Effect => "effect",
+        ValueKindTag::Memo => "memo",
+        ValueKindTag::Computed => "computed",
+        ValueKindTag::Probe => "probe",
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            },
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl ReactiveGraph {
+    pub fn node(&self, id: ValueId) -> Option<&GraphNode> {
+        self.nodes
+            .binary_search_by_key(&id, |node| node.id)
+            .ok()
+            .map(|pos| &self.nodes[pos])
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Count of captured nodes of `kind`.
+    pub fn count(&self, kind: ValueKindTag) -> usize {
+        self.nodes.iter().filter(|node| node.kind == kind).count()
+    }
+
+    /// Graphviz DOT: one node per value, edges from sources to subscribers.
+    /// Edges to nodes outside the capture are left out.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph reactive {\n");
+
+        for node in &self.nodes {
+            let shape = match node.kind {
+                ValueKindTag::Stored => "note",
+                ValueKindTag::Signal => "ellipse",
+                ValueKindTag::Effect => "box",
+                ValueKindTag::Memo => "Mrecord",
+                ValueKindTag::Computed => "doublecircle",
+                ValueKindTag::Probe => "diamond",
+            };
+            let name = node
+                .name
+                .map(|name| format!(" '{name}'"))
+                .unwrap_or_default();
+            let label = format!(
+                "{}{name}: {}\n{} ({})",
+                kind_name(node.kind),
+                node.ty,
+                node.created_at,
+                node.state
+            );
+
+            let _ = writeln!(
+                dot,
+                "    n{} [shape={shape}, label=\"{}\"];",
+                node.id,
+                escape(&label)
+            );
+        }
+
+        for node in &self.nodes {
+            for source in &node.sources {
+                if self.node(*source).is_some() {
+                    let _ = writeln!(dot, "    n{source} -> n{};", node.id);
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// JSON: `{"nodes": [...]}`, each node with `id`, `kind`, `name`, `type`,
+    /// `created_at`, `state`, `owner` (`{"scope": ..}`, `{"value": ..}` or
+    /// `null`), `sources` and `subscribers`. Ids are strings: they are 64-bit
+    /// and would lose precision as JSON numbers past 2^53.
+    pub fn to_json(&self) -> String {
+        let ids = |ids: &[ValueId]| {
+            ids.iter()
+                .map(|id| format!("\"{id}\""))
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|node| {
+                let name = node
+                    .name
+                    .map(|name| format!("\"{}\"", escape(name)))
+                    .unwrap_or_else(|| "null".into());
+                let owner = match node.owner {
+                    Some(GraphOwner::Scope(scope)) => {
+                        format!("{{\"scope\":\"{}\"}}", scope_number(scope))
+                    },
+                    Some(GraphOwner::Value(id)) => {
+                        format!("{{\"value\":\"{id}\"}}")
+                    },
+                    None => "null".into(),
+                };
+
+                format!(
+                    "{{\"id\":\"{}\",\"kind\":\"{}\",\"name\":{name},\
+                     \"type\":\"{}\",\"created_at\":\"{}\",\"state\":\"{}\",\
+                     \"owner\":{owner},\
+                     \"sources\":[{}],\"subscribers\":[{}]}}",
+                    node.id,
+                    kind_name(node.kind),
+                    escape(node.ty),
+                    escape(&format!("{}", node.created_at)),
+                    node.state,
+                    ids(&node.sources),
+                    ids(&node.subscribers),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!("{{\"nodes\":[{nodes}]}}")
+    }
+}
+
+fn scope_number(scope: ScopeId) -> u64 {
+    use slotmap::Key;
+    scope.data().as_ffi()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GraphFilter, GraphOwner, reactive_graph};
+    use crate::{prelude::*, scope::new_scope, storage::ValueKindTag};
+    use alloc::format;
+
+    #[test]
+    fn captures_nodes_edges_and_owners() {
+        with_new_runtime(|_| {
+            let outside = create_signal(0u8);
+
+            let scope = new_scope();
+            let count = create_signal(1u32);
+            count.id().unwrap().set_name("count");
+            let double = create_memo(move || count.get() * 2);
+            create_effect(move |_| {
+                double.get();
+            });
+            scope.leave();
+
+            let graph = reactive_graph(GraphFilter::Scope(&scope));
+            assert_eq!(graph.len(), 3);
+            assert!(graph.node(outside.id().unwrap()).is_none());
+            assert_eq!(graph.count(ValueKindTag::Effect), 1);
+
+            let count_node = graph.node(count.id().unwrap()).unwrap();
+            assert_eq!(count_node.name, Some("count"));
+            assert_eq!(count_node.subscribers, [double.id().unwrap()]);
+            assert!(matches!(count_node.owner, Some(GraphOwner::Scope(_))));
+
+            let around = reactive_graph(GraphFilter::Around {
+                root: count.id().unwrap(),
+                depth: 1,
+            });
+            assert_eq!(around.len(), 2, "count and double");
+
+            let dot = graph.to_dot();
+            assert!(dot.starts_with("digraph reactive {"));
+            assert!(dot.contains(&format!(
+                "n{} -> n{};",
+                count.id().unwrap(),
+                double.id().unwrap()
+            )));
+
+            let json = graph.to_json();
+            assert!(json.contains("\"kind\":\"memo\""));
+            assert!(json.contains("\"name\":\"count\""));
+            assert_eq!(json.matches("\"id\":").count(), 3);
+            assert!(
+                json.contains(&format!("\"id\":\"{}\"", count.id().unwrap()))
+            );
+            assert!(json.contains(&format!(
+                "\"sources\":[\"{}\"]",
+                count.id().unwrap()
+            )));
+            assert!(json.contains("\"owner\":{\"scope\":\""));
+        });
+    }
+}
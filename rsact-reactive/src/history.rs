@@ -0,0 +1,268 @@
+//! Signals with undo and redo.
+//!
+//! A [`HistorySignal`] is a signal remembering its previous values, up to a
+//! capacity, oldest dropped first. Every change is one undo step, except that
+//! writes made in one [`batch`](crate::runtime::batch) make a single step, as
+//! the history is recorded by an effect. It converts into its inner
+//! [`Signal`] with [`IntoSignal`], so widgets such as `Slider`, `Checkbox` or
+//! `Select` bind to it as they are: their writes are recorded like any other.
+
+use crate::{
+    ReactiveValue,
+    effect::create_effect,
+    read::ReadSignal,
+    signal::{IntoSignal, Signal, create_signal},
+    storage::ValueId,
+    write::WriteSignal,
+};
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+struct History<T> {
+    /// Previous values, the latest last.
+    undo: VecDeque<T>,
+    /// Undone values, the latest undone last.
+    redo: Vec<T>,
+    capacity: usize,
+    /// The next change is an undo or a redo, not a new step.
+    restoring: bool,
+}
+
+impl<T> History<T> {
+    fn record(&mut self, prev: T) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.undo.len() == self.capacity {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(prev);
+        self.redo.clear();
+    }
+}
+
+/// Create a [`HistorySignal`] keeping up to `capacity` undo steps.
+///
+/// The recording effect is owned by the current scope.
+#[track_caller]
+pub fn create_history_signal<T: Clone + PartialEq + 'static>(
+    value: T,
+    capacity: usize,
+) -> HistorySignal<T> {
+    let value = create_signal(value);
+    let mut history = create_signal(History {
+        undo: VecDeque::new(),
+        redo: Vec::new(),
+        capacity,
+        restoring: false,
+    });
+
+    create_effect(move |prev: Option<T>| {
+        let current = value.with(T::clone);
+
+        let restoring = history
+            .update_untracked(|h| core::mem::replace(&mut h.restoring, false));
+        if let Some(prev) = prev
+            && prev != current
+            && !restoring
+        {
+            history.update(|h| h.record(prev));
+        }
+
+        current
+    });
+
+    HistorySignal { value, history }
+}
+
+/// A signal with undo and redo, see the [module docs](self).
+pub struct HistorySignal<T: 'static> {
+    value: Signal<T>,
+    history: Signal<History<T>>,
+}
+
+impl<T: 'static> Clone for HistorySignal<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for HistorySignal<T> {}
+
+impl<T: 'static> ReactiveValue for HistorySignal<T> {
+    type Value = T;
+
+    fn id(&self) -> Option<ValueId> {
+        self.value.id()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.value.is_alive()
+    }
+
+    unsafe fn dispose(self) {
+        unsafe {
+            self.value.dispose();
+            self.history.dispose();
+        }
+    }
+}
+
+impl<T: 'static> ReadSignal<T> for HistorySignal<T> {
+    #[track_caller]
+    fn track(&self) {
+        self.value.track();
+    }
+
+    #[track_caller]
+    fn with_untracked<U>(&self, f: impl FnOnce(&T) -> U) -> U {
+        self.value.with_untracked(f)
+    }
+}
+
+impl<T: 'static> WriteSignal<T> for HistorySignal<T> {
+    #[track_caller]
+    fn notify(&self) {
+        self.value.notify();
+    }
+
+    #[track_caller]
+    fn update_untracked<U>(&mut self, f: impl FnOnce(&mut T) -> U) -> U {
+        self.value.update_untracked(f)
+    }
+}
+
+impl<T: 'static> IntoSignal<T> for HistorySignal<T> {
+    fn signal(self) -> Signal<T> {
+        self.value
+    }
+}
+
+impl<T: Clone + 'static> HistorySignal<T> {
+    /// Go back to the previous value. Returns `false` if there is none.
+    pub fn undo(&self) -> bool {
+        self.restore(|h| h.undo.pop_back(), |h, current| h.redo.push(current))
+    }
+
+    /// Go forward to the last undone value. Returns `false` if there is none.
+    pub fn redo(&self) -> bool {
+        self.restore(|h| h.redo.pop(), |h, current| h.undo.push_back(current))
+    }
+
+    /// Whether [`HistorySignal::undo`] can go back. Tracked, like a read.
+    pub fn can_undo(&self) -> bool {
+        self.history.with(|h| !h.undo.is_empty())
+    }
+
+    /// Whether [`HistorySignal::redo`] can go forward. Tracked, like a read.
+    pub fn can_redo(&self) -> bool {
+        self.history.with(|h| !h.redo.is_empty())
+    }
+
+    /// Forget every undo and redo step, keeping the value.
+    pub fn clear_history(&self) {
+        let mut history = self.history;
+        history.update(|h| {
+            h.undo.clear();
+            h.redo.clear();
+        });
+    }
+
+    fn restore(
+        &self,
+        take: impl FnOnce(&mut History<T>) -> Option<T>,
+        keep: impl FnOnce(&mut History<T>, T),
+    ) -> bool {
+        let (mut value, mut history) = (self.value, self.history);
+
+        let Some(restored) = history.update_untracked(take) else {
+            return false;
+        };
+
+        let current = value.with_untracked(T::clone);
+        history.update(|h| {
+            keep(h, current);
+            h.restoring = true;
+        });
+        value.set(restored);
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::create_history_signal;
+    use crate::prelude::*;
+    use alloc::{rc::Rc, vec::Vec};
+    use core::cell::Cell;
+
+    #[test]
+    fn undo_and_redo() {
+        with_new_runtime(|_| {
+            let mut value = create_history_signal(0, 8);
+            assert!(!value.can_undo());
+
+            value.set(1);
+            value.set(2);
+            value.set(2);
+            assert!(value.undo());
+            assert_eq!(value.get(), 1);
+            assert!(value.undo());
+            assert_eq!(value.get(), 0);
+            assert!(!value.undo(), "equal writes are not steps");
+
+            assert!(value.redo());
+            assert_eq!(value.get(), 1);
+
+            value.set(5);
+            assert!(!value.can_redo(), "a new change drops the redo steps");
+            assert!(value.undo());
+            assert_eq!(value.get(), 1);
+        });
+    }
+
+    #[test]
+    fn capacity_and_batches() {
+        with_new_runtime(|_| {
+            let mut value = create_history_signal(0, 2);
+
+            for v in 1..=4 {
+                value.set(v);
+            }
+            batch(|| {
+                value.set(10);
+                value.set(11);
+            });
+
+            let mut undone = Vec::new();
+            while value.undo() {
+                undone.push(value.get());
+            }
+            assert_eq!(undone, [4, 3], "the batch is one step, 2 kept");
+        });
+    }
+
+    #[test]
+    fn binds_as_a_signal_and_tracks_can_undo() {
+        fn widget(value: impl IntoSignal<bool>) -> Signal<bool> {
+            value.signal()
+        }
+
+        with_new_runtime(|_| {
+            let history = create_history_signal(false, 4);
+            let runs = Rc::new(Cell::new(0));
+            let counter = runs.clone();
+            create_effect(move |_| {
+                history.can_undo();
+                counter.set(counter.get() + 1);
+            });
+
+            let mut checkbox = widget(history);
+            checkbox.set(true);
+            assert_eq!(runs.get(), 2);
+            assert!(history.undo());
+            assert!(!checkbox.get());
+        });
+    }
+}
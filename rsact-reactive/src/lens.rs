@@ -0,0 +1,378 @@
+//! Read-write projections of a signal onto a part of its value.
+//!
+//! [`SignalMap`](crate::read::SignalMap) projections are read-only. A [`Lens`]
+//! reads a field of a parent signal through a memo and writes it back into
+//! the parent in place. It keeps no copy of the field, so it is never out of
+//! sync with the parent, even inside a [`batch`](crate::runtime::batch).
+//!
+//! Widgets binding a value both ways take a [`Binding`], either a [`Signal`]
+//! or a lens, through [`IntoBinding`]:
+//!
+//! ```rust,ignore
+//! Checkbox::new(lens!(settings => wifi.enabled))
+//! ```
+//!
+//! Observers of the lens re-run only when the field changes, not on every
+//! write to the parent.
+
+use crate::{
+    ReactiveValue,
+    memo::{Memo, create_memo},
+    read::{ReadSignal, SignalMap},
+    signal::{Signal, create_signal},
+    storage::ValueId,
+    stored::StoredValue,
+    write::WriteSignal,
+};
+use alloc::boxed::Box;
+
+/// Create a [`Lens`] following `get(parent)` and writing back into `parent`
+/// with `set`. See the [module docs](self) and [`lens!`](crate::lens!).
+///
+/// The memo reading the field is owned by the current scope.
+#[track_caller]
+pub fn create_lens<P, U>(
+    parent: Signal<P>,
+    get: impl Fn(&P) -> U + 'static,
+    set: impl Fn(&mut P, U) + 'static,
+) -> Lens<U>
+where
+    P: 'static,
+    U: PartialEq + Clone + 'static,
+{
+    Lens {
+        value: create_memo(move || parent.with(&get)),
+        parent: StoredValue::new(Box::new(Parent { signal: parent, set })),
+    }
+}
+
+/// The parent side of a [`Lens`], with the parent type erased.
+trait LensParent<U> {
+    fn is_alive(&self) -> bool;
+    fn notify(&self);
+    fn set_untracked(&self, value: U);
+}
+
+struct Parent<P: 'static, F> {
+    signal: Signal<P>,
+    set: F,
+}
+
+impl<P: 'static, U, F: Fn(&mut P, U)> LensParent<U> for Parent<P, F> {
+    fn is_alive(&self) -> bool {
+        self.signal.is_alive()
+    }
+
+    fn notify(&self) {
+        self.signal.notify();
+    }
+
+    fn set_untracked(&self, value: U) {
+        let mut signal = self.signal;
+        signal.update_untracked(|parent| (self.set)(parent, value));
+    }
+}
+
+/// A field of a signal's value, see the [module docs](self).
+pub struct Lens<U: PartialEq + 'static> {
+    value: Memo<U>,
+    parent: StoredValue<Box<dyn LensParent<U>>>,
+}
+
+impl<U: PartialEq + 'static> Clone for Lens<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U: PartialEq + 'static> Copy for Lens<U> {}
+
+impl<U: PartialEq + 'static> ReactiveValue for Lens<U> {
+    type Value = U;
+
+    fn id(&self) -> Option<ValueId> {
+        self.value.id()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.value.is_alive()
+            && self.parent.is_alive()
+            && self.parent.with_untracked(|parent| parent.is_alive())
+    }
+
+    /// Disposes the lens alone, not its parent.
+    unsafe fn dispose(self) {
+        unsafe {
+            self.value.dispose();
+            self.parent.dispose();
+        }
+    }
+}
+
+impl<U: PartialEq + 'static> ReadSignal<U> for Lens<U> {
+    #[track_caller]
+    fn track(&self) {
+        self.value.track();
+    }
+
+    #[track_caller]
+    fn with_untracked<R>(&self, f: impl FnOnce(&U) -> R) -> R {
+        self.value.with_untracked(f)
+    }
+}
+
+/// Writes go to the parent: [`WriteSignal::notify`] notifies the parent, and
+/// the lens observers re-run if the field changed.
+impl<U: PartialEq + Clone + 'static> WriteSignal<U> for Lens<U> {
+    #[track_caller]
+    fn notify(&self) {
+        self.parent.with_untracked(|parent| parent.notify());
+    }
+
+    #[track_caller]
+    fn update_untracked<R>(&mut self, f: impl FnOnce(&mut U) -> R) -> R {
+        let mut value = self.value.with_untracked(U::clone);
+        let result = f(&mut value);
+        self.parent
+            .with_untracked(|parent| parent.set_untracked(value));
+        result
+    }
+}
+
+/// A value bound both ways by a widget: a [`Signal`], or a [`Lens`] onto a
+/// field of one. Both are read and written in place, with no copy to sync.
+pub enum Binding<T: PartialEq + 'static> {
+    Signal(Signal<T>),
+    Lens(Lens<T>),
+}
+
+impl<T: PartialEq + 'static> Clone for Binding<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: PartialEq + 'static> Copy for Binding<T> {}
+
+impl<T: PartialEq + 'static> ReactiveValue for Binding<T> {
+    type Value = T;
+
+    fn id(&self) -> Option<ValueId> {
+        match self {
+            Binding::Signal(signal) => signal.id(),
+            Binding::Lens(lens) => lens.id(),
+        }
+    }
+
+    fn is_alive(&self) -> bool {
+        match self {
+            Binding::Signal(signal) => signal.is_alive(),
+            Binding::Lens(lens) => lens.is_alive(),
+        }
+    }
+
+    unsafe fn dispose(self) {
+        match self {
+            Binding::Signal(signal) => unsafe { signal.dispose() },
+            Binding::Lens(lens) => unsafe { lens.dispose() },
+        }
+    }
+}
+
+impl<T: PartialEq + 'static> ReadSignal<T> for Binding<T> {
+    #[track_caller]
+    fn track(&self) {
+        match self {
+            Binding::Signal(signal) => signal.track(),
+            Binding::Lens(lens) => lens.track(),
+        }
+    }
+
+    #[track_caller]
+    fn with_untracked<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        match self {
+            Binding::Signal(signal) => signal.with_untracked(f),
+            Binding::Lens(lens) => lens.with_untracked(f),
+        }
+    }
+}
+
+impl<T: PartialEq + Clone + 'static> WriteSignal<T> for Binding<T> {
+    #[track_caller]
+    fn notify(&self) {
+        match self {
+            Binding::Signal(signal) => signal.notify(),
+            Binding::Lens(lens) => lens.notify(),
+        }
+    }
+
+    #[track_caller]
+    fn update_untracked<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        match self {
+            Binding::Signal(signal) => signal.update_untracked(f),
+            Binding::Lens(lens) => lens.update_untracked(f),
+        }
+    }
+}
+
+impl<T: PartialEq + 'static, U: PartialEq + 'static> SignalMap<T, U>
+    for Binding<T>
+{
+    type Output = Memo<U>;
+
+    #[track_caller]
+    fn map(&self, mut map: impl FnMut(&T) -> U + 'static) -> Memo<U> {
+        let this = *self;
+        create_memo(move || this.with(&mut map))
+    }
+}
+
+/// `impl IntoBinding<T>` in widgets binding a value both ways. A plain value
+/// becomes an owned signal, a [`Signal`] or a [`Lens`] is bound as is.
+pub trait IntoBinding<T: PartialEq + 'static> {
+    fn binding(self) -> Binding<T>;
+}
+
+impl<T: PartialEq + 'static> IntoBinding<T> for Binding<T> {
+    fn binding(self) -> Binding<T> {
+        self
+    }
+}
+
+impl<T: PartialEq + 'static> IntoBinding<T> for Signal<T> {
+    fn binding(self) -> Binding<T> {
+        Binding::Signal(self)
+    }
+}
+
+impl<T: PartialEq + 'static> IntoBinding<T> for Lens<T> {
+    fn binding(self) -> Binding<T> {
+        Binding::Lens(self)
+    }
+}
+
+impl<T: PartialEq + 'static> IntoBinding<T> for T {
+    #[track_caller]
+    fn binding(self) -> Binding<T> {
+        Binding::Signal(create_signal(self))
+    }
+}
+
+/// [`create_lens`] onto a field path of a signal's value:
+/// `lens!(settings => wifi.enabled)` follows `settings.wifi.enabled`.
+#[macro_export]
+macro_rules! lens {
+    ($parent: expr => $($field: ident).+) => {
+        $crate::lens::create_lens(
+            $parent,
+            |parent| parent.$($field).+.clone(),
+            |parent, value| parent.$($field).+ = value,
+        )
+    };
+}
+
+pub use lens;
+
+#[cfg(test)]
+mod tests {
+    use super::create_lens;
+    use crate::prelude::*;
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    #[derive(Clone, Default, PartialEq)]
+    struct Wifi {
+        enabled: bool,
+        channel: u8,
+    }
+
+    #[derive(Clone, Default, PartialEq)]
+    struct Settings {
+        wifi: Wifi,
+        volume: u8,
+    }
+
+    fn count_runs(signal: impl ReadSignal<bool> + 'static) -> Rc<Cell<u32>> {
+        let runs = Rc::new(Cell::new(0));
+        let counter = runs.clone();
+        create_effect(move |_| {
+            signal.get();
+            counter.set(counter.get() + 1);
+        });
+        runs
+    }
+
+    #[test]
+    fn writes_go_back_to_the_parent() {
+        with_new_runtime(|_| {
+            let settings = create_signal(Settings::default());
+            let mut enabled = lens!(settings => wifi.enabled);
+
+            enabled.set(true);
+            assert!(settings.with(|s| s.wifi.enabled));
+
+            let mut channel = create_lens(
+                settings,
+                |s: &Settings| s.wifi.channel,
+                |s, channel| s.wifi.channel = channel,
+            );
+            channel.update(|c| *c += 6);
+            assert_eq!(settings.with(|s| s.wifi.channel), 6);
+            assert!(enabled.get(), "other fields are kept");
+        });
+    }
+
+    #[test]
+    fn observers_rerun_on_field_changes_only() {
+        with_new_runtime(|_| {
+            let mut settings = create_signal(Settings::default());
+            let enabled = lens!(settings => wifi.enabled);
+            let runs = count_runs(enabled);
+
+            settings.update(|s| s.volume = 3);
+            settings.update(|s| s.wifi.channel = 1);
+            assert_eq!(runs.get(), 1, "other fields do not notify the lens");
+
+            settings.update(|s| s.wifi.enabled = true);
+            assert!(enabled.get());
+            assert_eq!(runs.get(), 2);
+        });
+    }
+
+    #[test]
+    fn reads_follow_the_parent_inside_a_batch() {
+        with_new_runtime(|_| {
+            let mut settings = create_signal(Settings::default());
+            let mut volume = create_lens(
+                settings,
+                |s: &Settings| s.volume,
+                |s, volume| s.volume = volume,
+            );
+
+            batch(|| {
+                settings.update(|s| s.volume = 3);
+                assert_eq!(volume.get(), 3);
+
+                volume.set(4);
+                assert_eq!(settings.with(|s| s.volume), 4);
+                assert_eq!(volume.get(), 4);
+            });
+        });
+    }
+
+    #[test]
+    fn binding_follows_the_parent_inside_a_batch() {
+        with_new_runtime(|_| {
+            let mut settings = create_signal(Settings::default());
+            let mut enabled = lens!(settings => wifi.enabled).binding();
+
+            batch(|| {
+                enabled.set(true);
+                assert!(settings.with(|s| s.wifi.enabled));
+
+                settings.update(|s| s.wifi.enabled = false);
+                assert!(!enabled.get());
+            });
+        });
+    }
+}
@@ -21,19 +21,34 @@ pub mod alloc_probe;
 #[cfg(feature = "async")]
 pub mod async_rt;
 pub mod effect;
+#[cfg(feature = "async")]
+pub mod executor;
+#[cfg(feature = "debug-info")]
+pub mod graph;
+pub mod history;
 pub mod inert;
 pub mod leak;
+pub mod lens;
 pub mod maybe;
 pub mod memo;
+pub mod persist;
 pub mod probe;
 pub mod read;
+pub mod record;
 #[cfg(feature = "async")]
 pub mod resource;
 pub mod runtime;
 pub mod scope;
+pub mod selector;
 pub mod signal;
+pub mod signal_vec;
+#[cfg(feature = "fixed-capacity")]
+pub mod slab;
+#[cfg(feature = "serde")]
+pub mod state;
 pub mod storage;
 mod thread_local;
+pub mod timer;
 pub mod trigger;
 // pub mod versioned;
 mod macros;
@@ -47,31 +62,48 @@ pub mod prelude {
     #[cfg(feature = "async")]
     pub use super::async_rt::AsyncState;
     #[cfg(feature = "async")]
-    pub use super::resource::{Resource, create_resource};
+    pub use super::executor::{LocalExecutor, TaskId};
+    #[cfg(feature = "async")]
+    pub use super::resource::{
+        Backoff, Resource, ResourceOptions, create_resource,
+        create_resource_with,
+    };
+    #[cfg(feature = "serde")]
+    pub use super::state::StateRegistry;
     pub use super::{
         ReactiveValue,
         computed::{Computed, create_computed},
         // cow::CowSignal,
         effect::{Effect, create_effect},
+        history::{HistorySignal, create_history_signal},
         inert::{Inert, IntoInert},
+        lens::{Binding, IntoBinding, Lens, create_lens, lens},
         maybe::{
             IsInert, IsReactive, ReactivityMarker,
             maybe_reactive::IntoMaybeReactive, maybe_reactive::MaybeReactive,
             maybe_signal::IntoMaybeSignal, maybe_signal::MaybeSignal,
         },
         memo::{IntoMemo, Memo, MemoTree, create_memo},
+        persist::{create_persisted_signal, create_persisted_signal_with},
         probe::{Probe, create_probe},
         read::{
             ReadSignal, SignalMap, SignalMapRef, SignalMapRefMaybeReactive,
             SignalMapSlice, SignalWithRef, SignalWithSlice, map, with,
         },
+        record::{Recorder, Replay},
         // TODO: Is this right to reexport from other crate?
         rsact_macros::IntoMaybeReactive,
         runtime::{batch, defer_effects, untrack, with_current_runtime},
+        selector::{Selector, create_selector},
         signal::{
             IntoSignal, RwSignal, Signal, create_signal, marker::ReadOnly,
-            marker::Rw, marker::WriteOnly,
+            marker::Rw, marker::WriteOnly, try_create_signal,
+        },
+        signal_vec::{
+            DiffCursor, SignalVec, VecDiff, create_keyed_signal_vec,
+            create_signal_vec,
         },
+        timer::{Timer, create_interval, create_timeout, debounced, throttled},
         trigger::{Trigger, create_trigger},
         write::{SignalSetter, UpdateNotification, WriteSignal},
     };
@@ -174,6 +174,9 @@ impl<T: 'static> MaybeSignal<T> {
             MaybeSignal::Signal(signal) => {
                 let mut signal = *signal;
                 create_effect(move |prev| {
+                    // `update` does not subscribe: track explicitly, or the
+                    // effect runs once and never follows the signal.
+                    signal.track();
                     signal.update(|value| effect(value, prev))
                 });
             },
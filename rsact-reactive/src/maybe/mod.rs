@@ -100,6 +100,32 @@ mod tests {
         assert!(matches!(maybe, MaybeSignal::Signal(_)));
     }
 
+    #[test]
+    fn maybe_effect_follows_signal() {
+        use alloc::{rc::Rc, vec::Vec};
+        use core::cell::RefCell;
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        // Inert value: the effect runs once.
+        let mut inert = MaybeSignal::new_inert(1);
+        let inert_seen = seen.clone();
+        inert
+            .maybe_effect(move |value, _| inert_seen.borrow_mut().push(*value));
+        assert_eq!(*seen.borrow(), [1]);
+
+        // Signal: the effect runs again on every write.
+        seen.borrow_mut().clear();
+        let mut source = create_signal(1);
+        let signal_seen = seen.clone();
+        source.maybe_signal().maybe_effect(move |value, _| {
+            signal_seen.borrow_mut().push(*value)
+        });
+        source.set(2);
+        source.set(3);
+        assert_eq!(*seen.borrow(), [1, 2, 3]);
+    }
+
     // #[test]
     // fn into_maybe_iterator_inert() {
     //     let _deny_new_reactive = new_deny_new_scope();
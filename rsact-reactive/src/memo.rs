@@ -4,7 +4,7 @@ use crate::{
     read::{ReadSignal, SignalMap, impl_read_signal_traits},
     runtime::with_current_runtime,
     signal::Signal,
-    storage::ValueId,
+    storage::{MemoId, ValueId},
 };
 use alloc::{rc::Rc, vec::Vec};
 use core::{cell::RefCell, marker::PhantomData, ops::Deref, panic::Location};
@@ -94,8 +94,7 @@ where
 /// effects never observe a stale intermediate value.
 pub enum Memo<T: PartialEq> {
     Memo {
-        id: ValueId,
-        ty: PhantomData<T>,
+        id: MemoId<T>,
     },
     /// Identity-mapped signal as memo. Stored in memo as is to avoid creation
     /// of new memos for signals mapped as readonly identity values.
@@ -109,8 +108,9 @@ impl<T: PartialEq + 'static> Memo<T> {
     pub fn new<P: 'static>(f: impl CallbackFn<T, P> + 'static) -> Self {
         let caller = Location::caller();
         Self::Memo {
-            id: with_current_runtime(|rt| rt.create_memo(f, caller)),
-            ty: PhantomData,
+            id: MemoId::new(with_current_runtime(|rt| {
+                rt.create_memo(f, caller)
+            })),
         }
     }
 }
@@ -118,7 +118,7 @@ impl<T: PartialEq + 'static> Memo<T> {
 impl<T: PartialEq + 'static> Clone for Memo<T> {
     fn clone(&self) -> Self {
         match self {
-            &Memo::Memo { id, ty } => Self::Memo { id, ty },
+            &Memo::Memo { id } => Self::Memo { id },
             &Memo::Signal(signal) => Memo::Signal(signal),
         }
     }
@@ -130,16 +130,14 @@ impl<T: PartialEq + 'static> ReactiveValue for Memo<T> {
 
     fn id(&self) -> Option<ValueId> {
         match self {
-            Memo::Memo { id, ty: _ } => Some(*id),
+            Memo::Memo { id } => Some(id.untyped()),
             Memo::Signal(signal) => signal.id(),
         }
     }
 
     fn is_alive(&self) -> bool {
         match self {
-            &Memo::Memo { id, ty: _ } => {
-                with_current_runtime(|rt| rt.is_alive(id))
-            },
+            Memo::Memo { id } => with_current_runtime(|rt| id.is_alive(rt)),
             Memo::Signal(signal) => signal.is_alive(),
         }
     }
@@ -147,9 +145,7 @@ impl<T: PartialEq + 'static> ReactiveValue for Memo<T> {
     unsafe fn dispose(self) {
         unsafe {
             match self {
-                Memo::Memo { id, ty: _ } => {
-                    with_current_runtime(|rt| rt.dispose(id))
-                },
+                Memo::Memo { id } => with_current_runtime(|rt| id.dispose(rt)),
                 Memo::Signal(signal) => signal.dispose(),
             }
         }
@@ -160,9 +156,7 @@ impl<T: PartialEq + 'static> ReadSignal<T> for Memo<T> {
     #[track_caller]
     fn track(&self) {
         match self {
-            Memo::Memo { id, ty: _ } => {
-                with_current_runtime(|rt| id.subscribe(rt))
-            },
+            Memo::Memo { id } => with_current_runtime(|rt| id.subscribe(rt)),
             Memo::Signal(signal) => signal.track(),
         }
     }
@@ -170,12 +164,12 @@ impl<T: PartialEq + 'static> ReadSignal<T> for Memo<T> {
     #[track_caller]
     fn with_untracked<U>(&self, f: impl FnOnce(&T) -> U) -> U {
         match self {
-            &Memo::Memo { id, ty: _ } => {
+            Memo::Memo { id } => {
                 let caller = Location::caller();
                 with_current_runtime(|rt| {
                     id.with_untracked(
                         rt,
-                        |memoized: &Option<T>| {
+                        |memoized| {
                             f(memoized.as_ref().expect("Must already been set"))
                         },
                         caller,
@@ -0,0 +1,475 @@
+//! Signals persisted in a key-value store.
+//!
+//! [`create_persisted_signal`] makes a plain [`Signal`] that starts from the
+//! value saved under its key and saves every change back, so settings survive
+//! a reboot without any code in the widgets bound to them:
+//!
+//! ```rust,ignore
+//! let store = Rc::new(FileStore::new("settings"));
+//! let volume = create_persisted_signal("volume", 5u8, store.clone());
+//! ```
+//!
+//! Writes are debounced: a burst of changes (a slider being dragged) is saved
+//! once, [`PersistOptions::debounce`] after the last change, on the timer
+//! clock ([`tick_timers`](crate::timer::tick_timers), i.e. `UI::tick_time`).
+//! A change still waiting is saved when the signal's scope is disposed, or
+//! right away by [`flush`] (e.g. before powering off).
+//!
+//! Values are encoded with [`Recordable`], prefixed with the encoding version
+//! of [`PersistOptions::version`]. A value saved under another version goes
+//! through [`PersistOptions::migrate`], or is dropped for the default.
+
+use crate::{
+    effect::create_effect,
+    read::ReadSignal,
+    record::{Recordable, decode_varint, encode_varint},
+    runtime::with_current_runtime,
+    signal::{Signal, create_signal},
+    timer::Timer,
+};
+use alloc::{
+    rc::{Rc, Weak},
+    string::String,
+    vec::Vec,
+};
+use core::cell::RefCell;
+
+/// A key-value store of bytes, backing persisted signals.
+pub trait Store {
+    fn get(&self, key: &str) -> Option<Vec<u8>>;
+
+    /// Save `value` under `key`. Returns `false` if it could not be saved.
+    fn put(&self, key: &str, value: &[u8]) -> bool;
+}
+
+impl<S: Store + ?Sized> Store for Rc<S> {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        (**self).get(key)
+    }
+
+    fn put(&self, key: &str, value: &[u8]) -> bool {
+        (**self).put(key, value)
+    }
+}
+
+/// A [`Store`] in memory, for tests and the simulator.
+#[derive(Default)]
+pub struct MemoryStore {
+    /// Sorted by key.
+    entries: RefCell<Vec<(String, Vec<u8>)>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Store for MemoryStore {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let entries = self.entries.borrow();
+        entries
+            .binary_search_by(|(k, _)| k.as_str().cmp(key))
+            .ok()
+            .map(|pos| entries[pos].1.clone())
+    }
+
+    fn put(&self, key: &str, value: &[u8]) -> bool {
+        let mut entries = self.entries.borrow_mut();
+        match entries.binary_search_by(|(k, _)| k.as_str().cmp(key)) {
+            Ok(pos) => entries[pos].1 = value.to_vec(),
+            Err(pos) => entries.insert(pos, (key.into(), value.to_vec())),
+        }
+        true
+    }
+}
+
+/// A [`Store`] keeping each key in a file of a directory.
+///
+/// A value is written to a temporary file first and then renamed over the
+/// previous one, so a power loss mid-write leaves the old value intact.
+///
+/// A key must be a plain file name: keys that are empty, `.`, `..` or contain a
+/// path separator are rejected, so that no key reaches outside the directory.
+#[cfg(feature = "std")]
+pub struct FileStore {
+    dir: std::path::PathBuf,
+}
+
+#[cfg(feature = "std")]
+impl FileStore {
+    /// The directory is created on the first write if missing.
+    pub fn new(dir: impl Into<std::path::PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The file of `key`, `None` if `key` is not a plain file name.
+    fn path(&self, key: &str) -> Option<std::path::PathBuf> {
+        let mut components = std::path::Path::new(key).components();
+        let is_file_name = matches!(
+            (components.next(), components.next()),
+            (Some(std::path::Component::Normal(_)), None)
+        ) && !key.contains(['/', '\\']);
+
+        is_file_name.then(|| self.dir.join(key))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Store for FileStore {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        std::fs::read(self.path(key)?).ok()
+    }
+
+    fn put(&self, key: &str, value: &[u8]) -> bool {
+        let Some(path) = self.path(key) else {
+            log::error!("Invalid persisted key {key:?}: not a file name");
+            return false;
+        };
+        let tmp = self.dir.join(alloc::format!("{key}.tmp"));
+
+        let result = std::fs::create_dir_all(&self.dir)
+            .and_then(|_| std::fs::write(&tmp, value))
+            .and_then(|_| std::fs::rename(&tmp, &path));
+
+        if let Err(err) = &result {
+            log::error!("Failed to write {}: {err}", path.display());
+        }
+        result.is_ok()
+    }
+}
+
+/// Decodes a value saved under another version: gets that version and the
+/// encoded value.
+pub type Migrate<T> = fn(u32, &[u8]) -> Option<T>;
+
+/// Options of [`create_persisted_signal_with`].
+pub struct PersistOptions<T> {
+    /// Encoding version saved with the value.
+    pub version: u32,
+    /// Milliseconds without changes before a value is saved. `0` saves every
+    /// change at once.
+    pub debounce: u32,
+    /// Convert a value saved under an older (or newer) version.
+    pub migrate: Option<Migrate<T>>,
+}
+
+impl<T> Default for PersistOptions<T> {
+    fn default() -> Self {
+        Self { version: 0, debounce: 500, migrate: None }
+    }
+}
+
+impl<T> PersistOptions<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    pub fn debounce(mut self, millis: u32) -> Self {
+        self.debounce = millis;
+        self
+    }
+
+    pub fn migrate(mut self, migrate: Migrate<T>) -> Self {
+        self.migrate = Some(migrate);
+        self
+    }
+}
+
+/// A signal saved in `store` under `key`, starting from the saved value or
+/// `default`. See the [module docs](self).
+#[track_caller]
+pub fn create_persisted_signal<T: Recordable + 'static>(
+    key: &'static str,
+    default: T,
+    store: impl Store + 'static,
+) -> Signal<T> {
+    create_persisted_signal_with(key, default, store, PersistOptions::new())
+}
+
+/// [`create_persisted_signal`] with a versioned encoding, a migration of
+/// values saved under other versions, or another debounce delay.
+#[track_caller]
+pub fn create_persisted_signal_with<T: Recordable + 'static>(
+    key: &'static str,
+    default: T,
+    store: impl Store + 'static,
+    options: PersistOptions<T>,
+) -> Signal<T> {
+    let initial = store
+        .get(key)
+        .and_then(|saved| load(key, &saved, &options))
+        .unwrap_or(default);
+    let signal = create_signal(initial);
+
+    let pending =
+        Rc::new(PendingSave { key, store, bytes: RefCell::new(None) });
+    with_current_runtime(|rt| {
+        let mut saves = rt.persist.saves.borrow_mut();
+        saves.retain(|save| save.strong_count() > 0);
+        saves.push(Rc::downgrade(&pending) as Weak<dyn Flush>);
+    });
+
+    let debounce = options.debounce;
+    let timer = Timer::new(
+        debounce,
+        false,
+        {
+            let pending = pending.clone();
+            move || pending.flush()
+        },
+        false,
+    );
+
+    // The value is encoded on every change rather than when saved: the signal
+    // may be disposed before the save is, with the rest of its scope.
+    let version = options.version;
+    create_effect(move |prev: Option<()>| {
+        signal.with(|_| ());
+        // The first run only subscribes: the value is the saved one, or the
+        // default which needs no saving.
+        if prev.is_none() {
+            return;
+        }
+
+        let mut bytes = Vec::new();
+        encode_varint(u64::from(version), &mut bytes);
+        signal.with_untracked(|value| value.encode(&mut bytes));
+        *pending.bytes.borrow_mut() = Some(bytes);
+        if debounce == 0 {
+            pending.flush();
+        } else {
+            timer.restart();
+        }
+    });
+
+    signal
+}
+
+/// Save every persisted signal change still waiting for its debounce delay.
+pub fn flush() {
+    // Taken out of the runtime: a `Store` may touch reactive values.
+    let saves = with_current_runtime(|rt| {
+        let mut saves = rt.persist.saves.borrow_mut();
+        saves.retain(|save| save.strong_count() > 0);
+        saves.clone()
+    });
+
+    saves
+        .iter()
+        .filter_map(Weak::upgrade)
+        .for_each(|save| save.flush());
+}
+
+/// Per-runtime list of the persisted signals, for [`flush`].
+#[derive(Default)]
+pub(crate) struct PendingSaves {
+    saves: RefCell<Vec<Weak<dyn Flush>>>,
+}
+
+trait Flush {
+    fn flush(&self);
+}
+
+/// The encoded value of a persisted signal waiting to be saved. Shared by the
+/// debounce timer and the effect encoding the changes, so it is dropped with
+/// the last of them, and then saved.
+struct PendingSave<S: Store> {
+    key: &'static str,
+    store: S,
+    bytes: RefCell<Option<Vec<u8>>>,
+}
+
+impl<S: Store> Flush for PendingSave<S> {
+    fn flush(&self) {
+        let Some(bytes) = self.bytes.borrow_mut().take() else { return };
+        if !self.store.put(self.key, &bytes) {
+            log::error!("Failed to persist `{}`", self.key);
+        }
+    }
+}
+
+impl<S: Store> Drop for PendingSave<S> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+fn load<T: Recordable>(
+    key: &str,
+    saved: &[u8],
+    options: &PersistOptions<T>,
+) -> Option<T> {
+    let mut input = saved;
+    let Some(version) =
+        decode_varint(&mut input).and_then(|v| u32::try_from(v).ok())
+    else {
+        log::warn!("Saved `{key}` is empty, using the default");
+        return None;
+    };
+
+    let value = if version == options.version {
+        T::decode(&mut input)
+    } else if let Some(migrate) = options.migrate {
+        migrate(version, input)
+    } else {
+        log::warn!(
+            "Saved `{key}` has version {version} instead of {}, using the \
+             default",
+            options.version
+        );
+        return None;
+    };
+
+    if value.is_none() {
+        log::warn!("Failed to decode saved `{key}`, using the default");
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        MemoryStore, PersistOptions, Store, create_persisted_signal,
+        create_persisted_signal_with, flush,
+    };
+    use crate::{
+        prelude::*,
+        record::{Recordable, encode_varint},
+        scope::new_scope,
+        timer::tick_timers,
+    };
+    use alloc::{rc::Rc, string::String, vec::Vec};
+
+    #[test]
+    fn restores_saved_value_and_debounces_writes() {
+        let store = Rc::new(MemoryStore::new());
+
+        with_new_runtime(|_| {
+            let mut volume =
+                create_persisted_signal("volume", 5u8, store.clone());
+            assert_eq!(volume.get(), 5);
+            assert_eq!(store.get("volume"), None, "the default is not saved");
+
+            for (now, v) in [(0, 6), (100, 7), (200, 8), (300, 9)] {
+                tick_timers(now);
+                volume.set(v);
+            }
+            tick_timers(700);
+            assert_eq!(store.get("volume"), None, "saved 500ms after the last");
+
+            tick_timers(800);
+            assert_eq!(store.get("volume"), Some(alloc::vec![0, 9]));
+        });
+
+        with_new_runtime(|_| {
+            let volume = create_persisted_signal("volume", 5u8, store.clone());
+            assert_eq!(volume.get(), 9);
+        });
+    }
+
+    #[test]
+    fn migrates_older_versions() {
+        let store = Rc::new(MemoryStore::new());
+        // Version 1 saved the name alone, version 2 saves it with a size.
+        let mut old = Vec::new();
+        encode_varint(1, &mut old);
+        String::from("mono").encode(&mut old);
+        store.put("font", &old);
+
+        with_new_runtime(|_| {
+            let options = PersistOptions::new().version(2).debounce(0).migrate(
+                |version, mut bytes| match version {
+                    1 => Some((String::decode(&mut bytes)?, 8u8)),
+                    _ => None,
+                },
+            );
+            let mut font = create_persisted_signal_with(
+                "font",
+                (String::from("sans"), 10u8),
+                store.clone(),
+                options,
+            );
+            assert_eq!(font.get_cloned(), (String::from("mono"), 8));
+
+            font.update(|(_, size)| *size = 12);
+            let saved = store.get("font").unwrap();
+            assert_eq!(saved[0], 2, "saved under the new version");
+        });
+
+        with_new_runtime(|_| {
+            let font = create_persisted_signal(
+                "font",
+                (String::from("sans"), 10u8),
+                store.clone(),
+            );
+            assert_eq!(
+                font.get_cloned(),
+                (String::from("sans"), 10),
+                "version 2 can't be read without migration"
+            );
+        });
+    }
+
+    #[test]
+    fn pending_change_is_saved_on_flush_and_scope_disposal() {
+        let store = Rc::new(MemoryStore::new());
+
+        with_new_runtime(|_| {
+            let mut volume =
+                create_persisted_signal("volume", 5u8, store.clone());
+            volume.set(6);
+            flush();
+            assert_eq!(store.get("volume"), Some(alloc::vec![0, 6]));
+
+            let scope = new_scope();
+            let mut brightness =
+                create_persisted_signal("brightness", 50u8, store.clone());
+            scope.leave();
+            brightness.set(60);
+            assert_eq!(store.get("brightness"), None, "debounced");
+
+            drop(scope);
+            assert!(!brightness.is_alive());
+            assert_eq!(store.get("brightness"), Some(alloc::vec![0, 60]));
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_store_roundtrip() {
+        let dir = std::env::temp_dir()
+            .join(alloc::format!("rsact-persist-{}", std::process::id()));
+        let store = super::FileStore::new(&dir);
+
+        assert_eq!(store.get("missing"), None);
+        assert!(store.put("key", &[1, 2, 3]));
+        assert!(store.put("key", &[4]));
+        assert_eq!(store.get("key"), Some(alloc::vec![4]));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_store_rejects_keys_outside_its_dir() {
+        let root = std::env::temp_dir()
+            .join(alloc::format!("rsact-persist-keys-{}", std::process::id()));
+        let store = super::FileStore::new(root.join("store"));
+
+        for key in ["", ".", "..", "../escaped", "a/b", "a\\b", "/abs"] {
+            assert!(!store.put(key, &[1]), "{key:?} was accepted");
+            assert_eq!(store.get(key), None);
+        }
+        assert!(!root.join("escaped").exists());
+        assert!(store.put("..key", &[2]));
+        assert_eq!(store.get("..key"), Some(alloc::vec![2]));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -63,7 +63,10 @@
 //! # });
 //! ```
 
-use crate::{runtime::with_current_runtime, storage::ValueId};
+use crate::{
+    runtime::with_current_runtime,
+    storage::{ProbeId, ValueId},
+};
 use core::panic::Location;
 
 /// A `Copy` handle to an externally-polled reaction (see the [module
@@ -76,7 +79,7 @@ use core::panic::Location;
 /// `TinyVec<[(&'static str, Probe); 2]>`), which pad unused slots with
 /// `Default`; a real probe only ever comes from [`create_probe`].
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
-pub struct Probe(pub(crate) ValueId);
+pub struct Probe(pub(crate) ProbeId);
 
 /// Create a new [`Probe`], born dirty so its first [`poll`](Probe::poll) runs.
 ///
@@ -86,7 +89,7 @@ pub struct Probe(pub(crate) ValueId);
 #[track_caller]
 pub fn create_probe() -> Probe {
     let caller = Location::caller();
-    Probe(with_current_runtime(|rt| rt.create_probe(caller)))
+    Probe(ProbeId::new(with_current_runtime(|rt| rt.create_probe(caller))))
 }
 
 impl Probe {
@@ -96,18 +99,23 @@ impl Probe {
     #[track_caller]
     pub fn poll<R>(&self, force: bool, f: impl FnOnce() -> R) -> Option<R> {
         let caller = Location::caller();
-        with_current_runtime(|rt| rt.run_probe(self.0, force, caller, f))
+        with_current_runtime(|rt| {
+            if !self.0.check_runtime(rt, caller) {
+                return None;
+            }
+            rt.run_probe(self.0.untyped(), force, caller, f)
+        })
     }
 
     /// The underlying [`ValueId`] — for owners that store probes in their own
     /// maps and need a stable key.
     pub fn id(&self) -> ValueId {
-        self.0
+        self.0.untyped()
     }
 
     /// Whether this probe is still alive (not disposed).
     pub fn is_alive(&self) -> bool {
-        with_current_runtime(|rt| rt.is_alive(self.0))
+        with_current_runtime(|rt| self.0.is_alive(rt))
     }
 
     /// Dispose the probe, detaching it from the dependency graph. `unsafe` for
@@ -115,7 +123,7 @@ impl Probe {
     /// while a live edge still points at the node risks use-after-free. Prefer
     /// letting the owning scope dispose it.
     pub unsafe fn dispose(self) {
-        with_current_runtime(|rt| unsafe { rt.dispose(self.0) });
+        with_current_runtime(|rt| unsafe { self.0.dispose(rt) });
     }
 }
 
@@ -0,0 +1,647 @@
+//! Record and replay of reactive sessions.
+//!
+//! A [`Recorder`] logs, in a compact binary stream, everything that drives an
+//! app from outside: the clock, input events and writes to signals
+//! [watched](Recorder::watch) under a name (the same name
+//! [`ValueId::set_name`] shows under `debug-info`). A [`Replay`] reads the
+//! stream back, applying the writes to the signals [bound](Replay::bind) under
+//! the same names, and, together with `rsact-ui`'s `UI::replay_frame`, drives
+//! a fresh app through the same session frame by frame.
+//!
+//! Writes made while the recorder is [muted](Recorder::muted) are not logged:
+//! `UI` mutes it around its own ticks, as replaying the tick reproduces every
+//! write it caused.
+//!
+//! Values are encoded with [`Recordable`], implemented for primitives,
+//! strings, options, vecs and pairs.
+//!
+//! [`ValueId::set_name`]: crate::storage::ValueId
+
+use crate::{
+    ReactiveValue, effect::create_effect, read::ReadSignal, storage::ValueId,
+    stored::StoredValue, write::WriteSignal,
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::ops::Range;
+
+/// A value that can be written to and read from a recording.
+pub trait Recordable: Sized {
+    fn encode(&self, out: &mut Vec<u8>);
+
+    /// Decode a value from the front of `input`, advancing it. `None` if the
+    /// input is malformed.
+    fn decode(input: &mut &[u8]) -> Option<Self>;
+}
+
+/// LEB128 varint.
+pub fn encode_varint(mut value: u64, out: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+pub fn decode_varint(input: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = input.split_first()?;
+        *input = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+fn decode_bytes<'a>(input: &mut &'a [u8]) -> Option<&'a [u8]> {
+    let len = usize::try_from(decode_varint(input)?).ok()?;
+    if input.len() < len {
+        return None;
+    }
+    let (bytes, rest) = input.split_at(len);
+    *input = rest;
+    Some(bytes)
+}
+
+fn encode_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+    encode_varint(bytes.len() as u64, out);
+    out.extend_from_slice(bytes);
+}
+
+macro_rules! impl_recordable_unsigned {
+    ($($ty: ty),*) => {$(
+        impl Recordable for $ty {
+            fn encode(&self, out: &mut Vec<u8>) {
+                encode_varint(*self as u64, out);
+            }
+
+            fn decode(input: &mut &[u8]) -> Option<Self> {
+                decode_varint(input)?.try_into().ok()
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_recordable_signed {
+    ($($ty: ty),*) => {$(
+        impl Recordable for $ty {
+            fn encode(&self, out: &mut Vec<u8>) {
+                // Zigzag, so small negative numbers stay short.
+                let value = *self as i64;
+                encode_varint(((value << 1) ^ (value >> 63)) as u64, out);
+            }
+
+            fn decode(input: &mut &[u8]) -> Option<Self> {
+                let value = decode_varint(input)?;
+                let value = (value >> 1) as i64 ^ -((value & 1) as i64);
+                value.try_into().ok()
+            }
+        }
+    )*};
+}
+
+impl_recordable_unsigned!(u8, u16, u32, u64, usize);
+impl_recordable_signed!(i8, i16, i32, i64, isize);
+
+macro_rules! impl_recordable_float {
+    ($($ty: ty),*) => {$(
+        impl Recordable for $ty {
+            fn encode(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn decode(input: &mut &[u8]) -> Option<Self> {
+                const SIZE: usize = core::mem::size_of::<$ty>();
+                let (bytes, rest) = input.split_first_chunk::<SIZE>()?;
+                *input = rest;
+                Some(<$ty>::from_le_bytes(*bytes))
+            }
+        }
+    )*};
+}
+
+impl_recordable_float!(f32, f64);
+
+impl Recordable for bool {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        match u8::decode(input)? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl Recordable for char {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (*self as u32).encode(out);
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        char::from_u32(u32::decode(input)?)
+    }
+}
+
+impl Recordable for () {
+    fn encode(&self, _out: &mut Vec<u8>) {}
+
+    fn decode(_input: &mut &[u8]) -> Option<Self> {
+        Some(())
+    }
+}
+
+impl Recordable for String {
+    fn encode(&self, out: &mut Vec<u8>) {
+        encode_bytes(self.as_bytes(), out);
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let bytes = decode_bytes(input)?;
+        core::str::from_utf8(bytes).ok().map(String::from)
+    }
+}
+
+impl<T: Recordable> Recordable for Option<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            None => out.push(0),
+            Some(value) => {
+                out.push(1);
+                value.encode(out);
+            },
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        match bool::decode(input)? {
+            false => Some(None),
+            true => T::decode(input).map(Some),
+        }
+    }
+}
+
+impl<T: Recordable> Recordable for Vec<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.len().encode(out);
+        self.iter().for_each(|item| item.encode(out));
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let len = usize::decode(input)?;
+        // Don't trust the length for the allocation: a malformed stream could
+        // claim any size.
+        let mut items = Vec::with_capacity(len.min(input.len()));
+        for _ in 0..len {
+            items.push(T::decode(input)?);
+        }
+        Some(items)
+    }
+}
+
+impl<A: Recordable, B: Recordable> Recordable for (A, B) {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.0.encode(out);
+        self.1.encode(out);
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        Some((A::decode(input)?, B::decode(input)?))
+    }
+}
+
+/// Stream entry tags.
+mod tag {
+    /// `index, name`: assigns `index` to the name of a recorded signal, before
+    /// its first write.
+    pub const NAME: u8 = 0;
+    /// `now`: the clock advanced.
+    pub const TIME: u8 = 1;
+    /// `count, count × bytes`: one `UI::tick` with its events.
+    pub const TICK: u8 = 2;
+    /// `index, bytes`: a write to the named signal `index`.
+    pub const WRITE: u8 = 3;
+}
+
+struct RecorderState {
+    data: Vec<u8>,
+    /// Names with their stream index, sorted by name.
+    names: Vec<(&'static str, u32)>,
+    muted: u32,
+}
+
+impl RecorderState {
+    fn name_index(&mut self, name: &'static str) -> u32 {
+        match self.names.binary_search_by(|(n, _)| n.cmp(&name)) {
+            Ok(pos) => self.names[pos].1,
+            Err(pos) => {
+                let index = self.names.len() as u32;
+                self.names.insert(pos, (name, index));
+                self.data.push(tag::NAME);
+                encode_varint(u64::from(index), &mut self.data);
+                encode_bytes(name.as_bytes(), &mut self.data);
+                index
+            },
+        }
+    }
+}
+
+/// Records a session, see the [module docs](self).
+///
+/// Disposed together with its owner scope or observer, which also stops
+/// recording the signals registered through it.
+pub struct Recorder {
+    state: StoredValue<RecorderState>,
+}
+
+impl Clone for Recorder {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for Recorder {}
+
+impl ReactiveValue for Recorder {
+    type Value = ();
+
+    fn id(&self) -> Option<ValueId> {
+        self.state.id()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.state.is_alive()
+    }
+
+    unsafe fn dispose(self) {
+        unsafe { self.state.dispose() };
+    }
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Recorder {
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            state: StoredValue::new(RecorderState {
+                data: Vec::new(),
+                names: Vec::new(),
+                muted: 0,
+            }),
+        }
+    }
+
+    /// Record every later change of `signal` under `name`. Names must be
+    /// unique among the recorded signals, and the same in the replaying app.
+    #[track_caller]
+    pub fn watch<T: Recordable + 'static>(
+        &self,
+        name: &'static str,
+        signal: impl ReadSignal<T> + 'static,
+    ) -> &Self {
+        #[cfg(feature = "debug-info")]
+        if let Some(id) = signal.id() {
+            id.set_name(name);
+        }
+
+        let recorder = *self;
+        create_effect(move |prev: Option<()>| {
+            signal.with(|value| {
+                // The first run only subscribes: the replaying app starts from
+                // the same initial value.
+                if prev.is_some() {
+                    recorder.write(name, value);
+                }
+            });
+        });
+
+        self
+    }
+
+    /// Record the clock advancing to `now_millis`.
+    pub fn time(&self, now_millis: u32) {
+        self.with_state(|state| {
+            state.data.push(tag::TIME);
+            encode_varint(u64::from(now_millis), &mut state.data);
+        });
+    }
+
+    /// Record one tick handling `events`.
+    pub fn tick<E: Recordable>(&self, events: &[E]) {
+        self.with_state(|state| {
+            state.data.push(tag::TICK);
+            encode_varint(events.len() as u64, &mut state.data);
+
+            let mut event = Vec::new();
+            for e in events {
+                event.clear();
+                e.encode(&mut event);
+                encode_bytes(&event, &mut state.data);
+            }
+        });
+    }
+
+    /// Run `f` without recording signal writes made (and effects flushed)
+    /// meanwhile.
+    pub fn muted<U>(&self, f: impl FnOnce() -> U) -> U {
+        self.with_state(|state| state.muted += 1);
+        let result = f();
+        self.with_state(|state| state.muted -= 1);
+        result
+    }
+
+    /// The recorded stream so far.
+    pub fn bytes(&self) -> Vec<u8> {
+        if !self.is_alive() {
+            return Vec::new();
+        }
+        self.state.with_untracked(|state| state.data.clone())
+    }
+
+    fn write<T: Recordable>(&self, name: &'static str, value: &T) {
+        self.with_state(|state| {
+            if state.muted > 0 {
+                return;
+            }
+
+            let index = state.name_index(name);
+            state.data.push(tag::WRITE);
+            encode_varint(u64::from(index), &mut state.data);
+
+            let mut bytes = Vec::new();
+            value.encode(&mut bytes);
+            encode_bytes(&bytes, &mut state.data);
+        });
+    }
+
+    fn with_state(&self, f: impl FnOnce(&mut RecorderState)) {
+        if self.is_alive() {
+            let mut state = self.state;
+            state.update_untracked(f);
+        } else {
+            log::warn!("Using a disposed recorder");
+        }
+    }
+}
+
+/// What a [`Replay`] step asks the app to do. Recorded writes are applied by
+/// the replay itself.
+pub enum ReplayStep<'a> {
+    /// Advance the clock (`UI::tick_time`).
+    Time(u32),
+    /// Run one tick with these events (`UI::tick`).
+    Tick(ReplayEvents<'a>),
+}
+
+/// Encoded events of a [`ReplayStep::Tick`].
+pub struct ReplayEvents<'a> {
+    data: &'a [u8],
+    left: usize,
+}
+
+impl<'a> Iterator for ReplayEvents<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.left == 0 {
+            return None;
+        }
+        self.left -= 1;
+        decode_bytes(&mut self.data)
+    }
+}
+
+type Setter = Box<dyn FnMut(&[u8]) -> bool>;
+
+/// Replays a stream made by a [`Recorder`], see the [module docs](self).
+pub struct Replay {
+    data: Vec<u8>,
+    pos: usize,
+    /// Name of each stream index, as a range of `data`.
+    names: Vec<Range<usize>>,
+    /// Signals to apply writes to, sorted by name.
+    signals: Vec<(&'static str, Setter)>,
+}
+
+impl Replay {
+    pub fn new(data: Vec<u8>) -> Self {
+        Self { data, pos: 0, names: Vec::new(), signals: Vec::new() }
+    }
+
+    /// Apply the writes recorded under `name` to `signal`.
+    pub fn bind<T: Recordable + 'static>(
+        &mut self,
+        name: &'static str,
+        mut signal: impl WriteSignal<T> + 'static,
+    ) -> &mut Self {
+        let setter: Setter =
+            Box::new(move |mut bytes| match T::decode(&mut bytes) {
+                Some(value) => {
+                    signal.set(value);
+                    true
+                },
+                None => false,
+            });
+
+        match self.signals.binary_search_by(|(n, _)| n.cmp(&name)) {
+            Ok(pos) => self.signals[pos].1 = setter,
+            Err(pos) => self.signals.insert(pos, (name, setter)),
+        }
+
+        self
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    /// Replay one frame: a clock advance and the writes and ticks up to the
+    /// next one, passing ticks and the clock advance to `f`. Returns `false`
+    /// if the stream was already over (or is malformed, which is logged).
+    pub fn frame(&mut self, mut f: impl FnMut(ReplayStep<'_>)) -> bool {
+        let start = self.pos;
+        while let Some(&tag) = self.data.get(self.pos) {
+            if tag == tag::TIME && self.pos != start {
+                break;
+            }
+
+            if self.step(&mut f).is_none() {
+                log::error!(
+                    "Malformed recording at byte {}, stopping replay",
+                    self.pos
+                );
+                self.pos = self.data.len();
+                return false;
+            }
+        }
+        self.pos != start
+    }
+
+    /// Replay the whole stream.
+    pub fn run(&mut self, mut f: impl FnMut(ReplayStep<'_>)) {
+        while self.frame(&mut f) {}
+    }
+
+    /// Apply one entry, `None` if malformed.
+    fn step(&mut self, f: &mut impl FnMut(ReplayStep<'_>)) -> Option<()> {
+        let mut input = &self.data[self.pos..];
+        let (&tag, rest) = input.split_first()?;
+        input = rest;
+
+        match tag {
+            tag::NAME => {
+                let index = usize::try_from(decode_varint(&mut input)?).ok()?;
+                let name = decode_bytes(&mut input)?;
+                core::str::from_utf8(name).ok()?;
+                if index != self.names.len() {
+                    return None;
+                }
+                let start = self.data.len() - input.len() - name.len();
+                self.names.push(start..start + name.len());
+            },
+            tag::TIME => {
+                let now = u32::try_from(decode_varint(&mut input)?).ok()?;
+                f(ReplayStep::Time(now));
+            },
+            tag::TICK => {
+                let left = usize::try_from(decode_varint(&mut input)?).ok()?;
+                let start = input;
+                let mut events = ReplayEvents { data: input, left };
+                for _ in 0..left {
+                    events.next()?;
+                }
+                input = events.data;
+                f(ReplayStep::Tick(ReplayEvents { data: start, left }));
+            },
+            tag::WRITE => {
+                let index = usize::try_from(decode_varint(&mut input)?).ok()?;
+                let value = decode_bytes(&mut input)?;
+                let name = self.names.get(index)?.clone();
+                // The name range was validated as UTF-8 when defined.
+                let name = core::str::from_utf8(&self.data[name]).ok()?;
+
+                match self.signals.binary_search_by(|(n, _)| (*n).cmp(name)) {
+                    Ok(pos) => {
+                        if !(self.signals[pos].1)(value) {
+                            log::warn!(
+                                "Failed to decode recorded write to `{name}`"
+                            );
+                        }
+                    },
+                    Err(_) => {
+                        log::warn!(
+                            "Recorded write to unknown signal `{name}` skipped"
+                        )
+                    },
+                }
+            },
+            _ => return None,
+        }
+
+        self.pos = self.data.len() - input.len();
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Recordable, Recorder, Replay, ReplayStep};
+    use crate::{prelude::*, runtime::with_new_runtime};
+    use alloc::{string::String, vec, vec::Vec};
+
+    fn roundtrip<T: Recordable + PartialEq + core::fmt::Debug>(value: T) {
+        let mut out = Vec::new();
+        value.encode(&mut out);
+        let mut input = out.as_slice();
+        assert_eq!(T::decode(&mut input), Some(value));
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn values_roundtrip() {
+        roundtrip(0u8);
+        roundtrip(u64::MAX);
+        roundtrip(i32::MIN);
+        roundtrip(-1i64);
+        roundtrip(1.5f32);
+        roundtrip(true);
+        roundtrip('ы');
+        roundtrip(String::from("text"));
+        roundtrip(Some((3u16, -3i16)));
+        roundtrip(vec![None, Some(1u32)]);
+
+        let mut out = Vec::new();
+        (-1i32).encode(&mut out);
+        assert_eq!(out, [1], "small negatives stay short");
+        assert_eq!(bool::decode(&mut &[2u8][..]), None);
+        assert_eq!(String::decode(&mut &[5u8, b'a'][..]), None);
+    }
+
+    #[test]
+    fn replay_reproduces_writes_and_ticks() {
+        let data = with_new_runtime(|_| {
+            let recorder = Recorder::new();
+            let mut count = create_signal(0u32);
+            let mut name = create_signal(String::new());
+            recorder.watch("count", count).watch("name", name);
+
+            recorder.time(10);
+            count.set(1);
+            recorder.muted(|| count.set(2));
+            recorder.tick(&[7u8, 8]);
+            recorder.time(20);
+            name.set("x".into());
+            count.set(3);
+
+            recorder.bytes()
+        });
+
+        with_new_runtime(|_| {
+            let count = create_signal(0u32);
+            let name = create_signal(String::new());
+            let mut replay = Replay::new(data.clone());
+            replay.bind("count", count).bind("name", name);
+
+            let mut steps = Vec::new();
+            let mut log = |step: ReplayStep<'_>| match step {
+                ReplayStep::Time(now) => steps.push((now, Vec::new())),
+                ReplayStep::Tick(events) => steps.push((
+                    u32::MAX,
+                    events.flat_map(|e| e.iter().copied()).collect(),
+                )),
+            };
+
+            assert!(replay.frame(&mut log));
+            assert_eq!(count.get(), 1, "muted write not recorded");
+            assert!(replay.frame(&mut log));
+            assert_eq!(
+                (count.get(), name.get_cloned()),
+                (3, String::from("x"))
+            );
+            assert!(!replay.frame(&mut log));
+            assert!(replay.is_finished());
+            assert_eq!(
+                steps,
+                [(10, vec![]), (u32::MAX, vec![7, 8]), (20, vec![])]
+            );
+
+            let mut truncated = Replay::new(data[..data.len() - 1].to_vec());
+            truncated.run(|_| {});
+            assert!(truncated.is_finished());
+        });
+    }
+}
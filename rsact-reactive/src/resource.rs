@@ -4,33 +4,57 @@ use crate::{
     effect::create_effect,
     read::ReadSignal,
     signal::{Signal, create_signal},
+    timer::Timer,
+    trigger::Trigger,
     write::WriteSignal,
 };
 use alloc::{boxed::Box, rc::Rc};
 use core::{
     cell::{Cell, RefCell},
+    convert::Infallible,
     future::{Future, poll_fn},
-    pin::Pin,
+    pin::{Pin, pin},
+    task::Poll,
 };
 
 /// A reactive handle to an asynchronously-computed value.
 ///
-/// `Resource<T>` wraps a `Signal<AsyncState<T>>` and participates in the
+/// `Resource<T, E>` wraps a `Signal<AsyncState<T, E>>` and participates in the
 /// reactive graph just like any other signal: any effect or memo that reads it
 /// will re-run when the async state changes.
 ///
-/// Create a resource with [`create_resource`].
-pub struct Resource<T: 'static> {
-    signal: Signal<AsyncState<T>>,
+/// Create a resource with [`create_resource`] (infallible fetch) or
+/// [`create_resource_with`] (fallible fetch, retries, stale-while-revalidate).
+pub struct Resource<T: 'static, E: 'static = Infallible> {
+    signal: Signal<AsyncState<T, E>>,
+    refetch: Trigger,
 }
 
-impl<T: Clone + 'static> Resource<T> {
+impl<T: 'static, E: 'static> Clone for Resource<T, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static, E: 'static> Copy for Resource<T, E> {}
+
+impl<T: Clone + 'static, E: Clone + 'static> Resource<T, E> {
     /// Returns the inner value if the resource is [`AsyncState::Ready`], else
     /// `None`.
     pub fn ready(&self) -> Option<T> {
         self.signal.with(|s| s.ready().cloned())
     }
 
+    /// Returns the ready value, or the stale one kept while reloading.
+    pub fn latest(&self) -> Option<T> {
+        self.signal.with(|s| s.latest().cloned())
+    }
+
+    /// Returns the error of the last fetch if it failed.
+    pub fn error(&self) -> Option<E> {
+        self.signal.with(|s| s.error().cloned())
+    }
+
     /// Returns `true` if a fetch is currently in progress.
     pub fn is_loading(&self) -> bool {
         self.signal.with(|s| s.is_loading())
@@ -42,7 +66,15 @@ impl<T: Clone + 'static> Resource<T> {
     }
 }
 
-impl<T: 'static> ReactiveValue for Resource<T> {
+impl<T: 'static, E: 'static> Resource<T, E> {
+    /// Fetch again with the current source value, superseding (and dropping)
+    /// any fetch in flight and any pending retry.
+    pub fn refetch(&self) {
+        self.refetch.notify();
+    }
+}
+
+impl<T: 'static, E: 'static> ReactiveValue for Resource<T, E> {
     type Value = T;
 
     fn id(&self) -> Option<crate::storage::ValueId> {
@@ -54,20 +86,86 @@ impl<T: 'static> ReactiveValue for Resource<T> {
     }
 
     unsafe fn dispose(self) {
-        self.signal.dispose();
+        unsafe {
+            self.refetch.dispose();
+            self.signal.dispose();
+        }
     }
 }
 
-impl<T: 'static> ReadSignal<AsyncState<T>> for Resource<T> {
+impl<T: 'static, E: 'static> ReadSignal<AsyncState<T, E>> for Resource<T, E> {
     fn track(&self) {
         self.signal.track();
     }
 
-    fn with_untracked<U>(&self, f: impl FnOnce(&AsyncState<T>) -> U) -> U {
+    fn with_untracked<U>(&self, f: impl FnOnce(&AsyncState<T, E>) -> U) -> U {
         self.signal.with_untracked(f)
     }
 }
 
+/// The future driving a [`Resource`]'s fetches, see [`create_resource`].
+pub type ResourceDriver = Pin<Box<dyn Future<Output = ()> + 'static>>;
+
+/// Exponential backoff for retrying failed fetches: retry `n` (from 1) waits
+/// `base_delay_millis * 2^(n - 1)`, capped at `max_delay_millis`.
+///
+/// Delays are measured on the [timer](crate::timer) clock, so they only
+/// elapse as the application ticks it (`UI::tick_time` in `rsact-ui`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Backoff {
+    pub max_retries: u32,
+    pub base_delay_millis: u32,
+    pub max_delay_millis: u32,
+}
+
+impl Backoff {
+    pub fn new(max_retries: u32, base_delay_millis: u32) -> Self {
+        Self { max_retries, base_delay_millis, max_delay_millis: u32::MAX }
+    }
+
+    pub fn max_delay(mut self, max_delay_millis: u32) -> Self {
+        self.max_delay_millis = max_delay_millis;
+        self
+    }
+
+    /// Delay before retry number `retry` (1-based).
+    pub fn delay(&self, retry: u32) -> u32 {
+        let factor = 1u32.checked_shl(retry.saturating_sub(1)).unwrap_or(0);
+        let delay = match factor {
+            0 => u32::MAX,
+            factor => self.base_delay_millis.saturating_mul(factor),
+        };
+        delay.min(self.max_delay_millis)
+    }
+}
+
+/// Options of [`create_resource_with`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ResourceOptions {
+    /// Retry failed fetches. Without it an error stays until the source
+    /// changes or [`Resource::refetch`] is called.
+    pub retry: Option<Backoff>,
+    /// Keep the previous value while refetching ([`AsyncState::Reloading`])
+    /// instead of going back to [`AsyncState::Loading`].
+    pub stale_while_revalidate: bool,
+}
+
+impl ResourceOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn retry(mut self, backoff: Backoff) -> Self {
+        self.retry = Some(backoff);
+        self
+    }
+
+    pub fn stale_while_revalidate(mut self) -> Self {
+        self.stale_while_revalidate = true;
+        self
+    }
+}
+
 /// Creates a reactive [`Resource`] whose value is produced by an async fetch.
 ///
 /// Returns `(resource, driver)`:
@@ -79,8 +177,11 @@ impl<T: 'static> ReadSignal<AsyncState<T>> for Resource<T> {
 ///   state changes.
 ///
 /// - **`driver`** is a `Future<Output = ()>` that must be driven by the
-///   caller's executor. The driver runs forever, re-fetching whenever the
-///   reactive source changes.
+///   caller's executor. The driver runs until the resource is disposed,
+///   re-fetching whenever the reactive source changes.
+///
+/// For fallible fetches, retries and stale-while-revalidate see
+/// [`create_resource_with`].
 ///
 /// # Executor integration
 ///
@@ -104,76 +205,167 @@ impl<T: 'static> ReadSignal<AsyncState<T>> for Resource<T> {
 /// # Cancellation
 ///
 /// When `source_fn` returns a new value, the current in-flight fetch is
-/// dropped immediately (Rust's cooperative cancellation). A generation counter
-/// prevents any stale result that was already computing from being committed.
+/// dropped on the driver's next poll (Rust's cooperative cancellation) and a
+/// fetch for the new value starts; the superseded result is never committed.
 pub fn create_resource<T, S, SF, F, Fut>(
     source_fn: SF,
     fetcher: F,
-) -> (Resource<T>, Pin<Box<dyn Future<Output = ()> + 'static>>)
+) -> (Resource<T>, ResourceDriver)
 where
     T: 'static,
     S: Clone + 'static,
     SF: Fn() -> S + 'static,
     F: Fn(S) -> Fut + 'static,
     Fut: Future<Output = T> + 'static,
+{
+    create_resource_with(
+        source_fn,
+        move |source| {
+            let fetch = fetcher(source);
+            async move { Ok(fetch.await) }
+        },
+        ResourceOptions::new(),
+    )
+}
+
+/// [`create_resource`] for a fallible `fetcher`: a failed fetch sets
+/// [`AsyncState::Error`] and, with [`ResourceOptions::retry`], is retried with
+/// backoff until it succeeds, the retries run out, or the source changes.
+pub fn create_resource_with<T, E, S, SF, F, Fut>(
+    source_fn: SF,
+    fetcher: F,
+    options: ResourceOptions,
+) -> (Resource<T, E>, ResourceDriver)
+where
+    T: 'static,
+    E: 'static,
+    S: Clone + 'static,
+    SF: Fn() -> S + 'static,
+    F: Fn(S) -> Fut + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
 {
     let mut signal = create_signal(AsyncState::Uninitialized);
+    let refetch = Trigger::new();
     let notify = Rc::new(AsyncNotify::new());
     let current_source: Rc<RefCell<Option<S>>> = Rc::new(RefCell::new(None));
     let generation: Rc<Cell<u32>> = Rc::new(Cell::new(0));
+    let retry_due = Rc::new(Cell::new(false));
 
-    // Sync reactive effect: tracks source_fn(), stores the result, and wakes
-    // the driver every time the source changes. This runs once immediately on
-    // creation, which sets `notify` to pending so the driver fires its first
-    // fetch as soon as it is first polled.
+    // Sync reactive effect: tracks source_fn() (and refetch requests), stores
+    // the result, and wakes the driver every time the source changes. This
+    // runs once immediately on creation, which sets `notify` to pending so the
+    // driver fires its first fetch as soon as it is first polled.
     let notify_eff = notify.clone();
     let source_eff = current_source.clone();
     let gen_eff = generation.clone();
 
     create_effect(move |_: Option<()>| {
+        refetch.track();
         let src = source_fn();
         *source_eff.borrow_mut() = Some(src);
         gen_eff.set(gen_eff.get().wrapping_add(1));
         notify_eff.notify();
     });
 
-    // Async driver: loops forever, suspending between source changes and
-    // running the user-supplied async fetcher each time.
-    let driver: Pin<Box<dyn Future<Output = ()> + 'static>> =
-        Box::pin(async move {
-            loop {
-                // Suspend until the reactive effect notifies us of a change.
-                poll_fn(|cx| notify.poll_wait(cx)).await;
-
-                // Exit gracefully if the resource signal was disposed (e.g.
-                // its owning scope was dropped) while the driver was suspended.
-                // Without this guard, calling signal.set() on a dead ValueId
-                // would panic.
-                if !signal.is_alive() {
-                    break;
-                }
-
-                let src = match current_source.borrow().clone() {
-                    Some(s) => s,
-                    None => continue,
-                };
-
-                let current_gen = generation.get();
-                signal.set(AsyncState::Loading);
+    let notify_retry = notify.clone();
+    let retry_due_timer = retry_due.clone();
+    let retry_timer = Timer::new(
+        0,
+        false,
+        move || {
+            retry_due_timer.set(true);
+            notify_retry.notify();
+        },
+        false,
+    );
+
+    // Async driver: loops until the resource is disposed, suspending between
+    // source changes (or retries) and running the user-supplied async fetcher
+    // each time.
+    let driver: ResourceDriver = Box::pin(async move {
+        // Generation of the last started fetch; the effect bumps the
+        // counter to 1 on creation, so the first poll always fetches.
+        let mut fetched_gen = 0;
+        let mut retries = 0;
+
+        loop {
+            // Suspend until the reactive effect or the retry timer
+            // notifies us.
+            poll_fn(|cx| notify.poll_wait(cx)).await;
+
+            // Exit gracefully if the resource signal was disposed (e.g.
+            // its owning scope was dropped) while the driver was suspended.
+            // Without this guard, calling signal.set() on a dead ValueId
+            // would panic.
+            if !signal.is_alive() {
+                break;
+            }
 
-                let value = fetcher(src).await;
+            let src = match current_source.borrow().clone() {
+                Some(s) => s,
+                None => continue,
+            };
+
+            let current_gen = generation.get();
+            if current_gen != fetched_gen {
+                // New source value or refetch: a pending retry is moot.
+                fetched_gen = current_gen;
+                retries = 0;
+                retry_due.set(false);
+                retry_timer.cancel();
+            } else if !retry_due.replace(false) {
+                continue;
+            }
 
-                // Guard against stale results: if the source changed while we
-                // were fetching, the generation counter will have advanced.
-                // Also re-check liveness: a side-effect triggered by the
-                // Loading transition above could have disposed the signal.
-                if generation.get() == current_gen && signal.is_alive() {
-                    signal.set(AsyncState::Ready(value));
+            signal.update(|state| {
+                *state = match core::mem::replace(state, AsyncState::Loading) {
+                    AsyncState::Ready(value) | AsyncState::Reloading(value)
+                        if options.stale_while_revalidate =>
+                    {
+                        AsyncState::Reloading(value)
+                    },
+                    _ => AsyncState::Loading,
                 }
+            });
+
+            // Race the fetch against source changes: once the generation
+            // moves on, the superseded fetch is dropped right away instead
+            // of running to completion just to be discarded.
+            let result = {
+                let mut fetch = pin!(fetcher(src));
+                poll_fn(|cx| {
+                    if generation.get() != current_gen {
+                        return Poll::Ready(None);
+                    }
+                    notify.register(cx);
+                    fetch.as_mut().poll(cx).map(Some)
+                })
+                .await
+            };
+
+            // Also re-check liveness: a side-effect triggered by the
+            // Loading transition above could have disposed the signal.
+            let Some(result) = result else { continue };
+            if generation.get() != current_gen || !signal.is_alive() {
+                continue;
             }
-        });
 
-    (Resource { signal }, driver)
+            match result {
+                Ok(value) => signal.set(AsyncState::Ready(value)),
+                Err(error) => {
+                    if let Some(backoff) = options.retry
+                        && retries < backoff.max_retries
+                    {
+                        retries += 1;
+                        retry_timer.start_in(backoff.delay(retries));
+                    }
+                    signal.set(AsyncState::Error(error));
+                },
+            }
+        }
+    });
+
+    (Resource { signal, refetch }, driver)
 }
 
 #[cfg(test)]
@@ -182,7 +374,7 @@ mod tests {
     use crate::prelude::*;
     use alloc::rc::Rc;
     use core::{
-        cell::Cell,
+        cell::{Cell, RefCell},
         future::Future,
         pin::pin,
         task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
@@ -305,4 +497,235 @@ mod tests {
             assert_eq!(resource.ready(), Some(30u32));
         });
     }
+
+    /// Local dummy executor: polls one future for as long as it keeps waking
+    /// itself, then reports whether it completed.
+    struct DummyExecutor {
+        woken: alloc::sync::Arc<Woken>,
+        waker: Waker,
+    }
+
+    struct Woken(core::sync::atomic::AtomicBool);
+
+    impl alloc::task::Wake for Woken {
+        fn wake(self: alloc::sync::Arc<Self>) {
+            self.0.store(true, core::sync::atomic::Ordering::Relaxed);
+        }
+    }
+
+    impl DummyExecutor {
+        fn new() -> Self {
+            let woken = alloc::sync::Arc::new(Woken(
+                core::sync::atomic::AtomicBool::new(false),
+            ));
+            Self { waker: Waker::from(woken.clone()), woken }
+        }
+
+        fn run_until_stalled<F: Future>(
+            &self,
+            mut fut: core::pin::Pin<&mut F>,
+        ) -> bool {
+            let mut cx = Context::from_waker(&self.waker);
+            loop {
+                self.woken
+                    .0
+                    .store(false, core::sync::atomic::Ordering::Relaxed);
+                if fut.as_mut().poll(&mut cx).is_ready() {
+                    return true;
+                }
+                if !self.woken.0.load(core::sync::atomic::Ordering::Relaxed) {
+                    return false;
+                }
+            }
+        }
+    }
+
+    /// A fetch completed by hand, counting the fetches dropped unfinished.
+    #[derive(Clone, Default)]
+    struct Deferred {
+        result: Rc<RefCell<Option<Result<u32, &'static str>>>>,
+        waker: Rc<RefCell<Option<Waker>>>,
+        dropped: Rc<Cell<u32>>,
+    }
+
+    impl Deferred {
+        fn resolve(&self, result: Result<u32, &'static str>) {
+            *self.result.borrow_mut() = Some(result);
+            if let Some(waker) = self.waker.borrow_mut().take() {
+                waker.wake();
+            }
+        }
+
+        async fn fetch(self) -> Result<u32, &'static str> {
+            struct DropGuard(Rc<Cell<u32>>, bool);
+            impl Drop for DropGuard {
+                fn drop(&mut self) {
+                    if !self.1 {
+                        self.0.set(self.0.get() + 1);
+                    }
+                }
+            }
+
+            let mut guard = DropGuard(self.dropped.clone(), false);
+            let result = core::future::poll_fn(|cx| {
+                match self.result.borrow_mut().take() {
+                    Some(result) => Poll::Ready(result),
+                    None => {
+                        *self.waker.borrow_mut() = Some(cx.waker().clone());
+                        Poll::Pending
+                    },
+                }
+            })
+            .await;
+            guard.1 = true;
+            result
+        }
+    }
+
+    #[test]
+    fn failed_fetch_retries_with_backoff() {
+        with_new_runtime(|_| {
+            let attempts = Rc::new(Cell::new(0u32));
+            let counter = attempts.clone();
+            let (resource, driver) = create_resource_with(
+                || (),
+                move |()| {
+                    counter.set(counter.get() + 1);
+                    let attempt = counter.get();
+                    async move { if attempt < 3 { Err("io") } else { Ok(7u32) } }
+                },
+                ResourceOptions::new().retry(Backoff::new(5, 100)),
+            );
+
+            let executor = DummyExecutor::new();
+            let mut driver = pin!(driver);
+
+            executor.run_until_stalled(driver.as_mut());
+            assert_eq!(resource.error(), Some("io"));
+            assert_eq!(crate::timer::next_timer_deadline(), Some(100));
+
+            crate::timer::tick_timers(100);
+            executor.run_until_stalled(driver.as_mut());
+            assert_eq!(attempts.get(), 2);
+            assert_eq!(resource.error(), Some("io"));
+            assert_eq!(
+                crate::timer::next_timer_deadline(),
+                Some(300),
+                "second retry waits twice as long"
+            );
+
+            crate::timer::tick_timers(300);
+            executor.run_until_stalled(driver.as_mut());
+            assert_eq!(resource.ready(), Some(7));
+            assert_eq!(crate::timer::next_timer_deadline(), None);
+        });
+    }
+
+    #[test]
+    fn retries_run_out() {
+        with_new_runtime(|_| {
+            let (resource, driver) = create_resource_with(
+                || (),
+                |()| async { Err::<u32, _>("io") },
+                ResourceOptions::new().retry(Backoff::new(1, 10)),
+            );
+
+            let executor = DummyExecutor::new();
+            let mut driver = pin!(driver);
+            executor.run_until_stalled(driver.as_mut());
+            crate::timer::tick_timers(10);
+            executor.run_until_stalled(driver.as_mut());
+
+            assert_eq!(resource.error(), Some("io"));
+            assert_eq!(crate::timer::next_timer_deadline(), None);
+        });
+    }
+
+    #[test]
+    fn stale_value_kept_while_revalidating() {
+        with_new_runtime(|_| {
+            let mut source = create_signal(1u32);
+            let deferred = Deferred::default();
+            let fetch = deferred.clone();
+            let (resource, driver) = create_resource_with(
+                move || source.get(),
+                move |_| fetch.clone().fetch(),
+                ResourceOptions::new().stale_while_revalidate(),
+            );
+
+            let executor = DummyExecutor::new();
+            let mut driver = pin!(driver);
+            executor.run_until_stalled(driver.as_mut());
+            assert!(resource.with(|state| *state == AsyncState::Loading));
+
+            deferred.resolve(Ok(10));
+            executor.run_until_stalled(driver.as_mut());
+            assert_eq!(resource.ready(), Some(10));
+
+            source.set(2);
+            executor.run_until_stalled(driver.as_mut());
+            assert!(resource.is_loading());
+            assert_eq!(resource.latest(), Some(10), "stale value kept");
+            assert_eq!(resource.ready(), None);
+
+            deferred.resolve(Ok(20));
+            executor.run_until_stalled(driver.as_mut());
+            assert_eq!(resource.ready(), Some(20));
+        });
+    }
+
+    #[test]
+    fn superseded_fetch_is_dropped() {
+        with_new_runtime(|_| {
+            let mut source = create_signal(1u32);
+            let deferred = Deferred::default();
+            let fetch = deferred.clone();
+            let (resource, driver) = create_resource_with(
+                move || source.get(),
+                move |_| fetch.clone().fetch(),
+                ResourceOptions::new(),
+            );
+
+            let executor = DummyExecutor::new();
+            let mut driver = pin!(driver);
+            executor.run_until_stalled(driver.as_mut());
+
+            // The source changes while the first fetch is in flight: it is
+            // dropped without completing and a new fetch starts.
+            source.set(2);
+            executor.run_until_stalled(driver.as_mut());
+            assert_eq!(deferred.dropped.get(), 1);
+            assert!(resource.is_loading());
+
+            deferred.resolve(Ok(20));
+            executor.run_until_stalled(driver.as_mut());
+            assert_eq!(resource.ready(), Some(20));
+            assert_eq!(deferred.dropped.get(), 1);
+        });
+    }
+
+    #[test]
+    fn refetch_fetches_again() {
+        with_new_runtime(|_| {
+            let fetches = Rc::new(Cell::new(0u32));
+            let counter = fetches.clone();
+            let (resource, driver) = create_resource(
+                || 1u32,
+                move |n| {
+                    counter.set(counter.get() + 1);
+                    async move { n }
+                },
+            );
+
+            let executor = DummyExecutor::new();
+            let mut driver = pin!(driver);
+            executor.run_until_stalled(driver.as_mut());
+            assert_eq!(fetches.get(), 1);
+
+            resource.refetch();
+            executor.run_until_stalled(driver.as_mut());
+            assert_eq!(fetches.get(), 2);
+            assert_eq!(resource.ready(), Some(1));
+        });
+    }
 }
@@ -3,8 +3,11 @@ use crate::{
     computed::ComputedCallback,
     effect::EffectCallback,
     memo::MemoCallback,
-    scope::{ScopeData, ScopeHandle, ScopeId},
-    storage::{Storage, Value, ValueId, ValueKind, ValueKindTag, ValueState},
+    scope::{ScopeData, ScopeHandle, ScopeId, ScopeProfile},
+    storage::{
+        IdVec, NodeMap, Storage, Value, ValueId, ValueKind, ValueKindTag,
+        ValueState, id_vec_try_push,
+    },
 };
 use alloc::{rc::Rc, vec::Vec};
 // Only the `debug-info` mermaid-graph helpers below still use a `BTreeSet`; the
@@ -19,20 +22,12 @@ use core::{
     marker::PhantomData,
     panic::Location,
 };
-use slotmap::{SecondaryMap, SlotMap};
-use tinyvec::TinyVec;
+use slotmap::SlotMap;
 
 slotmap::new_key_type! {
     pub struct RuntimeId;
 }
 
-/// Inline-small vector of value ids used for the dependency-graph edge sets
-/// (`sources`/`subscribers`/`owned`). Fan-in and fan-out are almost always
-/// tiny, so the common case stays on the stack with no per-node heap
-/// allocation; large fan-outs spill to the heap exactly once. Order is not
-/// significant — topological order for effect flushing comes from `height`.
-type IdVec = TinyVec<[ValueId; 4]>;
-
 /// Remove the first occurrence of `id` from an [`IdVec`] by swap-remove
 /// (order-independent).
 #[inline]
@@ -63,7 +58,6 @@ impl RuntimeId {
     }
 }
 
-// TODO: Will we support multi-runtime? If so, ValueId needs to be compound value ID + runtime ID. If no, we need to hide with_new_runtime and new runtime creation from exposed API as it is dangerous.
 /// Run `f` with the **current** runtime and return its result.
 ///
 /// Panics if no runtime is active on the current thread. This is the standard
@@ -80,11 +74,163 @@ pub fn with_current_runtime<T>(f: impl FnOnce(&Runtime) -> T) -> T {
     })
 }
 
+pub(crate) fn current_runtime_id() -> Option<RuntimeId> {
+    CURRENT_RUNTIME
+        .try_with(|current| current.get())
+        .ok()
+        .flatten()
+}
+
+/// Like [`with_current_runtime`] but returns `None` instead of panicking when
+/// there is no current runtime — it is being torn down (`RuntimeId::leave`
+/// clears the current cell before dropping it) or the thread-local storage is
+/// already destroyed. For `Drop` impls of handles that may outlive their
+/// runtime, e.g. a `ScopeHandle` captured by an effect closure.
+pub(crate) fn try_with_current_runtime<T>(
+    f: impl FnOnce(&Runtime) -> T,
+) -> Option<T> {
+    RUNTIMES
+        .try_with(|rts| {
+            let rts = rts.try_borrow().ok()?;
+            let current = CURRENT_RUNTIME
+                .try_with(|current| current.get())
+                .ok()
+                .flatten()?;
+            rts.get(current).map(f)
+        })
+        .ok()
+        .flatten()
+}
+
+/// Like [`try_with_current_runtime`] but for the runtime `id`, which is made
+/// current while `f` runs. `None` if that runtime is already dropped.
+pub(crate) fn try_with_runtime<T>(
+    id: RuntimeId,
+    f: impl FnOnce(&Runtime) -> T,
+) -> Option<T> {
+    let _enter = RuntimeEnterGuard::enter(id)?;
+    try_with_current_runtime(f)
+}
+
+/// An owned runtime, for more than one reactive world on a thread, e.g. two
+/// independent UIs on a host.
+///
+/// Reactive values are created in the current runtime, so build and drive
+/// everything of a runtime while it is [entered](RuntimeHandle::enter).
+/// Dropping the handle drops the runtime wholesale with every value in it.
+///
+/// Handles of different runtimes must not be mixed. With the `multi-runtime`
+/// feature each handle remembers its runtime, and using it while another one
+/// is current logs an error and treats the value as dead.
+///
+/// ```rust
+/// # use rsact_reactive::runtime::RuntimeHandle;
+/// # use rsact_reactive::prelude::*;
+/// let a = RuntimeHandle::new();
+/// let b = RuntimeHandle::new();
+/// let sa = a.run(|| create_signal(1u32));
+/// let sb = b.run(|| create_signal(2u32));
+/// assert_eq!(a.run(|| sa.get()), 1);
+/// assert_eq!(b.run(|| sb.get()), 2);
+/// ```
+pub struct RuntimeHandle {
+    id: RuntimeId,
+    // Runtimes live in a thread-local
+    _not_send: PhantomData<Rc<()>>,
+}
+
+impl RuntimeHandle {
+    /// Create a new runtime without making it current.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            id: RUNTIMES
+                .with(|rts| rts.borrow_mut().insert_with_key(Runtime::new)),
+            _not_send: PhantomData,
+        }
+    }
+
+    pub fn id(&self) -> RuntimeId {
+        self.id
+    }
+
+    /// Make this runtime current until the returned guard drops, then restore
+    /// the previously current one. Guards must be dropped in reverse order of
+    /// entering, as with nested scopes.
+    pub fn enter(&self) -> RuntimeEnterGuard<'_> {
+        RuntimeEnterGuard::enter(self.id)
+            .expect("Attempt to enter runtime while thread is being destroyed")
+    }
+
+    /// Run `f` with this runtime [entered](RuntimeHandle::enter).
+    pub fn run<T>(&self, f: impl FnOnce() -> T) -> T {
+        let _enter = self.enter();
+        f()
+    }
+}
+
+impl Default for RuntimeHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for RuntimeHandle {
+    fn drop(&mut self) {
+        let rt = RUNTIMES
+            .try_with(|rts| rts.try_borrow_mut().ok()?.remove(self.id))
+            .ok()
+            .flatten();
+        let Some(rt) = rt else {
+            log::error!(
+                "Runtime {:?} is in use or already destroyed, leaking it",
+                self.id
+            );
+            return;
+        };
+
+        // Values of the runtime may hold handles whose `Drop` looks for a
+        // runtime (e.g. a `ScopeHandle` captured by an effect), none must be
+        // current while it goes so that they don't reach another one.
+        let prev = CURRENT_RUNTIME.try_with(|current| current.take()).ok();
+        drop(rt);
+        if let Some(prev) = prev {
+            let _ = CURRENT_RUNTIME.try_with(|current| {
+                current.set(prev.filter(|prev| *prev != self.id))
+            });
+        }
+    }
+}
+
+/// Returned by [`RuntimeHandle::enter`], restores the previously current
+/// runtime on drop.
+#[must_use]
+pub struct RuntimeEnterGuard<'a> {
+    prev: Option<RuntimeId>,
+    _handle: PhantomData<&'a RuntimeHandle>,
+}
+
+impl RuntimeEnterGuard<'_> {
+    fn enter(id: RuntimeId) -> Option<Self> {
+        CURRENT_RUNTIME
+            .try_with(|current| current.replace(Some(id)))
+            .ok()
+            .map(|prev| Self { prev, _handle: PhantomData })
+    }
+}
+
+impl Drop for RuntimeEnterGuard<'_> {
+    fn drop(&mut self) {
+        let _ = CURRENT_RUNTIME.try_with(|current| current.set(self.prev));
+    }
+}
+
 /// Create a **fresh** runtime, make it current, run `f`, then destroy it
 /// and restore the previous runtime.
 ///
 /// Primarily used in tests and benchmarks to get a clean isolated runtime
-/// for each run without leaking state between calls.
+/// for each run without leaking state between calls. See [`RuntimeHandle`]
+/// for runtimes that outlive a closure.
 ///
 /// ```rust
 /// # use rsact_reactive::runtime::with_new_runtime;
@@ -97,30 +243,11 @@ pub fn with_current_runtime<T>(f: impl FnOnce(&Runtime) -> T) -> T {
 #[cfg(any(test, feature = "test-utils"))]
 #[inline(always)]
 pub fn with_new_runtime<T>(f: impl FnOnce(&Runtime) -> T) -> T {
-    // Restore the previously-current runtime on the way out — even if `f`
-    // panics — so a nested `with_new_runtime` (or any temporary runtime) can't
-    // brick the current-runtime cell (WS1.2). The old code discarded `prev`,
-    // and `leave()` merely clears the current cell, so after the temporary
-    // runtime left the current cell was `None` and every subsequent reactive
-    // access panicked on `current.unwrap()`.
-    struct RuntimeGuard {
-        rt: RuntimeId,
-        prev: Option<RuntimeId>,
-    }
-    impl Drop for RuntimeGuard {
-        fn drop(&mut self) {
-            self.rt.leave();
-            CURRENT_RUNTIME.with(|current| current.set(self.prev));
-        }
-    }
-
-    let rt = create_runtime();
-    let prev = CURRENT_RUNTIME.with(|current| {
-        let prev = current.get();
-        current.set(Some(rt));
-        prev
-    });
-    let _guard = RuntimeGuard { rt, prev };
+    // The enter guard drops first and restores the previously current
+    // runtime — even if `f` panics — so a nested `with_new_runtime` can't
+    // brick the current-runtime cell (WS1.2).
+    let rt = RuntimeHandle::new();
+    let _enter = rt.enter();
 
     with_current_runtime(f)
 }
@@ -134,7 +261,7 @@ pub fn with_new_runtime<T>(f: impl FnOnce(&Runtime) -> T) -> T {
 #[must_use]
 #[inline(always)]
 pub fn create_runtime() -> RuntimeId {
-    RUNTIMES.with(|rts| rts.borrow_mut().insert(Runtime::new()))
+    RUNTIMES.with(|rts| rts.borrow_mut().insert_with_key(Runtime::new))
 }
 
 crate::thread_local::thread_local_impl! {
@@ -144,7 +271,7 @@ crate::thread_local::thread_local_impl! {
         let mut runtimes = SlotMap::default();
 
         #[cfg(feature = "default-runtime")]
-        CURRENT_RUNTIME.with(|current| current.set(Some(runtimes.insert(Runtime::new()))));
+        CURRENT_RUNTIME.with(|current| current.set(Some(runtimes.insert_with_key(Runtime::new))));
 
         RefCell::new(runtimes)
     };
@@ -264,18 +391,21 @@ pub fn batch<T>(f: impl FnOnce() -> T) -> T {
 /// `signal`.
 #[derive(Default)]
 pub struct Runtime {
+    /// Of this runtime in the runtimes of the thread, remembered by handles.
+    #[cfg(feature = "multi-runtime")]
+    pub(crate) id: RuntimeId,
     pub(crate) storage: Storage,
     scopes: RefCell<SlotMap<ScopeId, ScopeData>>,
     current_scope: Cell<Option<ScopeId>>,
     /// Values owned by observers.
-    owned: RefCell<SecondaryMap<ValueId, IdVec>>,
+    owned: RefCell<NodeMap<IdVec>>,
     /// Current observer
     pub(crate) observer: Cell<Option<ValueId>>,
     /// Signals subscribers.
-    pub(crate) subscribers: RefCell<SecondaryMap<ValueId, IdVec>>,
+    pub(crate) subscribers: RefCell<NodeMap<IdVec>>,
     /// Sources of signal changes. Signals that affect this observer (memo,
     /// effect, etc.).
-    pub(crate) sources: RefCell<SecondaryMap<ValueId, IdVec>>,
+    pub(crate) sources: RefCell<NodeMap<IdVec>>,
     /// Effects to run after a value changed (or after a [`DeferEffectsGuard`]
     /// runs/drops if `defer_effects` is enabled). Height-bucketed so draining
     /// ascending is already topological — no per-flush sort — and its buffers
@@ -293,7 +423,7 @@ pub struct Runtime {
     /// Bumping [`mark_gen`](Runtime::mark_gen) invalidates every entry in O(1)
     /// — no `clear()` (which would be O(capacity)) and no per-write allocation.
     /// `u64` so the generation never wraps in any realistic device lifetime.
-    mark_seen: RefCell<SecondaryMap<ValueId, u64>>,
+    mark_seen: RefCell<NodeMap<u64>>,
     mark_gen: Cell<u64>,
     /// True while [`run_effects`](Runtime::run_effects) is draining the pending
     /// queue. A signal written *by an effect* during a flush re-enters
@@ -303,6 +433,10 @@ pub struct Runtime {
     /// and turns a dependency cycle into a bounded, logged loop instead of a
     /// stack overflow.
     flushing: Cell<bool>,
+    /// Clock and registry of [`timer`](crate::timer)s.
+    pub(crate) timers: crate::timer::TimerQueue,
+    /// Debounced saves of [`persist`](crate::persist)ed signals.
+    pub(crate) persist: crate::persist::PendingSaves,
 }
 
 /// The pending-effect queue (WS9a.1): effects awaiting a flush, bucketed by
@@ -329,7 +463,7 @@ pub struct Runtime {
 pub(crate) struct EffectQueue {
     pending: Vec<Vec<ValueId>>,
     draining: Vec<Vec<ValueId>>,
-    queued: SecondaryMap<ValueId, ()>,
+    queued: NodeMap<()>,
     len: usize,
 }
 
@@ -419,8 +553,10 @@ impl EffectQueue {
 }
 
 impl Runtime {
-    fn new() -> Self {
+    fn new(_id: RuntimeId) -> Self {
         Self {
+            #[cfg(feature = "multi-runtime")]
+            id: _id,
             storage: Default::default(),
             scopes: Default::default(),
             current_scope: Default::default(),
@@ -434,6 +570,8 @@ impl Runtime {
             mark_seen: Default::default(),
             mark_gen: Cell::new(0),
             flushing: Cell::new(false),
+            timers: Default::default(),
+            persist: Default::default(),
         }
     }
 
@@ -540,7 +678,7 @@ impl Runtime {
         if let Some(observer) = self.observer.get() {
             // Use entry API so the owned set is created on first use,
             // enabling proper owned-value tracking for effects/memos.
-            // SecondaryMap::entry() returns Option<Entry<...>>.
+            // NodeMap::entry() returns Option<Entry<...>>.
             if let Some(entry) = self.owned.borrow_mut().entry(observer) {
                 entry.or_default().push(id);
             }
@@ -797,11 +935,17 @@ impl Runtime {
         self.subscribers.borrow_mut().remove(id);
         // TODO: Is it okay to remove from pending_effects?
         self.pending_effects.borrow_mut().remove(id);
-        self.storage
+        // Bind the removed value so it is dropped only after the `values`
+        // borrow is released: dropping it may drop user state that owns
+        // reactive resources (e.g. a `ScopeHandle` captured by an effect
+        // closure or held inside a signal), whose `Drop` re-enters `dispose`.
+        let value = self
+            .storage
             .values
             .borrow_mut()
             .remove(id)
             .expect("Removing non-existent scope value");
+        drop(value);
 
         // Recursively dispose owned children now that all borrows are released.
         for child in owned_children {
@@ -877,6 +1021,43 @@ impl Runtime {
         f(self)
     }
 
+    /// Run `f` with `owner` as the owner of every value created inside, and
+    /// with no current scope. Such values are disposed only together with
+    /// `owner` (see [`Runtime::dispose`]) — neither when the running observer
+    /// re-runs nor when the current scope drops. Used by primitives that lazily
+    /// create nodes on read, e.g. a [`Selector`](crate::selector::Selector)'s
+    /// per-key triggers.
+    pub(crate) fn with_owner<T>(
+        &self,
+        owner: ValueId,
+        f: impl FnOnce(&Self) -> T,
+    ) -> T {
+        struct ScopeGuard<'a> {
+            cell: &'a Cell<Option<ScopeId>>,
+            prev: Option<ScopeId>,
+        }
+        impl Drop for ScopeGuard<'_> {
+            fn drop(&mut self) {
+                self.cell.set(self.prev);
+            }
+        }
+
+        let _guard = ScopeGuard {
+            cell: &self.current_scope,
+            prev: self.current_scope.take(),
+        };
+
+        self.with_observer(owner, f)
+    }
+
+    /// Whether any observer currently depends on `id`.
+    pub(crate) fn has_subscribers(&self, id: ValueId) -> bool {
+        self.subscribers
+            .borrow()
+            .get(id)
+            .is_some_and(|subs| !subs.is_empty())
+    }
+
     pub(crate) fn subscribe(&self, id: ValueId) {
         if let Some(observer) = self.observer.get() {
             if observer == id {
@@ -898,17 +1079,30 @@ impl Runtime {
                 return;
             }
 
+            // Both halves of the edge or neither: a bounded edge set (the
+            // `fixed-capacity` backend) may refuse either push.
             {
                 let mut sources = self.sources.borrow_mut();
-                if let Some(sources) = sources.entry(observer) {
-                    sources.or_default().push(id);
-                }
-            }
-
-            {
                 let mut subs = self.subscribers.borrow_mut();
-                if let Some(subs) = subs.entry(id) {
-                    subs.or_default().push(observer);
+                let (Some(sources), Some(subs)) =
+                    (sources.entry(observer), subs.entry(id))
+                else {
+                    return;
+                };
+                let (sources, subs) = (sources.or_default(), subs.or_default());
+
+                if !id_vec_try_push(sources, id) {
+                    log::error!(
+                        "Reactive value {observer} has too many sources, not subscribing to {id}"
+                    );
+                    return;
+                }
+                if !id_vec_try_push(subs, observer) {
+                    log::error!(
+                        "Reactive value {id} has too many subscribers, not subscribing {observer}"
+                    );
+                    id_vec_remove(sources, id);
+                    return;
                 }
             }
 
@@ -1144,7 +1338,7 @@ impl Runtime {
                     "mark_dirty re-entered; falling back to fresh stack/seen"
                 );
                 let mut stack = Vec::new();
-                let mut seen = SecondaryMap::new();
+                let mut seen = NodeMap::default();
                 self.mark_check_closure(
                     id, generation, requester, caller, &mut stack, &mut seen,
                 );
@@ -1162,7 +1356,7 @@ impl Runtime {
         requester: Option<ValueId>,
         caller: &'static Location<'static>,
         stack: &mut Vec<ValueId>,
-        seen: &mut SecondaryMap<ValueId, u64>,
+        seen: &mut NodeMap<u64>,
     ) {
         // Hold the subscribers borrow for the whole walk — `mark_node` touches
         // storage and pending_effects, never the subscribers map.
@@ -1418,6 +1612,168 @@ impl Runtime {
         (name, format!("{decl}\n{subs}\n{sources}\n{state_change}\n"))
     }
 
+    /// Nodes created in `root` or its child scopes, and the nodes owned by
+    /// those, transitively. May hold ids of disposed values: scopes keep the
+    /// ids of values disposed before them.
+    fn scope_nodes(&self, root: ScopeId) -> Vec<ValueId> {
+        let scopes = self.scopes.borrow();
+        let in_root = |mut scope: Option<ScopeId>| {
+            while let Some(id) = scope {
+                if id == root {
+                    return true;
+                }
+                scope = scopes.get(id).and_then(|data| data.parent);
+            }
+            false
+        };
+
+        let mut ids = scopes
+            .iter()
+            .filter(|(id, _)| in_root(Some(*id)))
+            .flat_map(|(_, data)| data.values.iter().copied())
+            .collect::<Vec<_>>();
+
+        let owned = self.owned.borrow();
+        let mut next = 0;
+        while let Some(&id) = ids.get(next) {
+            if let Some(children) = owned.get(id) {
+                ids.extend(children.iter().copied());
+            }
+            next += 1;
+        }
+
+        ids.sort();
+        ids.dedup();
+        ids
+    }
+
+    pub(crate) fn scope_profile(&self, scope: ScopeId) -> ScopeProfile {
+        // The `Rc` counts in front of each allocation.
+        const RC_HEADER: usize = 2 * core::mem::size_of::<usize>();
+
+        let mut profile = ScopeProfile::default();
+        let values = self.storage.values.borrow();
+        for id in self.scope_nodes(scope) {
+            let Some(value) = values.get(id) else {
+                continue;
+            };
+
+            profile.heap_bytes +=
+                RC_HEADER + core::mem::size_of_val(&*value.value);
+            match &value.kind {
+                ValueKind::Stored => profile.stored += 1,
+                ValueKind::Signal => profile.signals += 1,
+                ValueKind::Effect { f } => {
+                    profile.effects += 1;
+                    profile.heap_bytes +=
+                        RC_HEADER + core::mem::size_of_val(&**f);
+                },
+                ValueKind::Memo { f } => {
+                    profile.memos += 1;
+                    profile.heap_bytes +=
+                        RC_HEADER + core::mem::size_of_val(&**f);
+                },
+                ValueKind::Computed { f } => {
+                    profile.computed += 1;
+                    profile.heap_bytes +=
+                        RC_HEADER + core::mem::size_of_val(&**f);
+                },
+                ValueKind::Probe => profile.probes += 1,
+            }
+        }
+
+        profile
+    }
+
+    /// Capture the reactive graph, see [`crate::graph`].
+    #[cfg(feature = "debug-info")]
+    pub fn reactive_graph(
+        &self,
+        filter: crate::graph::GraphFilter,
+    ) -> crate::graph::ReactiveGraph {
+        use crate::graph::{GraphFilter, GraphNode, GraphOwner, ReactiveGraph};
+
+        let edges = |map: &RefCell<NodeMap<IdVec>>, id: ValueId| {
+            map.borrow()
+                .get(id)
+                .map(|ids| ids.iter().copied().collect::<Vec<_>>())
+                .unwrap_or_default()
+        };
+
+        let mut owners = Vec::new();
+        for (scope_id, scope) in self.scopes.borrow().iter() {
+            owners.extend(
+                scope
+                    .values
+                    .iter()
+                    .map(|&id| (id, GraphOwner::Scope(scope_id))),
+            );
+        }
+        for (observer, owned) in self.owned.borrow().iter() {
+            owners.extend(
+                owned.iter().map(|&id| (id, GraphOwner::Value(observer))),
+            );
+        }
+        owners.sort_by_key(|(id, _)| *id);
+
+        let mut ids = match filter {
+            GraphFilter::All => self
+                .storage
+                .values
+                .borrow()
+                .iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>(),
+            GraphFilter::Scope(handle) => self.scope_nodes(handle.id()),
+            GraphFilter::Around { root, depth } => {
+                let mut ids = alloc::vec![root];
+                let mut frontier = alloc::vec![root];
+                for _ in 0..depth {
+                    let mut next = Vec::new();
+                    for id in frontier {
+                        for near in edges(&self.subscribers, id)
+                            .into_iter()
+                            .chain(edges(&self.sources, id))
+                        {
+                            if !ids.contains(&near) {
+                                ids.push(near);
+                                next.push(near);
+                            }
+                        }
+                    }
+                    frontier = next;
+                }
+                ids
+            },
+        };
+        ids.sort();
+        ids.dedup();
+
+        let nodes = ids
+            .into_iter()
+            .filter_map(|id| {
+                // Disposed values linger in scope lists: skip them.
+                let value = self.storage.get(id)?;
+                Some(GraphNode {
+                    id,
+                    kind: value.kind.tag(),
+                    name: value.debug.name,
+                    ty: value.debug.ty,
+                    created_at: value.debug.created_at,
+                    state: value.state,
+                    owner: owners
+                        .binary_search_by_key(&id, |(id, _)| *id)
+                        .ok()
+                        .map(|pos| owners[pos].1),
+                    sources: edges(&self.sources, id),
+                    subscribers: edges(&self.subscribers, id),
+                })
+            })
+            .collect();
+
+        ReactiveGraph { nodes }
+    }
+
     /// Detach `id` from its current dependency edges: remove `id` from the
     /// subscriber set of every source it tracked, then clear its own source
     /// list (so heights are recomputed on the next re-subscription).
@@ -1726,7 +2082,8 @@ pub struct Profile {
     /// there. So this is the runtime's permanent node-slot RAM high-water mark;
     /// on embedded that determinism is usually desirable. Contract: **capacity =
     /// peak** (see the metrics README / WS4.6). Compaction would need a full
-    /// rebuild — deferred to WS9b.1's storage rework.
+    /// rebuild — deferred to WS9b.1's storage rework. With `fixed-capacity`
+    /// it is the reserved [`NODES`](crate::slab::NODES) from the start.
     pub values_capacity: usize,
     /// Retained-but-unused value slots (`values_capacity` − live node total):
     /// freed on dispose, reusable by future inserts, but still costing RAM.
@@ -1800,7 +2157,8 @@ mod tests {
         memo::{Memo, create_memo},
         read::ReadSignal,
         runtime::{
-            RUNTIMES, batch, untrack, with_current_runtime, with_new_runtime,
+            RUNTIMES, RuntimeHandle, batch, untrack, with_current_runtime,
+            with_new_runtime,
         },
         scope::new_scope,
         signal::create_signal,
@@ -1877,6 +2235,120 @@ mod tests {
         });
     }
 
+    /// A handle to a disposed value whose slot was reused does not read the
+    /// new value: slots are versioned.
+    #[test]
+    fn stale_handle_misses_recycled_slot() {
+        with_new_runtime(|_| {
+            let stale = create_signal(1u8);
+            unsafe { stale.dispose() };
+            let fresh = create_signal(2u8);
+
+            assert_ne!(stale.id(), fresh.id());
+            assert!(!stale.is_alive());
+            assert_eq!(stale.try_get(), None);
+            assert_eq!(fresh.get(), 2);
+        });
+    }
+
+    /// A handle outliving its runtime does not read the value of the next
+    /// runtime in the same slot.
+    #[test]
+    #[cfg(feature = "multi-runtime")]
+    fn handle_of_another_runtime_is_dead() {
+        let stale = with_new_runtime(|_| create_signal(1u8));
+
+        with_new_runtime(|_| {
+            let mut fresh = create_signal(2u8);
+
+            assert_eq!(stale.id(), fresh.id(), "same slot of a new runtime");
+            assert!(!stale.is_alive());
+            assert_eq!(stale.try_get(), None);
+            fresh.set(3);
+            assert_eq!(fresh.get(), 3);
+        });
+    }
+
+    #[test]
+    fn entered_runtimes_are_independent() {
+        let a = RuntimeHandle::new();
+        let b = RuntimeHandle::new();
+        let outer = CURRENT_RUNTIME.with(|current| current.get());
+        let runs = Rc::new(Cell::new(0));
+
+        let (mut sa, _effect) = a.run(|| {
+            let sa = create_signal(0u8);
+            let runs = runs.clone();
+            let effect = create_effect(move |_| {
+                sa.track();
+                runs.set(runs.get() + 1);
+            });
+            (sa, effect)
+        });
+        let mut sb = b.run(|| create_signal(0u8));
+
+        {
+            let _a = a.enter();
+            assert_eq!(CURRENT_RUNTIME.with(|c| c.get()), Some(a.id()));
+            b.run(|| sb.set(1));
+            assert_eq!(CURRENT_RUNTIME.with(|c| c.get()), Some(a.id()));
+            assert_eq!(runs.get(), 1);
+            sa.set(1);
+            assert_eq!(runs.get(), 2);
+        }
+        assert_eq!(CURRENT_RUNTIME.with(|c| c.get()), outer);
+        assert_eq!(b.run(|| sb.get()), 1);
+    }
+
+    #[test]
+    fn dropping_runtime_handle_drops_its_values() {
+        let rt = RuntimeHandle::new();
+        let outer = CURRENT_RUNTIME.with(|current| current.get());
+        let id = rt.id();
+        let payload = Rc::new(());
+
+        rt.run(|| {
+            let scope = new_scope();
+            let payload = payload.clone();
+            let signal = create_signal(0u8);
+            create_effect(move |_| {
+                let _scope = &scope;
+                let _payload = &payload;
+                signal.track();
+            });
+        });
+        assert_eq!(Rc::strong_count(&payload), 2);
+
+        drop(rt);
+        assert_eq!(Rc::strong_count(&payload), 1);
+        assert!(RUNTIMES.with(|rts| !rts.borrow().contains_key(id)));
+        assert_eq!(CURRENT_RUNTIME.with(|c| c.get()), outer);
+    }
+
+    /// A scope dropped while another runtime is current disposes its values in
+    /// its own runtime, not the values in the same slots of the current one.
+    #[test]
+    fn scope_drops_in_its_own_runtime() {
+        let a = RuntimeHandle::new();
+        let b = RuntimeHandle::new();
+
+        let (scope, sa) = a.run(|| {
+            let scope = new_scope();
+            let sa = create_signal(1u8);
+            scope.leave();
+            (scope, sa)
+        });
+        let sb = b.run(|| create_signal(2u8));
+        assert_eq!(sa.id(), sb.id(), "same slot of another runtime");
+
+        #[cfg(feature = "multi-runtime")]
+        assert!(!b.run(|| sa.is_alive()), "only alive in its own runtime");
+
+        b.run(|| drop(scope));
+        assert!(!a.run(|| sa.is_alive()));
+        assert_eq!(b.run(|| sb.get()), 2);
+    }
+
     /// The panicking APIs still panic on a disposed handle, but with a
     /// contextful message (not a bare unwrap).
     #[test]
@@ -1,4 +1,9 @@
-use crate::{runtime::with_current_runtime, storage::ValueId};
+use crate::{
+    runtime::{
+        RuntimeId, current_runtime_id, try_with_runtime, with_current_runtime,
+    },
+    storage::ValueId,
+};
 use alloc::vec::Vec;
 use core::fmt::Display;
 
@@ -95,6 +100,52 @@ impl ScopeData {
     }
 }
 
+/// Live nodes of a scope, its child scopes and the nodes owned by those, from
+/// [`ScopeHandle::profile`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ScopeProfile {
+    pub stored: usize,
+    pub signals: usize,
+    pub effects: usize,
+    pub memos: usize,
+    pub computed: usize,
+    pub probes: usize,
+    /// Approximate heap bytes of the nodes: the allocations holding their
+    /// values and observer closures. Heap memory the values point to
+    /// themselves (a `Vec`'s buffer, a `String`) is not counted.
+    pub heap_bytes: usize,
+}
+
+impl ScopeProfile {
+    /// Total live node count.
+    pub fn total(&self) -> usize {
+        self.stored
+            + self.signals
+            + self.effects
+            + self.memos
+            + self.computed
+            + self.probes
+    }
+}
+
+impl Display for ScopeProfile {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} nodes ({} stored, {} signals, {} effects, {} memos, {} \
+             computed, {} probes), ~{}B",
+            self.total(),
+            self.stored,
+            self.signals,
+            self.effects,
+            self.memos,
+            self.computed,
+            self.probes,
+            self.heap_bytes
+        )
+    }
+}
+
 /// A RAII guard that owns a reactive scope.
 ///
 /// When `ScopeHandle` is dropped all reactive values (signals, memos, effects)
@@ -105,11 +156,18 @@ impl ScopeData {
 #[must_use]
 pub struct ScopeHandle {
     scope_id: ScopeId,
+    // Scopes are dropped non-lexically, maybe while another runtime is current
+    runtime: RuntimeId,
 }
 
 impl ScopeHandle {
     pub(crate) fn new(scope_id: ScopeId) -> Self {
-        Self { scope_id }
+        Self { scope_id, runtime: current_runtime_id().unwrap_or_default() }
+    }
+
+    #[cfg(feature = "debug-info")]
+    pub(crate) fn id(&self) -> ScopeId {
+        self.scope_id
     }
 
     /// Restore the previously-current scope without disposing this one.
@@ -125,15 +183,25 @@ impl ScopeHandle {
     /// Restores `current_scope` to this scope's parent only if this scope is
     /// still current; idempotent otherwise.
     pub fn leave(&self) {
-        with_current_runtime(|rt| rt.exit_scope(self.scope_id));
+        try_with_runtime(self.runtime, |rt| rt.exit_scope(self.scope_id));
+    }
+
+    /// Count the live nodes of this scope by kind, with their approximate heap
+    /// bytes, see [`ScopeProfile`].
+    pub fn profile(&self) -> ScopeProfile {
+        try_with_runtime(self.runtime, |rt| rt.scope_profile(self.scope_id))
+            .unwrap_or_default()
     }
 }
 
 impl Drop for ScopeHandle {
     fn drop(&mut self) {
-        with_current_runtime(|rt| {
+        // A handle can be owned by reactive state (e.g. captured by an effect
+        // closure), so it may be dropped while its runtime is being torn down.
+        // Everything the scope owned dies with the runtime then — nothing to do.
+        try_with_runtime(self.runtime, |rt| {
             rt.drop_scope(self.scope_id);
-        })
+        });
     }
 }
 
@@ -375,4 +443,65 @@ mod tests {
             assert!(inner_ref.get());
         });
     }
+
+    /// A `ScopeHandle` owned by reactive state (captured by an effect closure)
+    /// is dropped when that state is disposed — disposing the scope's values
+    /// re-enters the runtime, which must not hit a held storage borrow — and a
+    /// handle still alive at runtime teardown must not panic.
+    #[test]
+    fn scope_handle_owned_by_effect() {
+        with_new_runtime(|_| {
+            let outer = new_scope();
+
+            let row = new_scope();
+            let inner = create_signal(0i32);
+            row.leave();
+
+            create_effect(move |_: Option<()>| {
+                let _row = &row;
+            });
+
+            drop(outer);
+            assert!(
+                !inner.is_alive(),
+                "captured scope was not dropped with its effect"
+            );
+
+            // Leaked into the runtime: dropped during teardown.
+            let leaked = new_scope();
+            let _leaked_signal = create_signal(0i32);
+            leaked.leave();
+            create_effect(move |_: Option<()>| {
+                let _leaked = &leaked;
+            });
+        });
+    }
+
+    #[test]
+    fn profile_counts_child_scopes_and_owned_nodes() {
+        with_new_runtime(|_| {
+            let _outside = create_signal(0u8);
+
+            let page = new_scope();
+            let count = create_signal(0u32);
+            let row = new_scope();
+            create_effect(move |_: Option<()>| {
+                count.get();
+                let _owned = create_signal([0u8; 64]);
+            });
+            row.leave();
+            page.leave();
+
+            let profile = row.profile();
+            assert_eq!((profile.effects, profile.signals), (1, 1));
+            assert!(profile.heap_bytes >= 64);
+
+            let profile = page.profile();
+            assert_eq!(profile.total(), 3, "with the row and its owned signal");
+            assert_eq!(profile.signals, 2);
+
+            drop(row);
+            assert_eq!(page.profile().total(), 1);
+        });
+    }
 }
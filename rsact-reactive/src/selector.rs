@@ -0,0 +1,255 @@
+//! Keyed selection with per-key subscriptions.
+//!
+//! Highlighting "the selected one" among N items with N memos that each compare
+//! against a shared `Signal<K>` wakes all N memos on every selection change. A
+//! [`Selector`] instead keeps one [`Trigger`] per queried key:
+//! [`Selector::is_selected`] subscribes the running observer to the trigger of
+//! its key only, and a selection change notifies just the triggers of the
+//! previously and the newly selected key — two observers, whatever N is.
+
+use crate::{
+    ReactiveValue,
+    effect::create_effect,
+    read::ReadSignal,
+    runtime::{untrack, with_current_runtime},
+    storage::ValueId,
+    stored::StoredValue,
+    trigger::Trigger,
+    write::WriteSignal,
+};
+use alloc::vec::Vec;
+
+/// Create a [`Selector`] following `source`.
+///
+/// The selector is owned by the current scope. Its syncing effect is owned by
+/// the selector and disposed together with it. Keys are kept sorted (binary search, no hashing), hence `K: Ord`.
+#[track_caller]
+pub fn create_selector<K, S>(source: S) -> Selector<K>
+where
+    K: Ord + Clone + 'static,
+    S: ReadSignal<K> + 'static,
+{
+    let mut state = StoredValue::new(SelectorState {
+        current: untrack(|| source.with(K::clone)),
+        keys: Vec::new(),
+    });
+
+    // Owned by the state so that disposing the selector stops the effect
+    // before it touches the disposed state.
+    let owner = state.id().unwrap();
+    let sync = move |_: Option<()>| {
+        let new = source.with(K::clone);
+
+        let changed = state.update_untracked(|state| {
+            if state.current == new {
+                return None;
+            }
+            let old = core::mem::replace(&mut state.current, new.clone());
+            Some([state.take_idle(&old), state.take_idle(&new)])
+        });
+
+        // Notify outside the state borrow: subscribers re-run right away and
+        // call `is_selected` again.
+        for (trigger, idle) in changed.into_iter().flatten().flatten() {
+            if idle {
+                unsafe { trigger.dispose() };
+            } else {
+                trigger.notify();
+            }
+        }
+    };
+    with_current_runtime(|rt| rt.with_owner(owner, |_| create_effect(sync)));
+
+    Selector { state }
+}
+
+struct SelectorState<K> {
+    current: K,
+    /// Sorted by key.
+    keys: Vec<(K, Trigger)>,
+}
+
+impl<K: Ord> SelectorState<K> {
+    /// The trigger of `key` (if any), with whether nothing depends on it
+    /// anymore. An idle trigger is removed from the map: it is recreated on
+    /// the next `is_selected(key)`, so triggers of keys nobody asks about
+    /// (e.g. of removed rows) do not pile up.
+    fn take_idle(&mut self, key: &K) -> Option<(Trigger, bool)> {
+        let pos = self.keys.binary_search_by(|(k, _)| k.cmp(key)).ok()?;
+        let trigger = self.keys[pos].1;
+        let idle = !trigger.id().is_some_and(|id| {
+            with_current_runtime(|rt| rt.has_subscribers(id))
+        });
+        if idle {
+            self.keys.remove(pos);
+        }
+        Some((trigger, idle))
+    }
+}
+
+/// Handle answering "is `key` selected?" with per-key subscriptions. See the
+/// [module docs](self) and [`create_selector`].
+///
+/// The per-key triggers are owned by the selector, not by the observer that
+/// first asked for a key, and are disposed together with it.
+pub struct Selector<K: 'static> {
+    state: StoredValue<SelectorState<K>>,
+}
+
+impl<K: 'static> Clone for Selector<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: 'static> Copy for Selector<K> {}
+
+impl<K: 'static> ReactiveValue for Selector<K> {
+    type Value = K;
+
+    fn id(&self) -> Option<ValueId> {
+        self.state.id()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.state.is_alive()
+    }
+
+    unsafe fn dispose(self) {
+        unsafe { self.state.dispose() };
+    }
+}
+
+impl<K: Ord + Clone + 'static> Selector<K> {
+    /// Whether `key` is the current value of the source. Subscribes the
+    /// running observer to `key` only: it re-runs when `key` becomes or stops
+    /// being selected, not on any other selection change.
+    #[track_caller]
+    pub fn is_selected(&self, key: &K) -> bool {
+        self.trigger(key).track();
+        self.state.with_untracked(|state| state.current == *key)
+    }
+
+    /// The current value of the source, without subscribing to anything.
+    pub fn selected(&self) -> K {
+        self.state.with_untracked(|state| state.current.clone())
+    }
+
+    #[track_caller]
+    fn trigger(&self, key: &K) -> Trigger {
+        let found = self.state.with_untracked(|state| {
+            state
+                .keys
+                .binary_search_by(|(k, _)| k.cmp(key))
+                .map_err(|_| ())
+        });
+        if let Ok(pos) = found {
+            return self.state.with_untracked(|state| state.keys[pos].1);
+        }
+
+        // Created outside the state borrow and owned by the selector, so the
+        // trigger survives re-runs of the observer asking for it.
+        let owner = self.state.id().unwrap();
+        let trigger =
+            with_current_runtime(|rt| rt.with_owner(owner, |_| Trigger::new()));
+
+        let mut state = self.state;
+        state.update_untracked(|state| {
+            match state.keys.binary_search_by(|(k, _)| k.cmp(key)) {
+                Ok(pos) => state.keys[pos].1 = trigger,
+                Err(pos) => state.keys.insert(pos, (key.clone(), trigger)),
+            }
+        });
+
+        trigger
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::create_selector;
+    use crate::{
+        ReactiveValue, effect::create_effect, runtime::with_new_runtime,
+        signal::create_signal, write::WriteSignal,
+    };
+    use alloc::{rc::Rc, vec::Vec};
+    use core::cell::Cell;
+
+    /// A selection change re-runs only the observers of the previously and
+    /// newly selected keys.
+    #[test]
+    fn notifies_only_old_and_new_keys() {
+        with_new_runtime(|_| {
+            let mut selected = create_signal(0usize);
+            let selector = create_selector(selected);
+
+            let runs =
+                (0..5).map(|_| Rc::new(Cell::new(0))).collect::<Vec<_>>();
+            let states = (0..5)
+                .map(|_| Rc::new(Cell::new(false)))
+                .collect::<Vec<_>>();
+            for key in 0..5 {
+                let runs = runs[key].clone();
+                let state = states[key].clone();
+                create_effect(move |_| {
+                    runs.set(runs.get() + 1);
+                    state.set(selector.is_selected(&key));
+                });
+            }
+
+            let counts = || runs.iter().map(|r| r.get()).collect::<Vec<_>>();
+            assert_eq!(counts(), [1, 1, 1, 1, 1]);
+            assert!(states[0].get());
+
+            selected.set(3);
+            assert_eq!(counts(), [2, 1, 1, 2, 1]);
+            assert!(!states[0].get());
+            assert!(states[3].get());
+
+            // Same value: nobody re-runs.
+            selected.set(3);
+            assert_eq!(counts(), [2, 1, 1, 2, 1]);
+            assert_eq!(selector.selected(), 3);
+        });
+    }
+
+    /// Key triggers are owned by the selector: dropped when nothing observes
+    /// them anymore and disposed together with the selector.
+    #[test]
+    fn key_triggers_are_pruned_and_disposed() {
+        with_new_runtime(|_| {
+            let mut selected = create_signal(0u8);
+            let selector = create_selector(selected);
+
+            let probe = create_effect(move |_| {
+                selector.is_selected(&1);
+            });
+            let trigger = selector.trigger(&1);
+            assert!(trigger.is_alive());
+
+            // The observer goes away: the next change involving its key
+            // drops the idle trigger.
+            unsafe { probe.dispose() };
+            selected.set(1);
+            assert!(!trigger.is_alive());
+
+            let trigger = selector.trigger(&2);
+            unsafe { selector.dispose() };
+            assert!(!trigger.is_alive());
+        });
+    }
+
+    /// The syncing effect goes away with the selector, so the source can
+    /// still change afterwards.
+    #[test]
+    fn source_outlives_disposed_selector() {
+        with_new_runtime(|_| {
+            let mut selected = create_signal(0u8);
+            let selector = create_selector(selected);
+            unsafe { selector.dispose() };
+
+            selected.set(1);
+            assert!(!selector.is_alive());
+        });
+    }
+}
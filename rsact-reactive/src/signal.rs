@@ -4,10 +4,11 @@ use crate::{
     prelude::*,
     read::impl_read_signal_traits,
     runtime::with_current_runtime,
-    storage::ValueId,
+    storage::{OutOfNodes, SignalId, ValueId},
     write::{SignalSetter, WriteSignal},
 };
 use core::{marker::PhantomData, panic::Location};
+use slotmap::Key;
 
 /// Create a new [`Signal<T>`] in the current runtime scope.
 ///
@@ -33,6 +34,17 @@ pub fn create_signal<T: 'static>(value: T) -> Signal<T> {
     Signal::new(value)
 }
 
+/// [`create_signal`] that reports a full `fixed-capacity` storage as
+/// [`OutOfNodes`] instead of giving a dead handle. Always `Ok` on the growable
+/// storage.
+#[track_caller]
+pub fn try_create_signal<T: 'static>(
+    value: T,
+) -> Result<Signal<T>, OutOfNodes> {
+    let signal = Signal::new(value);
+    if signal.id.untyped().is_null() { Err(OutOfNodes) } else { Ok(signal) }
+}
+
 /// Blanket trait for types that implement both [`ReadSignal<T>`] and
 /// [`WriteSignal<T>`]. Useful as a bound when a function needs
 /// full read-write access without caring about the concrete type.
@@ -150,8 +162,7 @@ pub mod marker {
 /// # });
 /// ```
 pub struct Signal<T: ?Sized, M: marker::Any = marker::Rw> {
-    id: ValueId,
-    ty: PhantomData<T>,
+    id: SignalId<T>,
     rw: PhantomData<M>,
 }
 
@@ -166,17 +177,17 @@ impl<T: 'static, M: marker::Any> ReactiveValue for Signal<T, M> {
     type Value = T;
 
     fn id(&self) -> Option<ValueId> {
-        Some(self.id)
+        Some(self.id.untyped())
     }
 
     #[track_caller]
     fn is_alive(&self) -> bool {
-        with_current_runtime(|rt| rt.is_alive(self.id))
+        with_current_runtime(|rt| self.id.is_alive(rt))
     }
 
     #[track_caller]
     unsafe fn dispose(self) {
-        unsafe { with_current_runtime(|rt| rt.dispose(self.id)) }
+        unsafe { with_current_runtime(|rt| self.id.dispose(rt)) }
     }
 }
 
@@ -188,10 +199,9 @@ impl<T: 'static, M: marker::Any> Signal<T, M> {
         let caller = Location::caller();
 
         Self {
-            id: with_current_runtime(|runtime| {
+            id: SignalId::new(with_current_runtime(|runtime| {
                 runtime.create_signal(value, caller)
-            }),
-            ty: PhantomData,
+            })),
             rw: PhantomData,
         }
     }
@@ -210,7 +220,7 @@ impl<T: 'static, M: marker::Any> Signal<T, M> {
     /// the id has been, or will be, upgraded to a reactive kind before it is
     /// relied upon to notify subscribers).
     pub unsafe fn from_id(id: ValueId) -> Self {
-        Self { id, ty: PhantomData, rw: PhantomData }
+        Self { id: SignalId::new(id), rw: PhantomData }
     }
 
     // // TODO: Mark unsafe?
@@ -347,13 +357,13 @@ impl<T: 'static, U: PartialEq + 'static, M: marker::CanRead> SignalMap<T, U>
 
 impl<T: 'static, M: marker::CanRead> Signal<T, M> {
     pub fn read_only(self) -> Signal<T, marker::ReadOnly> {
-        Signal { id: self.id, ty: PhantomData, rw: PhantomData }
+        Signal { id: self.id, rw: PhantomData }
     }
 }
 
 impl<T: 'static, M: marker::CanWrite> Signal<T, M> {
     pub fn write_only(self) -> Signal<T, marker::WriteOnly> {
-        Signal { id: self.id, ty: PhantomData, rw: PhantomData }
+        Signal { id: self.id, rw: PhantomData }
     }
 }
 
@@ -0,0 +1,573 @@
+//! Reactive vector that propagates **diffs** instead of whole values.
+//!
+//! A plain `Signal<Vec<T>>` notifies with "everything changed": each consumer
+//! re-reads the full list and, for UI children, rebuilds every element. A
+//! [`SignalVec<T>`] records each mutation as a [`VecDiff`] in a short log next
+//! to the items, so a consumer that remembers how far it has read (a
+//! [`DiffCursor`]) replays only what happened since — e.g. `rsact-ui`'s `For`
+//! widget builds a single child on `push` and disposes a single child on
+//! `remove`.
+//!
+//! The log is bounded ([`DIFF_LOG_CAP`]). A consumer that falls further behind
+//! than the log reaches (or reads for the first time) is resynced with
+//! [`VecDiff::Clear`] followed by one [`VecDiff::Insert`] per item, so it never
+//! observes a torn state — it only loses the incremental path.
+//!
+//! Keyed collections: [`SignalVec::reconcile`] takes a whole new list plus a
+//! key function and emits the minimal-ish keyed diff (removes, moves, inserts,
+//! and updates for items whose key matches but whose value changed). Use it to
+//! feed an external `Vec` source into a `SignalVec` without losing identity of
+//! unchanged items.
+
+use crate::{
+    ReactiveValue,
+    effect::create_effect,
+    read::ReadSignal,
+    runtime::untrack,
+    signal::{Signal, create_signal},
+    storage::ValueId,
+    write::WriteSignal,
+};
+use alloc::{collections::VecDeque, vec::Vec};
+
+/// Max number of diffs kept for lagging consumers. Consumers are usually
+/// effects flushed right after the write (or at the end of the batch), so the
+/// log rarely holds more than a handful of entries; past the cap the oldest are
+/// dropped and a consumer that needed them resyncs (see the module docs).
+pub const DIFF_LOG_CAP: usize = 32;
+
+/// A single change to a [`SignalVec`]. Indices are positions in the vector
+/// **at the moment the diff is applied**, so replaying the diffs in order on a
+/// copy of the old vector yields the new one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VecDiff<T> {
+    /// `value` was inserted at `index`, shifting later items right.
+    Insert { index: usize, value: T },
+    /// The item at `index` was removed, shifting later items left.
+    Remove { index: usize },
+    /// The item at `from` was removed and re-inserted at `to` (`to` is an
+    /// index in the vector *after* the removal).
+    Move { from: usize, to: usize },
+    /// The item at `index` was replaced by `value` (same position/key).
+    Update { index: usize, value: T },
+    /// All items were removed.
+    Clear,
+}
+
+impl<T> VecDiff<T> {
+    /// Apply this diff to a plain vector. Out-of-range indices are logged and
+    /// ignored, never panic.
+    pub fn apply(self, items: &mut Vec<T>) {
+        match self {
+            VecDiff::Insert { index, value } if index <= items.len() => {
+                items.insert(index, value);
+            },
+            VecDiff::Remove { index } if index < items.len() => {
+                items.remove(index);
+            },
+            VecDiff::Move { from, to }
+                if from < items.len() && to < items.len() =>
+            {
+                let item = items.remove(from);
+                items.insert(to, item);
+            },
+            VecDiff::Update { index, value } if index < items.len() => {
+                items[index] = value;
+            },
+            VecDiff::Clear => items.clear(),
+            _ => {
+                log::error!(
+                    "VecDiff index out of bounds for vector of length {}",
+                    items.len()
+                );
+            },
+        }
+    }
+}
+
+/// Read position of one [`SignalVec`] consumer. A fresh cursor has seen
+/// nothing, so its first [`SignalVec::diffs`] call resyncs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffCursor {
+    seen: Option<u64>,
+}
+
+impl DiffCursor {
+    pub fn new() -> Self {
+        Self { seen: None }
+    }
+}
+
+struct VecLog<T> {
+    items: Vec<T>,
+    log: VecDeque<VecDiff<T>>,
+    /// Number of diffs ever recorded. The log holds the last `log.len()` of
+    /// them, i.e. versions `version - log.len() + 1 ..= version`.
+    version: u64,
+}
+
+impl<T: Clone> VecLog<T> {
+    fn record(&mut self, diff: VecDiff<T>) {
+        if self.log.len() >= DIFF_LOG_CAP {
+            self.log.pop_front();
+        }
+        self.log.push_back(diff);
+        self.version += 1;
+    }
+
+    /// Apply `diff` to the items and record it.
+    fn push(&mut self, diff: VecDiff<T>) {
+        diff.clone().apply(&mut self.items);
+        self.record(diff);
+    }
+}
+
+/// Create a new [`SignalVec`] holding `items`.
+#[track_caller]
+pub fn create_signal_vec<T: Clone + 'static>(items: Vec<T>) -> SignalVec<T> {
+    SignalVec::new(items)
+}
+
+/// Create a [`SignalVec`] that follows `source` by key: every time `source`
+/// changes, its new value is [`reconcile`](SignalVec::reconcile)d into the
+/// returned vector, so consumers see keyed diffs instead of a full rebuild.
+///
+/// The syncing effect is owned by the current scope, like any other effect.
+#[track_caller]
+pub fn create_keyed_signal_vec<T, K, S>(
+    source: S,
+    key: impl Fn(&T) -> K + 'static,
+) -> SignalVec<T>
+where
+    T: Clone + PartialEq + 'static,
+    K: PartialEq,
+    S: ReadSignal<Vec<T>> + 'static,
+{
+    let mut vec = SignalVec::new(Vec::new());
+    create_effect(move |_| {
+        let new = source.with(Vec::clone);
+        untrack(|| vec.reconcile(new, &key));
+    });
+    vec
+}
+
+/// A reactive `Vec<T>` emitting [`VecDiff`]s, see the module docs.
+///
+/// Reads ([`with`](Self::with), [`diffs`](Self::diffs), ...) track like a
+/// signal read; every mutation notifies subscribers once, no matter how many
+/// diffs it records. Wrap several mutations in [`crate::prelude::batch`] to
+/// coalesce them into a single consumer run.
+pub struct SignalVec<T> {
+    inner: Signal<VecLog<T>>,
+}
+
+impl<T: 'static> Clone for SignalVec<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: 'static> Copy for SignalVec<T> {}
+
+impl<T: 'static> ReactiveValue for SignalVec<T> {
+    type Value = Vec<T>;
+
+    fn id(&self) -> Option<ValueId> {
+        self.inner.id()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.inner.is_alive()
+    }
+
+    unsafe fn dispose(self) {
+        unsafe { self.inner.dispose() };
+    }
+}
+
+impl<T: Clone + 'static> SignalVec<T> {
+    #[track_caller]
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            inner: create_signal(VecLog {
+                items,
+                log: VecDeque::new(),
+                version: 0,
+            }),
+        }
+    }
+
+    // Reads //
+
+    /// Subscribe the current observer without reading.
+    #[track_caller]
+    pub fn track(&self) {
+        self.inner.track();
+    }
+
+    #[track_caller]
+    pub fn with<U>(&self, f: impl FnOnce(&[T]) -> U) -> U {
+        self.inner.with(|log| f(&log.items))
+    }
+
+    #[track_caller]
+    pub fn with_untracked<U>(&self, f: impl FnOnce(&[T]) -> U) -> U {
+        self.inner.with_untracked(|log| f(&log.items))
+    }
+
+    #[track_caller]
+    pub fn len(&self) -> usize {
+        self.with(|items| items.len())
+    }
+
+    #[track_caller]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[track_caller]
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.with(|items| items.get(index).cloned())
+    }
+
+    /// Tracked read of every diff recorded since `cursor`, oldest first, then
+    /// advance `cursor` to the current version. A cursor that is fresh or
+    /// lagged beyond [`DIFF_LOG_CAP`] gets a resync (`Clear` + `Insert`s).
+    #[track_caller]
+    pub fn diffs(
+        &self,
+        cursor: &mut DiffCursor,
+        mut f: impl FnMut(VecDiff<T>),
+    ) {
+        self.inner.with(|log| {
+            let pending = cursor
+                .seen
+                .and_then(|seen| usize::try_from(log.version - seen).ok())
+                .filter(|&pending| pending <= log.log.len());
+
+            match pending {
+                Some(pending) => log
+                    .log
+                    .iter()
+                    .skip(log.log.len() - pending)
+                    .cloned()
+                    .for_each(&mut f),
+                None => {
+                    f(VecDiff::Clear);
+                    log.items.iter().cloned().enumerate().for_each(
+                        |(index, value)| f(VecDiff::Insert { index, value }),
+                    );
+                },
+            }
+
+            cursor.seen = Some(log.version);
+        })
+    }
+
+    // Writes //
+
+    #[track_caller]
+    fn apply(&mut self, diffs: impl FnOnce(&mut VecLog<T>)) {
+        self.inner.update(diffs);
+    }
+
+    #[track_caller]
+    pub fn push(&mut self, value: T) {
+        self.apply(|log| {
+            let index = log.items.len();
+            log.push(VecDiff::Insert { index, value });
+        });
+    }
+
+    /// Insert `value` at `index`; an out-of-range index appends instead.
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.apply(|log| {
+            let index = index.min(log.items.len());
+            log.push(VecDiff::Insert { index, value });
+        });
+    }
+
+    /// Remove the item at `index`; returns `None` (and does not notify) if it
+    /// is out of range.
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.inner.with_untracked(|log| log.items.len()) {
+            return None;
+        }
+        self.inner.update(|log| {
+            let value = log.items.remove(index);
+            log.record(VecDiff::Remove { index });
+            Some(value)
+        })
+    }
+
+    #[track_caller]
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.inner.with_untracked(|log| log.items.len());
+        len.checked_sub(1).and_then(|last| self.remove(last))
+    }
+
+    /// Move the item at `from` to `to` (see [`VecDiff::Move`]). No-op for
+    /// `from == to` or out-of-range indices.
+    #[track_caller]
+    pub fn move_item(&mut self, from: usize, to: usize) {
+        let len = self.inner.with_untracked(|log| log.items.len());
+        if from == to || from >= len || to >= len {
+            return;
+        }
+        self.apply(|log| log.push(VecDiff::Move { from, to }));
+    }
+
+    /// Replace the item at `index`. No-op if it is out of range.
+    #[track_caller]
+    pub fn set(&mut self, index: usize, value: T) {
+        if index >= self.inner.with_untracked(|log| log.items.len()) {
+            return;
+        }
+        self.apply(|log| log.push(VecDiff::Update { index, value }));
+    }
+
+    /// Mutate the item at `index` in place, recording an `Update`. Returns
+    /// `None` if `index` is out of range.
+    #[track_caller]
+    pub fn update_item<U>(
+        &mut self,
+        index: usize,
+        f: impl FnOnce(&mut T) -> U,
+    ) -> Option<U> {
+        if index >= self.inner.with_untracked(|log| log.items.len()) {
+            return None;
+        }
+        Some(self.inner.update(|log| {
+            let result = f(&mut log.items[index]);
+            let value = log.items[index].clone();
+            log.record(VecDiff::Update { index, value });
+            result
+        }))
+    }
+
+    #[track_caller]
+    pub fn clear(&mut self) {
+        self.apply(|log| log.push(VecDiff::Clear));
+    }
+
+    /// Replace the whole content. Consumers see `Clear` + `Insert`s; prefer
+    /// [`reconcile`](Self::reconcile) when items have a stable identity.
+    #[track_caller]
+    pub fn replace(&mut self, items: Vec<T>) {
+        self.apply(|log| {
+            log.push(VecDiff::Clear);
+            for (index, value) in items.into_iter().enumerate() {
+                log.push(VecDiff::Insert { index, value });
+            }
+        });
+    }
+
+    /// Turn the current content into `new`, matching items by `key`:
+    /// items whose key disappeared are removed, kept items are moved into
+    /// their new position and updated if their value changed, new keys are
+    /// inserted. Notifies only if at least one diff was recorded.
+    ///
+    /// Quadratic in the list length — fine for UI lists, which are short on
+    /// the targets this crate is for. Duplicate keys are matched in order.
+    #[track_caller]
+    pub fn reconcile<K: PartialEq>(
+        &mut self,
+        new: Vec<T>,
+        key: impl Fn(&T) -> K,
+    ) where
+        T: PartialEq,
+    {
+        let changed = self.inner.update_untracked(|log| {
+            let version = log.version;
+
+            // Remove items whose key is gone, back to front so indices of the
+            // pending removals stay valid.
+            for index in (0..log.items.len()).rev() {
+                let old_key = key(&log.items[index]);
+                if !new.iter().any(|item| key(item) == old_key) {
+                    log.push(VecDiff::Remove { index });
+                }
+            }
+
+            let new_len = new.len();
+            for (index, value) in new.into_iter().enumerate() {
+                let new_key = key(&value);
+                let found = log.items[index..]
+                    .iter()
+                    .position(|item| key(item) == new_key)
+                    .map(|offset| index + offset);
+
+                match found {
+                    Some(from) => {
+                        if from != index {
+                            log.push(VecDiff::Move { from, to: index });
+                        }
+                        if log.items[index] != value {
+                            log.push(VecDiff::Update { index, value });
+                        }
+                    },
+                    None => log.push(VecDiff::Insert { index, value }),
+                }
+            }
+
+            // Surplus old items (duplicate keys the new list has fewer of)
+            // were left past the end.
+            for index in (new_len..log.items.len()).rev() {
+                log.push(VecDiff::Remove { index });
+            }
+
+            log.version != version
+        });
+
+        if changed {
+            self.inner.notify();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DIFF_LOG_CAP, DiffCursor, VecDiff, create_signal_vec};
+    use crate::{
+        effect::create_effect, prelude::batch, runtime::with_new_runtime,
+    };
+    use alloc::{rc::Rc, vec, vec::Vec};
+    use core::cell::RefCell;
+
+    fn collect<T: Clone + 'static>(
+        vec: super::SignalVec<T>,
+        cursor: &mut DiffCursor,
+    ) -> Vec<VecDiff<T>> {
+        let mut out = Vec::new();
+        vec.diffs(cursor, |diff| out.push(diff));
+        out
+    }
+
+    #[test]
+    fn fresh_cursor_resyncs() {
+        with_new_runtime(|_| {
+            let vec = create_signal_vec(vec![1, 2]);
+            let mut cursor = DiffCursor::new();
+
+            assert_eq!(
+                collect(vec, &mut cursor),
+                vec![
+                    VecDiff::Clear,
+                    VecDiff::Insert { index: 0, value: 1 },
+                    VecDiff::Insert { index: 1, value: 2 },
+                ]
+            );
+            assert!(collect(vec, &mut cursor).is_empty());
+        });
+    }
+
+    #[test]
+    fn mutations_emit_diffs() {
+        with_new_runtime(|_| {
+            let mut vec = create_signal_vec(vec![1, 2, 3]);
+            let mut cursor = DiffCursor::new();
+            collect(vec, &mut cursor);
+
+            vec.push(4);
+            vec.remove(0);
+            vec.move_item(2, 0);
+            vec.set(1, 20);
+
+            assert_eq!(
+                collect(vec, &mut cursor),
+                vec![
+                    VecDiff::Insert { index: 3, value: 4 },
+                    VecDiff::Remove { index: 0 },
+                    VecDiff::Move { from: 2, to: 0 },
+                    VecDiff::Update { index: 1, value: 20 },
+                ]
+            );
+            vec.with_untracked(|items| assert_eq!(items, &[4, 20, 3]));
+        });
+    }
+
+    #[test]
+    fn lagging_cursor_resyncs() {
+        with_new_runtime(|_| {
+            let mut vec = create_signal_vec(Vec::new());
+            let mut cursor = DiffCursor::new();
+            collect(vec, &mut cursor);
+
+            for i in 0..DIFF_LOG_CAP + 1 {
+                vec.push(i);
+            }
+
+            let diffs = collect(vec, &mut cursor);
+            assert_eq!(diffs[0], VecDiff::Clear);
+            assert_eq!(diffs.len(), DIFF_LOG_CAP + 2);
+        });
+    }
+
+    /// Replaying the emitted diffs on a copy of the old list must give the new
+    /// list, and unchanged keys must not produce any diff.
+    #[test]
+    fn reconcile_by_key() {
+        with_new_runtime(|_| {
+            let mut vec =
+                create_signal_vec(vec![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')]);
+            let mut cursor = DiffCursor::new();
+            collect(vec, &mut cursor);
+
+            let new = vec![(3, 'c'), (1, 'A'), (5, 'e'), (4, 'd')];
+            vec.reconcile(new.clone(), |item| item.0);
+
+            let diffs = collect(vec, &mut cursor);
+            assert!(!diffs.iter().any(|diff| matches!(diff, VecDiff::Clear)));
+            assert_eq!(
+                diffs
+                    .iter()
+                    .filter(|diff| matches!(diff, VecDiff::Insert { .. }))
+                    .count(),
+                1,
+                "only the new key is inserted"
+            );
+
+            let mut replay = vec![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')];
+            diffs.into_iter().for_each(|diff| diff.apply(&mut replay));
+            assert_eq!(replay, new);
+            vec.with_untracked(|items| assert_eq!(items, new.as_slice()));
+
+            // Same content: nothing recorded.
+            vec.reconcile(new, |item| item.0);
+            assert!(collect(vec, &mut cursor).is_empty());
+        });
+    }
+
+    /// A consumer effect runs once per batch and sees every diff of it.
+    #[test]
+    fn effect_consumes_batched_diffs() {
+        with_new_runtime(|_| {
+            let mut vec = create_signal_vec(Vec::<u32>::new());
+            let seen = Rc::new(RefCell::new(Vec::new()));
+            let runs = Rc::new(RefCell::new(0));
+
+            let (seen_eff, runs_eff) = (seen.clone(), runs.clone());
+            let mut cursor = DiffCursor::new();
+            create_effect(move |_| {
+                *runs_eff.borrow_mut() += 1;
+                vec.diffs(&mut cursor, |diff| seen_eff.borrow_mut().push(diff));
+            });
+
+            batch(|| {
+                vec.push(1);
+                vec.push(2);
+            });
+
+            assert_eq!(*runs.borrow(), 2);
+            assert_eq!(
+                *seen.borrow(),
+                vec![
+                    VecDiff::Clear,
+                    VecDiff::Insert { index: 0, value: 1 },
+                    VecDiff::Insert { index: 1, value: 2 },
+                ]
+            );
+        });
+    }
+}
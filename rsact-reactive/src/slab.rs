@@ -0,0 +1,537 @@
+//! Fixed-capacity storage backend (`fixed-capacity` feature).
+//!
+//! Replaces the growable `SlotMap`/`SecondaryMap`/`TinyVec` trio behind
+//! [`Storage`](crate::storage::Storage) and the runtime's dependency graph
+//! with const-generic arrays sized by [`NODES`] and [`FANOUT`]. Node slots and
+//! graph edges are allocated once when the runtime is created — their RAM is
+//! fixed at build time and a steady-state app never grows them.
+//!
+//! Running out degrades instead of panicking: a value created past [`NODES`]
+//! gets a dead handle (`is_alive() == false`, `try_*` accessors return
+//! `None`), and an edge past [`FANOUT`] is dropped, so the observer simply
+//! misses that dependency. Both log an error naming the limit to raise.
+//! [`try_create_signal`](crate::signal::try_create_signal) reports the first
+//! case as an error, and plain reads of a dead handle panic naming the limit.
+//!
+//! Value cells and observer closures are still `Rc`-allocated: this backend
+//! bounds the graph bookkeeping, not the user payloads.
+
+use crate::storage::ValueId;
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+use slotmap::{Key, KeyData};
+
+/// `usize` from a build-time env var, or `default` if unset.
+const fn env_or(var: Option<&str>, default: usize) -> usize {
+    let Some(var) = var else { return default };
+    let bytes = var.as_bytes();
+    assert!(!bytes.is_empty(), "Empty rsact capacity env var");
+
+    let mut value = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        assert!(bytes[i].is_ascii_digit(), "Invalid rsact capacity env var");
+        value = value * 10 + (bytes[i] - b'0') as usize;
+        i += 1;
+    }
+    value
+}
+
+/// Default of [`NODES`], enough for a page of ten labels (49 nodes) and the
+/// state around it.
+pub const DEFAULT_NODES: usize = 64;
+
+/// Default of [`FANOUT`].
+pub const DEFAULT_FANOUT: usize = 8;
+
+/// Most reactive values alive at once. Set with the `RSACT_NODES` env var at
+/// build time.
+///
+/// Each node costs about 180 bytes of runtime storage on a 32-bit target with
+/// the default [`FANOUT`], so the default keeps the runtime around 11 KB,
+/// within the RAM of the smallest supported target (20 KB on thumbv7m).
+pub const NODES: usize = env_or(option_env!("RSACT_NODES"), DEFAULT_NODES);
+
+/// Most sources, subscribers or owned values of a single node. Set with the
+/// `RSACT_FANOUT` env var at build time.
+pub const FANOUT: usize = env_or(option_env!("RSACT_FANOUT"), DEFAULT_FANOUT);
+
+// A compact `ValueId` holds a 16-bit slab index, the last one being null, and
+// an edge list counts its ids in a byte.
+const _: () = assert!(NODES < 0xffff, "RSACT_NODES must be below 65535");
+const _: () = assert!(FANOUT < 256, "RSACT_FANOUT must be below 256");
+
+/// `[T; N]` built in place on the heap, so that a large `N` never passes
+/// through the stack as `core::array::from_fn` would.
+fn boxed_array<T, const N: usize>(init: impl FnMut(usize) -> T) -> Box<[T; N]> {
+    // The exact size iterator allocates `N` elements once, with no
+    // reallocation when converted to a boxed slice.
+    let slice = (0..N).map(init).collect::<Vec<_>>().into_boxed_slice();
+    match slice.try_into() {
+        Ok(array) => array,
+        Err(_) => unreachable!(),
+    }
+}
+
+fn make_key<K: Key>(index: usize, version: u32) -> K {
+    KeyData::from_ffi((u64::from(version) << 32) | index as u64).into()
+}
+
+/// `(index, version)` of a key. The null key's index is out of any slab.
+fn split_key(key: impl Key) -> (usize, u32) {
+    let ffi = key.data().as_ffi();
+    ((ffi & 0xffff_ffff) as usize, (ffi >> 32) as u32)
+}
+
+struct Slot<T> {
+    /// Odd, as for `slotmap` keys; bumped by 2 on removal so stale keys miss.
+    /// Wraps within 16 bits, all a compact [`ValueId`] holds.
+    version: u32,
+    value: Option<T>,
+    /// Next vacant slot while this one is vacant.
+    next_free: usize,
+}
+
+/// `SlotMap` of at most `N` values, allocated once up front.
+pub(crate) struct Slab<K: Key, T, const N: usize> {
+    slots: Box<[Slot<T>; N]>,
+    /// Head of the vacant slots list, `N` when full.
+    free: usize,
+    len: usize,
+    key: PhantomData<K>,
+}
+
+impl<K: Key, T, const N: usize> Default for Slab<K, T, N> {
+    fn default() -> Self {
+        Self {
+            slots: boxed_array(|index| Slot {
+                version: 1,
+                value: None,
+                next_free: index + 1,
+            }),
+            free: 0,
+            len: 0,
+            key: PhantomData,
+        }
+    }
+}
+
+impl<K: Key, T, const N: usize> Slab<K, T, N> {
+    /// Insert `value`, giving it back if the slab is full.
+    pub(crate) fn try_insert(&mut self, value: T) -> Result<K, T> {
+        let index = self.free;
+        let Some(slot) = self.slots.get_mut(index) else {
+            return Err(value);
+        };
+
+        self.free = slot.next_free;
+        slot.value = Some(value);
+        self.len += 1;
+
+        Ok(make_key(index, slot.version))
+    }
+
+    pub(crate) fn remove(&mut self, key: K) -> Option<T> {
+        let (index, version) = split_key(key);
+        let slot = self.slots.get_mut(index)?;
+        if slot.version != version {
+            return None;
+        }
+
+        let value = slot.value.take()?;
+        slot.version = (slot.version + 2) & 0xffff;
+        slot.next_free = self.free;
+        self.free = index;
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    pub(crate) fn get(&self, key: K) -> Option<&T> {
+        let (index, version) = split_key(key);
+        self.slots
+            .get(index)
+            .filter(|slot| slot.version == version)
+            .and_then(|slot| slot.value.as_ref())
+    }
+
+    pub(crate) fn get_mut(&mut self, key: K) -> Option<&mut T> {
+        let (index, version) = split_key(key);
+        self.slots
+            .get_mut(index)
+            .filter(|slot| slot.version == version)
+            .and_then(|slot| slot.value.as_mut())
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (K, &T)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            slot.value
+                .as_ref()
+                .map(|value| (make_key(index, slot.version), value))
+        })
+    }
+
+    pub(crate) fn keys(&self) -> impl Iterator<Item = K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub(crate) fn values(&self) -> impl Iterator<Item = &T> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Always `N`: the slots are reserved up front.
+    pub(crate) fn capacity(&self) -> usize {
+        N
+    }
+}
+
+/// `SecondaryMap` over keys of a [`Slab`] of the same `N`, allocated once up
+/// front.
+pub(crate) struct SlabMap<K: Key, V, const N: usize> {
+    slots: Box<[Option<(u32, V)>; N]>,
+    len: usize,
+    key: PhantomData<K>,
+}
+
+impl<K: Key, V, const N: usize> Default for SlabMap<K, V, N> {
+    fn default() -> Self {
+        Self { slots: boxed_array(|_| None), len: 0, key: PhantomData }
+    }
+}
+
+/// Slot of a [`SlabMap`], see [`SlabMap::entry`].
+pub(crate) struct SlabMapEntry<'a, V> {
+    slot: &'a mut Option<(u32, V)>,
+    version: u32,
+    len: &'a mut usize,
+}
+
+impl<'a, V: Default> SlabMapEntry<'a, V> {
+    /// The value of the entry, set to the default if vacant or left over from
+    /// a removed key of the same slot.
+    pub(crate) fn or_default(self) -> &'a mut V {
+        match self.slot {
+            Some((version, _)) if *version == self.version => {},
+            Some(_) => *self.slot = Some((self.version, V::default())),
+            None => {
+                *self.len += 1;
+                *self.slot = Some((self.version, V::default()));
+            },
+        }
+
+        &mut self.slot.as_mut().unwrap().1
+    }
+}
+
+impl<K: Key, V, const N: usize> SlabMap<K, V, N> {
+    /// Returns the old value for `key`. A key out of the slab (the dead
+    /// handle of an overflowing one) is ignored.
+    pub(crate) fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let (index, version) = split_key(key);
+        let slot = self.slots.get_mut(index)?;
+
+        match slot.replace((version, value)) {
+            Some((old_version, old)) if old_version == version => Some(old),
+            Some(_) => None,
+            None => {
+                self.len += 1;
+                None
+            },
+        }
+    }
+
+    pub(crate) fn remove(&mut self, key: K) -> Option<V> {
+        let (index, version) = split_key(key);
+        let slot = self.slots.get_mut(index)?;
+        match slot.take() {
+            Some((v, value)) if v == version => {
+                self.len -= 1;
+                Some(value)
+            },
+            other => {
+                *slot = other;
+                None
+            },
+        }
+    }
+
+    /// `None` for a key out of the slab, like `SecondaryMap::entry` for a
+    /// removed key.
+    pub(crate) fn entry(&mut self, key: K) -> Option<SlabMapEntry<'_, V>> {
+        let (index, version) = split_key(key);
+        let slot = self.slots.get_mut(index)?;
+        Some(SlabMapEntry { slot, version, len: &mut self.len })
+    }
+
+    pub(crate) fn get(&self, key: K) -> Option<&V> {
+        let (index, version) = split_key(key);
+        match self.slots.get(index)? {
+            Some((v, value)) if *v == version => Some(value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_mut(&mut self, key: K) -> Option<&mut V> {
+        let (index, version) = split_key(key);
+        match self.slots.get_mut(index)? {
+            Some((v, value)) if *v == version => Some(value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (K, &V)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            slot.as_ref()
+                .map(|(version, value)| (make_key(index, *version), value))
+        })
+    }
+
+    #[cfg(test)]
+    pub(crate) fn keys(&self) -> impl Iterator<Item = K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub(crate) fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+        self.len = 0;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// At most `N` (below 256) value ids, inline. Derefs to a slice.
+#[derive(Clone, Copy)]
+pub(crate) struct EdgeList<const N: usize> {
+    ids: [ValueId; N],
+    len: u8,
+}
+
+impl<const N: usize> Default for EdgeList<N> {
+    fn default() -> Self {
+        Self { ids: [ValueId::null(); N], len: 0 }
+    }
+}
+
+impl<const N: usize> EdgeList<N> {
+    /// Push `id` unless full. Returns whether it was pushed.
+    pub(crate) fn try_push(&mut self, id: ValueId) -> bool {
+        match self.ids.get_mut(self.len as usize) {
+            Some(slot) => {
+                *slot = id;
+                self.len += 1;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// [`EdgeList::try_push`], logging an overflow.
+    pub(crate) fn push(&mut self, id: ValueId) {
+        if !self.try_push(id) {
+            log::error!(
+                "Reactive node has more than {N} edges, dropping edge to {id}. \
+                 Raise `RSACT_FANOUT`"
+            );
+        }
+    }
+
+    pub(crate) fn swap_remove(&mut self, index: usize) -> ValueId {
+        let id = self[index];
+        self.len -= 1;
+        self.ids[index] = self.ids[self.len as usize];
+        id
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Deref for EdgeList<N> {
+    type Target = [ValueId];
+
+    fn deref(&self) -> &Self::Target {
+        &self.ids[..self.len as usize]
+    }
+}
+
+impl<const N: usize> DerefMut for EdgeList<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.ids[..self.len as usize]
+    }
+}
+
+impl<const N: usize> IntoIterator for EdgeList<N> {
+    type Item = ValueId;
+    type IntoIter = core::iter::Take<core::array::IntoIter<ValueId, N>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ids.into_iter().take(self.len as usize)
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a EdgeList<N> {
+    type Item = &'a ValueId;
+    type IntoIter = core::slice::Iter<'a, ValueId>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DEFAULT_FANOUT, DEFAULT_NODES, EdgeList, FANOUT, NODES, Slab, SlabMap,
+        Slot,
+    };
+    use crate::{
+        prelude::*,
+        runtime::{Runtime, with_new_runtime},
+        storage::{OutOfNodes, Value, ValueId},
+    };
+    use alloc::{rc::Rc, string::String, vec::Vec};
+    use core::cell::Cell;
+    use slotmap::Key;
+
+    #[test]
+    fn slab_reuses_slots_with_new_versions() {
+        let mut slab = Slab::<ValueId, u32, 2>::default();
+        let a = slab.try_insert(1).unwrap();
+        let b = slab.try_insert(2).unwrap();
+        assert_eq!(slab.try_insert(3), Err(3), "full");
+
+        assert_eq!(slab.remove(a), Some(1));
+        let c = slab.try_insert(3).unwrap();
+        assert_ne!(a, c, "the reused slot has a new version");
+        assert_eq!(slab.get(a), None);
+        assert_eq!(slab.remove(a), None);
+        assert_eq!(slab.values().copied().collect::<Vec<_>>(), [3, 2]);
+        assert_eq!((slab.len(), slab.get(b)), (2, Some(&2)));
+        assert_eq!(slab.get(ValueId::null()), None);
+    }
+
+    #[test]
+    fn slab_map_drops_stale_keys() {
+        let mut slab = Slab::<ValueId, (), 2>::default();
+        let mut map = SlabMap::<ValueId, u32, 2>::default();
+        let old = slab.try_insert(()).unwrap();
+
+        *map.entry(old).unwrap().or_default() += 1;
+        *map.entry(old).unwrap().or_default() += 1;
+        assert_eq!(map.get(old), Some(&2));
+
+        slab.remove(old);
+        let new = slab.try_insert(()).unwrap();
+        assert_eq!(map.get(new), None, "same slot, other key");
+        assert_eq!(*map.entry(new).unwrap().or_default(), 0);
+        assert_eq!(
+            (map.len(), map.remove(old), map.get(new)),
+            (1, None, Some(&0))
+        );
+
+        assert!(map.entry(ValueId::null()).is_none());
+        assert_eq!(map.insert(ValueId::null(), 1), None);
+        assert_eq!(map.keys().collect::<Vec<_>>(), [new]);
+    }
+
+    #[test]
+    fn edge_list_drops_overflowing_edges() {
+        let mut slab = Slab::<ValueId, (), 3>::default();
+        let ids: Vec<ValueId> =
+            (0..3).map(|_| slab.try_insert(()).unwrap()).collect();
+
+        let mut edges = EdgeList::<2>::default();
+        edges.push(ids[0]);
+        edges.push(ids[1]);
+        edges.push(ids[2]);
+        assert_eq!(&*edges, &ids[..2]);
+
+        edges.swap_remove(0);
+        assert_eq!(&*edges, &ids[1..2]);
+        assert!(edges.try_push(ids[2]));
+        assert!(!edges.try_push(ids[0]));
+    }
+
+    #[test]
+    fn runtime_overflow_degrades() {
+        with_new_runtime(|_| {
+            let mut signals: Vec<Signal<u32>> =
+                (0..=NODES).map(|_| create_signal(0)).collect();
+            let mut dead = signals.pop().unwrap();
+            assert!(!dead.is_alive());
+            assert_eq!(dead.try_get(), None);
+            assert_eq!(dead.try_set(1), None);
+
+            // Room for the effect node, which then tracks more sources than
+            // it can hold.
+            unsafe { signals.pop().unwrap().dispose() };
+            let runs = Rc::new(Cell::new(0));
+            let tracked = signals[..=FANOUT].to_vec();
+            let counter = runs.clone();
+            create_effect(move |_: Option<()>| {
+                tracked.iter().for_each(|signal| {
+                    signal.get();
+                });
+                counter.set(counter.get() + 1);
+            });
+
+            signals[0].set(1);
+            assert_eq!(runs.get(), 2);
+            signals[FANOUT].set(1);
+            assert_eq!(runs.get(), 2, "the edge past FANOUT was dropped");
+        });
+    }
+
+    #[test]
+    fn overflow_is_reported_on_creation() {
+        with_new_runtime(|_| {
+            let signals: Vec<Signal<u32>> =
+                (0..NODES).map(|_| create_signal(0)).collect();
+            assert_eq!(try_create_signal(0).err(), Some(OutOfNodes));
+
+            // Effects and memos past the limit never run but don't panic.
+            let memo = create_memo(move || signals[0].get() + 1);
+            create_effect(move |_: Option<()>| {
+                memo.get();
+            });
+            assert_eq!(memo.try_get(), None);
+
+            let dead = create_signal(0);
+            let message = std::panic::catch_unwind(move || dead.get())
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap();
+            assert!(message.contains("RSACT_NODES"), "{message}");
+        });
+    }
+
+    #[test]
+    fn default_capacity_fits_smallest_target() {
+        // Value slots, the `owned`/`subscribers`/`sources` edge maps and the
+        // `mark_seen` map, all allocated when the runtime is created. Sizes
+        // on the host are an upper bound of the 32-bit ones.
+        let storage = DEFAULT_NODES
+            * (size_of::<Slot<Value>>()
+                + 3 * size_of::<Option<(u32, EdgeList<DEFAULT_FANOUT>)>>()
+                + size_of::<Option<(u32, u64)>>());
+        assert!(storage <= 14 * 1024, "{storage} bytes of node storage");
+        assert_eq!(size_of::<ValueId>(), 4, "compact edge ids");
+
+        // None of it is inline, so creating a runtime copies no large value.
+        assert!(size_of::<Runtime>() < 1024);
+    }
+}
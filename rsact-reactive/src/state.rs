@@ -0,0 +1,276 @@
+//! Snapshot and restore of named signals with serde.
+//!
+//! Signals [registered](StateRegistry::register) in a [`StateRegistry`] under
+//! a name are serialized by the registry itself, as a map from names to
+//! values, in any serde format:
+//!
+//! ```rust,ignore
+//! let mut state = StateRegistry::new();
+//! state.register("volume", volume).register("theme", theme);
+//!
+//! // On power loss
+//! flash.write(&postcard::to_allocvec(&state)?);
+//!
+//! // At boot, or in a test from a fixture
+//! state.restore(&mut postcard::Deserializer::from_bytes(&flash.read()))?;
+//! state.restore(&mut serde_json::Deserializer::from_str(FIXTURE))?;
+//! ```
+//!
+//! Restoring sets the signals, so their observers run as after any other
+//! write, once for the whole snapshot. Signals missing from the snapshot keep
+//! their value and names unknown to the registry are skipped, which needs a
+//! self-describing format (JSON, not postcard). Nothing is set if the
+//! snapshot fails to deserialize.
+
+use crate::{read::ReadSignal, runtime::batch, write::WriteSignal};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt;
+use serde::{
+    Deserializer, Serialize, Serializer,
+    de::{
+        DeserializeOwned, DeserializeSeed, Error, IgnoredAny, MapAccess,
+        Visitor,
+    },
+    ser::SerializeMap,
+};
+
+type Apply = Box<dyn FnOnce()>;
+type Save = Box<dyn Fn(&mut dyn FnMut(&dyn erased_serde::Serialize))>;
+type Load = Box<
+    dyn Fn(
+        &mut dyn erased_serde::Deserializer<'_>,
+    ) -> Result<Apply, erased_serde::Error>,
+>;
+
+struct Entry {
+    is_alive: Box<dyn Fn() -> bool>,
+    /// Pass the current value to the callback.
+    save: Save,
+    /// Deserialize a value, returning how to set it.
+    load: Load,
+}
+
+/// Named signals to snapshot and restore, see the [module docs](self).
+#[derive(Default)]
+pub struct StateRegistry {
+    /// Sorted by name.
+    entries: Vec<(&'static str, Entry)>,
+}
+
+impl StateRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Save and restore `signal` under `name`. Registering a name again
+    /// replaces the signal.
+    pub fn register<T, S>(&mut self, name: &'static str, signal: S) -> &mut Self
+    where
+        T: Serialize + DeserializeOwned + 'static,
+        S: ReadSignal<T> + WriteSignal<T> + Copy + 'static,
+    {
+        #[cfg(feature = "debug-info")]
+        if let Some(id) = signal.id() {
+            id.set_name(name);
+        }
+
+        let entry = Entry {
+            is_alive: Box::new(move || signal.is_alive()),
+            save: Box::new(move |f| signal.with_untracked(|value| f(value))),
+            load: Box::new(move |deserializer| {
+                let value = erased_serde::deserialize::<T>(deserializer)?;
+                let mut signal = signal;
+                Ok(Box::new(move || {
+                    if signal.is_alive() {
+                        signal.set(value);
+                    } else {
+                        log::warn!(
+                            "Restoring disposed signal `{name}` skipped"
+                        );
+                    }
+                }))
+            }),
+        };
+
+        match self.entries.binary_search_by(|(n, _)| n.cmp(&name)) {
+            Ok(pos) => {
+                log::warn!("Signal `{name}` registered again, replacing it");
+                self.entries[pos].1 = entry;
+            },
+            Err(pos) => self.entries.insert(pos, (name, entry)),
+        }
+
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Set the registered signals to the values of a snapshot made by
+    /// serializing a registry.
+    pub fn restore<'de, D: Deserializer<'de>>(
+        &self,
+        deserializer: D,
+    ) -> Result<(), D::Error> {
+        let pending = deserializer.deserialize_map(RestoreVisitor(self))?;
+
+        batch(|| pending.into_iter().for_each(|apply| apply()));
+
+        Ok(())
+    }
+
+    fn entry(&self, name: &str) -> Option<&Entry> {
+        self.entries
+            .binary_search_by(|(n, _)| (*n).cmp(name))
+            .ok()
+            .map(|pos| &self.entries[pos].1)
+    }
+}
+
+impl Serialize for StateRegistry {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let alive = || self.entries.iter().filter(|(_, e)| (e.is_alive)());
+
+        let mut map = serializer.serialize_map(Some(alive().count()))?;
+        for (name, entry) in alive() {
+            let mut result = Ok(());
+            (entry.save)(&mut |value| {
+                result = map.serialize_entry(name, value);
+            });
+            result?;
+        }
+
+        if alive().count() != self.entries.len() {
+            log::warn!("Disposed signals left out of the state snapshot");
+        }
+
+        map.end()
+    }
+}
+
+struct RestoreVisitor<'a>(&'a StateRegistry);
+
+impl<'de> Visitor<'de> for RestoreVisitor<'_> {
+    type Value = Vec<Apply>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map of signal names to values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(
+        self,
+        mut map: A,
+    ) -> Result<Self::Value, A::Error> {
+        let mut pending = Vec::new();
+
+        while let Some(name) = map.next_key::<String>()? {
+            match self.0.entry(&name) {
+                Some(entry) => {
+                    pending.push(map.next_value_seed(EntrySeed(entry))?)
+                },
+                None => {
+                    log::warn!("Unknown signal `{name}` in snapshot skipped");
+                    map.next_value::<IgnoredAny>()?;
+                },
+            }
+        }
+
+        Ok(pending)
+    }
+}
+
+struct EntrySeed<'a>(&'a Entry);
+
+impl<'de> DeserializeSeed<'de> for EntrySeed<'_> {
+    type Value = Apply;
+
+    fn deserialize<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        let mut erased = <dyn erased_serde::Deserializer>::erase(deserializer);
+        (self.0.load)(&mut erased).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StateRegistry;
+    use crate::prelude::*;
+    use alloc::{
+        rc::Rc,
+        string::{String, ToString},
+    };
+    use core::cell::Cell;
+
+    #[test]
+    fn snapshot_roundtrips_and_notifies() {
+        with_new_runtime(|_| {
+            let mut volume = create_signal(3u8);
+            let mut theme = create_signal(String::from("light"));
+
+            let mut state = StateRegistry::new();
+            state.register("volume", volume).register("theme", theme);
+
+            let bytes = postcard::to_allocvec(&state).unwrap();
+            let json = serde_json::to_string(&state).unwrap();
+            assert_eq!(json, r#"{"theme":"light","volume":3}"#);
+
+            volume.set(9);
+            theme.set("dark".to_string());
+
+            let runs = Rc::new(Cell::new(0));
+            let counter = runs.clone();
+            create_effect(move |_| {
+                volume.get();
+                theme.with(|_| ());
+                counter.set(counter.get() + 1);
+            });
+
+            state
+                .restore(&mut postcard::Deserializer::from_bytes(&bytes))
+                .unwrap();
+            assert_eq!(volume.get(), 3);
+            assert_eq!(theme.get_cloned(), "light");
+            assert_eq!(runs.get(), 2, "observers run once per restore");
+        });
+    }
+
+    #[test]
+    fn restore_from_partial_fixture() {
+        with_new_runtime(|_| {
+            let volume = create_signal(3u8);
+            let brightness = create_signal(50u8);
+
+            let mut state = StateRegistry::new();
+            state
+                .register("volume", volume)
+                .register("brightness", brightness);
+
+            state
+                .restore(&mut serde_json::Deserializer::from_str(
+                    r#"{"volume": 7, "removed": [1, 2]}"#,
+                ))
+                .unwrap();
+            assert_eq!((volume.get(), brightness.get()), (7, 50));
+
+            let bad = state.restore(&mut serde_json::Deserializer::from_str(
+                r#"{"brightness": 10, "volume": "loud"}"#,
+            ));
+            assert!(bad.is_err());
+            assert_eq!(
+                (volume.get(), brightness.get()),
+                (7, 50),
+                "nothing is set from a bad snapshot"
+            );
+        });
+    }
+}
@@ -1,18 +1,83 @@
+#[cfg(feature = "multi-runtime")]
+use crate::runtime::{RuntimeId, current_runtime_id};
 use crate::{callback::AnyCallback, runtime::Runtime};
 use alloc::rc::Rc;
 use core::{
     any::{Any, type_name},
     cell::RefCell,
     fmt::{Debug, Display},
+    marker::PhantomData,
     panic::Location,
 };
-use slotmap::{Key, SlotMap};
+use slotmap::Key;
 
-// TODO: Add typed ValueId's (per Memo, Signal, etc.)
+#[cfg(not(feature = "fixed-capacity"))]
 slotmap::new_key_type! {
     pub struct ValueId;
 }
 
+/// Slab index in the high and slot version in the low 16 bits: half a
+/// `slotmap` key, so the fixed edge lists fit twice the ids in the same RAM.
+/// The slab keeps its versions within 16 bits.
+#[cfg(feature = "fixed-capacity")]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ValueId(u32);
+
+#[cfg(feature = "fixed-capacity")]
+impl ValueId {
+    const NULL_INDEX: u32 = 0xffff;
+}
+
+#[cfg(feature = "fixed-capacity")]
+impl Debug for ValueId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "ValueId({:?})", self.data())
+    }
+}
+
+#[cfg(feature = "fixed-capacity")]
+impl Default for ValueId {
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+#[cfg(feature = "fixed-capacity")]
+impl From<slotmap::KeyData> for ValueId {
+    fn from(data: slotmap::KeyData) -> Self {
+        let ffi = data.as_ffi();
+        let index = (ffi as u32).min(Self::NULL_INDEX);
+        Self(index << 16 | (ffi >> 32) as u32 & 0xffff)
+    }
+}
+
+// SAFETY: `data` gives back the `KeyData` the id was made from, the null one
+// for the default id.
+#[cfg(feature = "fixed-capacity")]
+unsafe impl Key for ValueId {
+    fn data(&self) -> slotmap::KeyData {
+        let index = match self.0 >> 16 {
+            Self::NULL_INDEX => u32::MAX,
+            index => index,
+        };
+        slotmap::KeyData::from_ffi(
+            u64::from(self.0 & 0xffff) << 32 | u64::from(index),
+        )
+    }
+}
+
+/// The `fixed-capacity` storage has no free node slot, see
+/// [`try_create_signal`](crate::signal::try_create_signal). Raise
+/// `RSACT_NODES` or dispose of unused values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfNodes;
+
+impl Display for OutOfNodes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("out of reactive node slots")
+    }
+}
+
 #[derive(Clone, Copy)]
 pub enum NotifyError {
     #[allow(unused)]
@@ -39,11 +104,13 @@ impl ValueId {
     /// Fallible read. Returns `None` — logging the reason — when the value has
     /// been disposed, is currently borrowed elsewhere, or is stored as a
     /// different type, instead of panicking (WS1.8). This is the primitive the
-    /// public `try_*` APIs and the panicking [`with_untracked`](Self::with_untracked)
-    /// are both built on, so render/event paths can degrade rather than abort.
+    /// `try_*` and the panicking reads of [`ReadSignal`] are both built on, so
+    /// render/event paths can degrade rather than abort.
+    ///
+    /// [`ReadSignal`]: crate::read::ReadSignal
     #[track_caller]
     #[inline]
-    pub fn try_with_untracked<T: 'static, U>(
+    pub(crate) fn try_with_untracked<T: 'static, U>(
         &self,
         rt: &Runtime,
         f: impl FnOnce(&T) -> U,
@@ -54,7 +121,10 @@ impl ValueId {
         // Clone only the value cell `Rc` (one refcount bump), not the whole
         // `Value` — this runs on every read.
         let Some(cell) = rt.storage.value_rc(*self) else {
-            log::error!("Read of disposed reactive value {self} at {caller}");
+            log::error!(
+                "Read of disposed reactive value {self}{} at {caller}",
+                self.stale_note(rt)
+            );
             return None;
         };
 
@@ -91,24 +161,6 @@ impl ValueId {
         result
     }
 
-    #[track_caller]
-    #[inline(always)]
-    pub fn with_untracked<T: 'static, U>(
-        &self,
-        rt: &Runtime,
-        f: impl FnOnce(&T) -> U,
-        caller: &'static Location<'static>,
-    ) -> U {
-        match self.try_with_untracked::<T, U>(rt, f, caller) {
-            Some(result) => result,
-            // Contextful panic replacing the old bare `.unwrap()` — the specific
-            // cause (disposed / borrowed / type mismatch) is already logged by
-            // `try_with_untracked`; here we add the id, type, and (under
-            // `debug-info`) the value's creation site.
-            None => self.panic_inaccessible::<T>(rt, caller),
-        }
-    }
-
     /// Diverging helper: build the contextful panic message for an inaccessible
     /// value without a second `unwrap` (the value may be disposed, so we read
     /// its debug info through the `Option`-returning accessor — WS1.8).
@@ -120,6 +172,15 @@ impl ValueId {
         _rt: &Runtime,
         caller: &'static Location<'static>,
     ) -> ! {
+        #[cfg(feature = "fixed-capacity")]
+        if self.is_null() {
+            panic!(
+                "Access at {caller} to a reactive value created past the \
+                 {} node slots. Create it with a `try_*` constructor or raise \
+                 `RSACT_NODES`",
+                crate::slab::NODES
+            );
+        }
         #[cfg(feature = "debug-info")]
         if let Some(info) = _rt.storage.debug_info(*self) {
             panic!(
@@ -129,11 +190,22 @@ impl ValueId {
         }
         panic!(
             "Failed to access reactive value {self} of type {} at {caller} \
-             (disposed, borrowed, or type mismatch — see the logged error)",
+             (disposed, borrowed, or of another runtime — see the logged \
+             error)",
             type_name::<T>()
         );
     }
 
+    /// Slots are versioned, so a stale handle never reads the value now in
+    /// its slot. With `debug-info` errors still point it out.
+    fn stale_note(&self, _rt: &Runtime) -> &'static str {
+        #[cfg(feature = "debug-info")]
+        if _rt.storage.slot_reused(*self) {
+            return " (stale handle, its slot holds a newer value)";
+        }
+        ""
+    }
+
     pub fn notify(
         &self,
         rt: &Runtime,
@@ -156,14 +228,17 @@ impl ValueId {
     /// (WS1.8).
     #[track_caller]
     #[inline]
-    pub fn try_update_untracked<T: 'static, U>(
+    pub(crate) fn try_update_untracked<T: 'static, U>(
         &self,
         rt: &Runtime,
         f: impl FnOnce(&mut T) -> U,
         caller: &'static Location<'static>,
     ) -> Option<U> {
         let Some(cell) = rt.storage.value_rc(*self) else {
-            log::error!("Write to disposed reactive value {self} at {caller}");
+            log::error!(
+                "Write to disposed reactive value {self}{} at {caller}",
+                self.stale_note(rt)
+            );
             return None;
         };
 
@@ -200,20 +275,6 @@ impl ValueId {
         result
     }
 
-    #[track_caller]
-    #[inline(always)]
-    pub fn update_untracked<T: 'static, U>(
-        &self,
-        rt: &Runtime,
-        f: impl FnOnce(&mut T) -> U,
-        caller: &'static Location<'static>,
-    ) -> U {
-        match self.try_update_untracked::<T, U>(rt, f, caller) {
-            Some(result) => result,
-            None => self.panic_inaccessible::<T>(rt, caller),
-        }
-    }
-
     #[cfg(feature = "debug-info")]
     pub fn debug_info(&self) -> ValueDebugInfo {
         use crate::runtime::with_current_runtime;
@@ -240,6 +301,204 @@ impl ValueId {
     }
 }
 
+/// Kinds of nodes, for [`TypedValueId`].
+pub mod kind {
+    /// A kind of node, setting what the node stores for a value of type `T`.
+    pub trait Kind {
+        type Stored<T: 'static>: 'static;
+    }
+
+    macro_rules! kinds {
+        ($($(#[$meta: meta])* $name: ident: $stored: ty;)*) => {$(
+            $(#[$meta])*
+            pub struct $name;
+
+            impl Kind for $name {
+                type Stored<T: 'static> = $stored;
+            }
+        )*};
+    }
+
+    kinds! {
+        Signal: T;
+        Stored: T;
+        /// Unset until the first run.
+        Memo: Option<T>;
+        Computed: Option<T>;
+        Effect: Option<T>;
+        Probe: ();
+    }
+}
+
+/// A [`ValueId`] of a node of kind `K` holding a `T`. Handles keep their id
+/// typed, so a value is only ever read as the type it was created with.
+///
+/// With `multi-runtime` the id also remembers its runtime: a handle used in
+/// another runtime, or after its runtime is gone (and its slot possibly taken
+/// by a value of the next runtime), is caught as disposed, where the slot
+/// generation alone would let it reach the other value.
+pub struct TypedValueId<K, T: ?Sized> {
+    id: ValueId,
+    #[cfg(feature = "multi-runtime")]
+    runtime: RuntimeId,
+    ty: PhantomData<(K, T)>,
+}
+
+pub type SignalId<T> = TypedValueId<kind::Signal, T>;
+pub type StoredId<T> = TypedValueId<kind::Stored, T>;
+pub type MemoId<T> = TypedValueId<kind::Memo, T>;
+pub type ComputedId<T> = TypedValueId<kind::Computed, T>;
+pub type EffectId<T> = TypedValueId<kind::Effect, T>;
+pub type ProbeId = TypedValueId<kind::Probe, ()>;
+
+impl<K, T: ?Sized> Clone for TypedValueId<K, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, T: ?Sized> Copy for TypedValueId<K, T> {}
+
+impl<K, T: ?Sized> PartialEq for TypedValueId<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(feature = "multi-runtime")]
+        if self.runtime != other.runtime {
+            return false;
+        }
+        self.id == other.id
+    }
+}
+
+impl<K, T: ?Sized> Eq for TypedValueId<K, T> {}
+
+impl<K, T: ?Sized> Debug for TypedValueId<K, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.id, f)
+    }
+}
+
+/// The null id, of no value.
+impl<K, T: ?Sized> Default for TypedValueId<K, T> {
+    fn default() -> Self {
+        Self {
+            id: ValueId::null(),
+            #[cfg(feature = "multi-runtime")]
+            runtime: RuntimeId::null(),
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<K, T: ?Sized> TypedValueId<K, T> {
+    /// Type `id` of the current runtime. The node must be of kind `K` and
+    /// store a `K::Stored<T>`.
+    pub(crate) fn new(id: ValueId) -> Self {
+        Self {
+            id,
+            #[cfg(feature = "multi-runtime")]
+            runtime: current_runtime_id().unwrap_or_default(),
+            ty: PhantomData,
+        }
+    }
+
+    pub fn untyped(&self) -> ValueId {
+        self.id
+    }
+
+    /// Whether the id belongs to `rt`, logging it if not. Always `true`
+    /// without `multi-runtime`.
+    #[inline(always)]
+    pub(crate) fn check_runtime(
+        &self,
+        _rt: &Runtime,
+        _caller: &'static Location<'static>,
+    ) -> bool {
+        #[cfg(feature = "multi-runtime")]
+        if !self.id.is_null() && self.runtime != _rt.id {
+            log::error!(
+                "Reactive value {} used at {_caller} belongs to another runtime or outlived its own",
+                self.id
+            );
+            return false;
+        }
+        true
+    }
+
+    #[track_caller]
+    pub(crate) fn is_alive(&self, rt: &Runtime) -> bool {
+        self.check_runtime(rt, Location::caller()) && rt.is_alive(self.id)
+    }
+
+    /// # Safety
+    /// See [`Runtime::dispose`].
+    pub(crate) unsafe fn dispose(&self, rt: &Runtime) {
+        if self.check_runtime(rt, Location::caller()) {
+            unsafe { rt.dispose(self.id) }
+        }
+    }
+
+    #[track_caller]
+    pub(crate) fn subscribe(&self, rt: &Runtime) {
+        if self.check_runtime(rt, Location::caller()) {
+            self.id.subscribe(rt);
+        }
+    }
+
+    pub(crate) fn notify(
+        &self,
+        rt: &Runtime,
+        caller: &'static Location<'static>,
+    ) -> NotifyResult {
+        if self.check_runtime(rt, caller) {
+            self.id.notify(rt, caller)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<K: kind::Kind, T: 'static> TypedValueId<K, T> {
+    #[track_caller]
+    #[inline(always)]
+    pub(crate) fn with_untracked<U>(
+        &self,
+        rt: &Runtime,
+        f: impl FnOnce(&K::Stored<T>) -> U,
+        caller: &'static Location<'static>,
+    ) -> U {
+        let result = self
+            .check_runtime(rt, caller)
+            .then(|| self.id.try_with_untracked(rt, f, caller))
+            .flatten();
+        match result {
+            Some(result) => result,
+            // Contextful panic replacing the old bare `.unwrap()` — the specific
+            // cause (disposed / borrowed / type mismatch / other runtime) is
+            // already logged; here we add the id, type, and (under
+            // `debug-info`) the value's creation site.
+            None => self.id.panic_inaccessible::<K::Stored<T>>(rt, caller),
+        }
+    }
+
+    #[track_caller]
+    #[inline(always)]
+    pub(crate) fn update_untracked<U>(
+        &self,
+        rt: &Runtime,
+        f: impl FnOnce(&mut K::Stored<T>) -> U,
+        caller: &'static Location<'static>,
+    ) -> U {
+        let result = self
+            .check_runtime(rt, caller)
+            .then(|| self.id.try_update_untracked(rt, f, caller))
+            .flatten();
+        match result {
+            Some(result) => result,
+            None => self.id.panic_inaccessible::<K::Stored<T>>(rt, caller),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum ValueDebugInfoState {
     Clean(Option<(ValueId, &'static Location<'static>)>),
@@ -443,16 +702,75 @@ impl Value {
     }
 }
 
+#[cfg(not(feature = "fixed-capacity"))]
+pub(crate) type ValueMap = slotmap::SlotMap<ValueId, Value>;
+#[cfg(feature = "fixed-capacity")]
+pub(crate) type ValueMap =
+    crate::slab::Slab<ValueId, Value, { crate::slab::NODES }>;
+
+/// Per-node data keyed by [`ValueId`], e.g. the dependency graph edges.
+#[cfg(not(feature = "fixed-capacity"))]
+pub(crate) type NodeMap<V> = slotmap::SecondaryMap<ValueId, V>;
+#[cfg(feature = "fixed-capacity")]
+pub(crate) type NodeMap<V> =
+    crate::slab::SlabMap<ValueId, V, { crate::slab::NODES }>;
+
+/// Inline-small vector of value ids used for the dependency-graph edge sets
+/// (`sources`/`subscribers`/`owned`). Fan-in and fan-out are almost always
+/// tiny, so the common case stays on the stack with no per-node heap
+/// allocation; large fan-outs spill to the heap exactly once. Order is not
+/// significant — topological order for effect flushing comes from `height`.
+#[cfg(not(feature = "fixed-capacity"))]
+pub(crate) type IdVec = tinyvec::TinyVec<[ValueId; 4]>;
+/// Edge set of at most [`FANOUT`](crate::slab::FANOUT) ids; pushing past it
+/// logs and drops the edge.
+#[cfg(feature = "fixed-capacity")]
+pub(crate) type IdVec = crate::slab::EdgeList<{ crate::slab::FANOUT }>;
+
+/// Push `id` unless the edge set is full. Returns whether it was pushed.
+#[inline]
+pub(crate) fn id_vec_try_push(v: &mut IdVec, id: ValueId) -> bool {
+    #[cfg(not(feature = "fixed-capacity"))]
+    {
+        v.push(id);
+        true
+    }
+    #[cfg(feature = "fixed-capacity")]
+    v.try_push(id)
+}
+
 #[derive(Default)]
 pub struct Storage {
-    pub(crate) values: RefCell<SlotMap<ValueId, Value>>,
+    pub(crate) values: RefCell<ValueMap>,
 }
 
 impl Storage {
+    #[cfg(not(feature = "fixed-capacity"))]
     pub(crate) fn add_value(&self, value: Value) -> ValueId {
         self.values.borrow_mut().insert(value)
     }
 
+    /// Out of node slots, the value is dropped and a dead id returned, which
+    /// every access treats like a disposed value.
+    #[cfg(feature = "fixed-capacity")]
+    pub(crate) fn add_value(&self, value: Value) -> ValueId {
+        let result = self.values.borrow_mut().try_insert(value);
+        match result {
+            Ok(id) => id,
+            // Dropped after the `values` borrow: the value may own reactive
+            // handles whose `Drop` re-enters the storage.
+            Err(value) => {
+                log::error!(
+                    "Out of reactive node slots ({}), the new value is dead. \
+                     Raise `RSACT_NODES`",
+                    crate::slab::NODES
+                );
+                drop(value);
+                ValueId::null()
+            },
+        }
+    }
+
     pub(crate) fn get(&self, id: ValueId) -> Option<Value> {
         self.values.borrow().get(id).cloned()
     }
@@ -470,6 +788,16 @@ impl Storage {
         self.values.borrow().get(id).map(|v| v.kind.tag())
     }
 
+    /// Whether the slot of a removed `id` holds another value.
+    #[cfg(feature = "debug-info")]
+    pub(crate) fn slot_reused(&self, id: ValueId) -> bool {
+        let index = |id: ValueId| id.data().as_ffi() as u32;
+        self.values
+            .borrow()
+            .keys()
+            .any(|live| index(live) == index(id))
+    }
+
     /// Clone only the inner value cell `Rc` (a single refcount bump) instead of
     /// the entire [`Value`]. Used by read/write access paths that need the cell
     /// but not the kind.
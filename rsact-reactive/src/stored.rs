@@ -2,14 +2,12 @@ use crate::{
     ReactiveValue,
     read::{ReadSignal, impl_read_signal_traits},
     runtime::with_current_runtime,
-    storage::ValueId,
+    storage::{StoredId, ValueId},
     write::WriteSignal,
 };
-use core::marker::PhantomData;
 
 pub struct StoredValue<T: 'static> {
-    id: ValueId,
-    ty: PhantomData<T>,
+    id: StoredId<T>,
 }
 
 impl<T: 'static> StoredValue<T> {
@@ -17,13 +15,13 @@ impl<T: 'static> StoredValue<T> {
     pub fn new(value: T) -> Self {
         let caller = core::panic::Location::caller();
         let id = with_current_runtime(|rt| rt.create_stored(value, caller));
-        Self { id, ty: PhantomData }
+        Self { id: StoredId::new(id) }
     }
 }
 
 impl<T: 'static> Clone for StoredValue<T> {
     fn clone(&self) -> Self {
-        Self { id: self.id.clone(), ty: self.ty.clone() }
+        *self
     }
 }
 impl<T: 'static> Copy for StoredValue<T> {}
@@ -32,15 +30,15 @@ impl<T: 'static> ReactiveValue for StoredValue<T> {
     type Value = T;
 
     fn id(&self) -> Option<ValueId> {
-        Some(self.id)
+        Some(self.id.untyped())
     }
 
     fn is_alive(&self) -> bool {
-        with_current_runtime(|rt| rt.is_alive(self.id))
+        with_current_runtime(|rt| self.id.is_alive(rt))
     }
 
     unsafe fn dispose(self) {
-        unsafe { with_current_runtime(|rt| rt.dispose(self.id)) }
+        unsafe { with_current_runtime(|rt| self.id.dispose(rt)) }
     }
 }
 
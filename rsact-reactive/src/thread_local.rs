@@ -87,6 +87,18 @@ pub mod fake_thread_local {
         }
     }
 
+    impl<T: 'static> FakeThreadLocal<T> {
+        /// Mirrors `std::thread::LocalKey::try_with`. A process-global cell is
+        /// never destroyed, so access cannot fail.
+        #[inline]
+        pub fn try_with<R>(
+            &'static self,
+            f: impl FnOnce(&T) -> R,
+        ) -> Result<R, core::convert::Infallible> {
+            Ok(self.with(f))
+        }
+    }
+
     macro_rules! thread_local_impl {
         () => {};
 
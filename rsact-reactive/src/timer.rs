@@ -0,0 +1,417 @@
+//! Timers driven by an external millisecond clock.
+//!
+//! The runtime has no clock of its own: the application advances it with
+//! [`tick_timers`] (in `rsact-ui`, `UI::tick_time` does it), and every armed
+//! timer whose deadline has passed fires during that call, inside one
+//! [`batch`]. Between ticks [`next_timer_deadline`] tells the main loop when it
+//! must wake up next, so an idle device can sleep instead of polling.
+//!
+//! A timer is a reactive node like any other: it belongs to the current scope
+//! (or observer) and is disposed with it, which also stops it. Times are `u32`
+//! milliseconds and may wrap around, like animations' `now_millis`.
+
+use crate::{
+    ReactiveValue,
+    effect::create_effect,
+    read::ReadSignal,
+    runtime::{batch, with_current_runtime},
+    signal::{Signal, create_signal, marker::ReadOnly},
+    storage::ValueId,
+    stored::StoredValue,
+    write::WriteSignal,
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::cell::{Cell, RefCell};
+
+/// Per-runtime clock and timer registry.
+#[derive(Default)]
+pub(crate) struct TimerQueue {
+    now: Cell<u32>,
+    /// Every timer ever created in the runtime. Disposed timers are dropped
+    /// from the list lazily, on the next tick.
+    timers: RefCell<Vec<Timer>>,
+}
+
+struct TimerState {
+    deadline: Option<u32>,
+    period: u32,
+    repeat: bool,
+    /// `None` while the callback runs.
+    callback: Option<Box<dyn FnMut()>>,
+}
+
+/// `deadline` has passed at `now`, accounting for clock wrap-around.
+fn is_due(deadline: u32, now: u32) -> bool {
+    now.wrapping_sub(deadline) as i32 >= 0
+}
+
+/// The last time passed to [`tick_timers`] (`0` before the first tick).
+pub fn now_millis() -> u32 {
+    with_current_runtime(|rt| rt.timers.now.get())
+}
+
+/// Advance the clock to `now_millis` and fire every due timer.
+///
+/// An interval that fell behind by more than one period fires once and is
+/// rescheduled from `now_millis`: missed periods are skipped, not replayed in
+/// a burst.
+pub fn tick_timers(now_millis: u32) {
+    let timers = with_current_runtime(|rt| {
+        rt.timers.now.set(now_millis);
+        let mut timers = rt.timers.timers.borrow_mut();
+        timers.retain(|timer| timer.is_alive());
+        timers.clone()
+    });
+
+    batch(|| timers.into_iter().for_each(|timer| timer.fire(now_millis)));
+}
+
+/// The deadline of the timer due soonest, or `None` if no timer is armed. A
+/// deadline at or before [`now_millis`] means "tick again right away".
+pub fn next_timer_deadline() -> Option<u32> {
+    let (now, timers) = with_current_runtime(|rt| {
+        (rt.timers.now.get(), rt.timers.timers.borrow().clone())
+    });
+
+    timers
+        .into_iter()
+        .filter(|timer| timer.is_alive())
+        .filter_map(|timer| timer.deadline())
+        .min_by_key(|deadline| deadline.wrapping_sub(now) as i32)
+}
+
+/// Call `f` every `period_millis`, starting one period from now.
+#[track_caller]
+pub fn create_interval(period_millis: u32, f: impl FnMut() + 'static) -> Timer {
+    Timer::new(period_millis, true, f, true)
+}
+
+/// Call `f` once, `delay_millis` from now. [`Timer::restart`] re-arms it.
+#[track_caller]
+pub fn create_timeout(delay_millis: u32, f: impl FnMut() + 'static) -> Timer {
+    Timer::new(delay_millis, false, f, true)
+}
+
+/// A signal following `source`, but only once `source` has stayed unchanged
+/// for `millis`: each change restarts the wait.
+#[track_caller]
+pub fn debounced<T: Clone + 'static>(
+    source: impl ReadSignal<T> + 'static,
+    millis: u32,
+) -> Signal<T, ReadOnly> {
+    let mut output = create_signal(source.with_untracked(T::clone));
+    let mut pending = StoredValue::new(None::<T>);
+
+    let timer = Timer::new(
+        millis,
+        false,
+        move || {
+            if let Some(value) = pending.update_untracked(Option::take) {
+                output.set(value);
+            }
+        },
+        false,
+    );
+
+    create_effect(move |prev: Option<()>| {
+        let value = source.with(T::clone);
+        // The first run only subscribes: `output` already holds the value.
+        if prev.is_some() {
+            pending.set(Some(value));
+            timer.restart();
+        }
+    });
+
+    output.read_only()
+}
+
+/// A signal following `source` at most once per `millis`. A change after a
+/// quiet period passes through at once; changes arriving faster are coalesced
+/// into one update at the end of the period, carrying the latest value.
+#[track_caller]
+pub fn throttled<T: Clone + 'static>(
+    source: impl ReadSignal<T> + 'static,
+    millis: u32,
+) -> Signal<T, ReadOnly> {
+    let mut output = create_signal(source.with_untracked(T::clone));
+    let mut pending = StoredValue::new(None::<T>);
+    let mut last_emit = StoredValue::new(None::<u32>);
+
+    let mut emit = move || {
+        if let Some(value) = pending.update_untracked(Option::take) {
+            output.set(value);
+            last_emit.set(Some(now_millis()));
+        }
+    };
+
+    let trailing = Timer::new(millis, false, emit, false);
+
+    create_effect(move |prev: Option<()>| {
+        let value = source.with(T::clone);
+        if prev.is_none() {
+            return;
+        }
+
+        pending.set(Some(value));
+        if trailing.is_armed() {
+            return;
+        }
+
+        let now = now_millis();
+        match last_emit.get() {
+            Some(last) if now.wrapping_sub(last) < millis => {
+                trailing.start_in(millis - now.wrapping_sub(last));
+            },
+            _ => emit(),
+        }
+    });
+
+    output.read_only()
+}
+
+/// Handle to a timer created by [`create_interval`]/[`create_timeout`].
+///
+/// Disposing it (or its scope) stops it for good; [`Timer::cancel`] only
+/// disarms it until the next [`Timer::restart`].
+pub struct Timer {
+    state: StoredValue<TimerState>,
+}
+
+impl Clone for Timer {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for Timer {}
+
+impl ReactiveValue for Timer {
+    type Value = ();
+
+    fn id(&self) -> Option<ValueId> {
+        self.state.id()
+    }
+
+    fn is_alive(&self) -> bool {
+        self.state.is_alive()
+    }
+
+    unsafe fn dispose(self) {
+        unsafe { self.state.dispose() };
+    }
+}
+
+impl Timer {
+    /// A timer firing `period` after being armed; `armed` arms it right
+    /// away.
+    #[track_caller]
+    pub(crate) fn new(
+        period: u32,
+        repeat: bool,
+        callback: impl FnMut() + 'static,
+        armed: bool,
+    ) -> Self {
+        let deadline = armed.then(|| now_millis().wrapping_add(period));
+        let timer = Self {
+            state: StoredValue::new(TimerState {
+                deadline,
+                period,
+                repeat,
+                callback: Some(Box::new(callback)),
+            }),
+        };
+
+        with_current_runtime(|rt| rt.timers.timers.borrow_mut().push(timer));
+
+        timer
+    }
+
+    /// Arm the timer one period (or its delay) from now, replacing any
+    /// pending deadline.
+    pub fn restart(&self) {
+        let now = now_millis();
+        self.with_state(|state| {
+            state.deadline = Some(now.wrapping_add(state.period));
+        });
+    }
+
+    /// Arm the timer `millis` from now, replacing any pending deadline. An
+    /// interval keeps its own period afterwards.
+    pub fn start_in(&self, millis: u32) {
+        let deadline = now_millis().wrapping_add(millis);
+        self.with_state(|state| state.deadline = Some(deadline));
+    }
+
+    /// Disarm the timer without disposing it.
+    pub fn cancel(&self) {
+        self.with_state(|state| state.deadline = None);
+    }
+
+    pub fn is_armed(&self) -> bool {
+        self.deadline().is_some()
+    }
+
+    /// When the timer fires next, if armed.
+    pub fn deadline(&self) -> Option<u32> {
+        if !self.is_alive() {
+            return None;
+        }
+        self.state.with_untracked(|state| state.deadline)
+    }
+
+    fn with_state(&self, f: impl FnOnce(&mut TimerState)) {
+        if self.is_alive() {
+            let mut state = self.state;
+            state.update_untracked(f);
+        } else {
+            log::warn!("Using a disposed timer");
+        }
+    }
+
+    fn fire(self, now: u32) {
+        if !self.is_alive() {
+            return;
+        }
+
+        let mut state = self.state;
+        let callback = state.update_untracked(|state| {
+            let deadline = state.deadline.filter(|&d| is_due(d, now))?;
+
+            state.deadline = state.repeat.then(|| {
+                let next = deadline.wrapping_add(state.period);
+                if is_due(next, now) {
+                    now.wrapping_add(state.period)
+                } else {
+                    next
+                }
+            });
+
+            state.callback.take()
+        });
+
+        // Called outside the state borrow: the callback may restart, cancel or
+        // dispose this very timer.
+        if let Some(mut callback) = callback {
+            callback();
+            if self.is_alive() {
+                state.update_untracked(|state| state.callback = Some(callback));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        create_interval, create_timeout, debounced, next_timer_deadline,
+        throttled, tick_timers,
+    };
+    use crate::{
+        read::ReadSignal, runtime::with_new_runtime, scope::new_scope,
+        signal::create_signal, write::WriteSignal,
+    };
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    fn counter() -> (Rc<Cell<u32>>, impl FnMut() + 'static) {
+        let count = Rc::new(Cell::new(0));
+        let inc = count.clone();
+        (count, move || inc.set(inc.get() + 1))
+    }
+
+    #[test]
+    fn interval_and_timeout_fire_on_deadline() {
+        with_new_runtime(|_| {
+            let (intervals, inc) = counter();
+            create_interval(100, inc);
+            let (timeouts, inc) = counter();
+            let timeout = create_timeout(150, inc);
+
+            assert_eq!(next_timer_deadline(), Some(100));
+
+            tick_timers(99);
+            assert_eq!((intervals.get(), timeouts.get()), (0, 0));
+            tick_timers(100);
+            tick_timers(160);
+            assert_eq!((intervals.get(), timeouts.get()), (1, 1));
+            assert_eq!(next_timer_deadline(), Some(200));
+
+            // Fell behind several periods: fires once, then resumes from now.
+            tick_timers(550);
+            assert_eq!((intervals.get(), timeouts.get()), (2, 1));
+            assert_eq!(next_timer_deadline(), Some(650));
+
+            timeout.restart();
+            assert_eq!(next_timer_deadline(), Some(650));
+            tick_timers(700);
+            assert_eq!((intervals.get(), timeouts.get()), (3, 2));
+        });
+    }
+
+    #[test]
+    fn timers_stop_with_their_scope() {
+        with_new_runtime(|_| {
+            let (count, inc) = counter();
+            let scope = new_scope();
+            let timer = create_interval(10, inc);
+            drop(scope);
+
+            assert!(!timer.is_armed());
+            assert_eq!(next_timer_deadline(), None);
+            tick_timers(100);
+            assert_eq!(count.get(), 0);
+        });
+    }
+
+    #[test]
+    fn clock_wraps_around() {
+        with_new_runtime(|_| {
+            tick_timers(u32::MAX - 5);
+            let (count, inc) = counter();
+            create_timeout(10, inc);
+
+            assert_eq!(next_timer_deadline(), Some(4));
+            tick_timers(u32::MAX);
+            assert_eq!(count.get(), 0);
+            tick_timers(4);
+            assert_eq!(count.get(), 1);
+        });
+    }
+
+    #[test]
+    fn debounced_waits_for_quiet() {
+        with_new_runtime(|_| {
+            let mut source = create_signal(0);
+            let output = debounced(source, 50);
+
+            source.set(1);
+            tick_timers(30);
+            source.set(2);
+            tick_timers(60);
+            assert_eq!(output.get(), 0, "change at 30 restarted the wait");
+            tick_timers(80);
+            assert_eq!(output.get(), 2);
+        });
+    }
+
+    #[test]
+    fn throttled_coalesces_fast_changes() {
+        with_new_runtime(|_| {
+            tick_timers(1000);
+            let mut source = create_signal(0);
+            let output = throttled(source, 100);
+
+            source.set(1);
+            assert_eq!(output.get(), 1, "leading change passes through");
+
+            tick_timers(1020);
+            source.set(2);
+            source.set(3);
+            assert_eq!(output.get(), 1);
+            assert_eq!(next_timer_deadline(), Some(1100));
+
+            tick_timers(1100);
+            assert_eq!(output.get(), 3, "trailing update has the latest value");
+        });
+    }
+}
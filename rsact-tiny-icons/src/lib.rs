@@ -34,6 +34,12 @@ impl<BO: ByteOrder> IconRaw<BO> {
     }
 }
 
+impl<BO: ByteOrder> PartialEq for IconRaw<BO> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.data == other.data
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct Icon<C: Color, BO: ByteOrder> {
     raw: IconRaw<BO>,
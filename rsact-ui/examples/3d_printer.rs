@@ -272,6 +272,18 @@ fn main() {
         }
     });
 
+    // Last minute of temperatures at the effect's pace, for the sparklines
+    let mut bed_history = create_signal(Samples::new(60));
+    let mut nozzle_history = create_signal(Samples::new(60));
+    create_effect(move |_| {
+        let temp = bed_temp.get().inner() as f32;
+        bed_history.update(|history| history.push(temp));
+    });
+    create_effect(move |_| {
+        let temp = nozzle_temp.get().inner() as f32;
+        nozzle_history.update(|history| history.push(temp));
+    });
+
     // TODO: Nozzle and bed icons?
     let temp_page = Flex::row([
         Flex::col([
@@ -290,6 +302,7 @@ fn main() {
         .el(),
         Flex::col([
             Label::new(bed_temp.map(|temp| format!("{temp}C"))).el(),
+            Chart::sparkline(bed_history).el(),
             Knob::new(bed_temp).el(),
             Label::new("Bed").el(),
         ])
@@ -299,6 +312,7 @@ fn main() {
         .el(),
         Flex::col([
             Label::new(nozzle_temp.map(|temp| format!("{temp}C"))).el(),
+            Chart::sparkline(nozzle_history).el(),
             Knob::new(nozzle_temp).el(),
             Label::new("Nozzle").el(),
         ])
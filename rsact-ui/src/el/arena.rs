@@ -104,6 +104,17 @@ impl ArenaChildren {
         self.children.get(parent).map(|v| v.as_slice())
     }
 
+    /// Children of `parent`, creating an empty list if it has none yet.
+    /// `None` only if `parent` was removed from the primary map.
+    fn get_or_default(
+        &mut self,
+        parent: ElId,
+    ) -> Option<&mut ArenaChildrenVec> {
+        self.children
+            .entry(parent)
+            .map(|entry| entry.or_insert_with(ArenaChildrenVec::new))
+    }
+
     pub fn remove(&mut self, parent: ElId) -> Option<ArenaChildrenVec> {
         self.children.remove(parent)
     }
@@ -114,6 +125,11 @@ pub struct ElArena<W: WidgetCtx> {
     pub(crate) children: ArenaChildren,
     // TODO: Do we really need parent relation separately?
     pub(crate) parents: slotmap::SecondaryMap<ElId, ElId>,
+    /// Children taken out of their parent's children by [`park_child`], kept
+    /// built until put back or until the parent is removed.
+    ///
+    /// [`park_child`]: ElArena::park_child
+    parked: slotmap::SecondaryMap<ElId, Vec<ElId>>,
 }
 
 impl<W: WidgetCtx> ElArena<W> {
@@ -122,6 +138,7 @@ impl<W: WidgetCtx> ElArena<W> {
             els: ArenaEls::new(),
             children: ArenaChildren::new(),
             parents: slotmap::SecondaryMap::new(),
+            parked: slotmap::SecondaryMap::new(),
         }
     }
 
@@ -186,6 +203,9 @@ impl<W: WidgetCtx> ElArena<W> {
             if let Some(children) = self.children.remove(id) {
                 stack.extend(children.iter().copied());
             }
+            if let Some(parked) = self.parked.remove(id) {
+                stack.extend(parked);
+            }
             self.parents.remove(id);
             // Dispose the element's render probes as it leaves the tree, so
             // they do not outlive it (WS2.3) — otherwise every reactive rebuild
@@ -213,9 +233,13 @@ impl<W: WidgetCtx> ElArena<W> {
             let old_children = self.children.set(id, children);
 
             if let Some(old_children) = old_children {
-                old_children.iter().for_each(|child_id| {
-                    self.remove_subtree(*child_id);
-                });
+                let kept = self.children.get(id).unwrap_or(&[]).to_vec();
+                old_children
+                    .iter()
+                    .filter(|child_id| !kept.contains(child_id))
+                    .for_each(|child_id| {
+                        self.remove_subtree(*child_id);
+                    });
             }
         } else {
             error!(
@@ -244,6 +268,143 @@ impl<W: WidgetCtx> ElArena<W> {
         }
     }
 
+    // Keyed/incremental children updates (used by `For`). Unlike
+    // `set_children`, which swaps the whole list and removes every old
+    // subtree, these touch a single slot so the remaining children keep their
+    // ids, built widgets and render probes. Bad indices are logged and ignored.
+
+    /// Insert an already added `child` at `index` in `id`'s children.
+    pub fn insert_child(&mut self, id: ElId, index: usize, child: ElId) {
+        if !self.els.els.contains_key(id) {
+            error!(
+                "Trying to insert child of non-existent element with id {:?}",
+                id
+            );
+            return;
+        }
+
+        match self.children.get_or_default(id) {
+            Some(children) if index <= children.len() => {
+                children.insert(index, child);
+            },
+            _ => {
+                error!("Child index {index} out of bounds for element {id:?}");
+                self.remove_subtree(child);
+            },
+        }
+    }
+
+    /// Remove the child at `index` of `id` together with its subtree.
+    pub fn remove_child(&mut self, id: ElId, index: usize) {
+        let removed = self
+            .children
+            .children
+            .get_mut(id)
+            .filter(|children| index < children.len())
+            .map(|children| children.remove(index));
+
+        match removed {
+            Some(child) => self.remove_subtree(child),
+            None => {
+                error!("Child index {index} out of bounds for element {id:?}")
+            },
+        }
+    }
+
+    /// Move the child at `from` to `to` (index after removal) without
+    /// touching its subtree.
+    pub fn move_child(&mut self, id: ElId, from: usize, to: usize) {
+        match self.children.children.get_mut(id) {
+            Some(children) if from < children.len() && to < children.len() => {
+                let child = children.remove(from);
+                children.insert(to, child);
+            },
+            _ => error!(
+                "Child move {from} -> {to} out of bounds for element {id:?}"
+            ),
+        }
+    }
+
+    /// Replace the child at `index` by an already added `child`, removing the
+    /// old child's subtree.
+    pub fn replace_child(&mut self, id: ElId, index: usize, child: ElId) {
+        let old = self
+            .children
+            .children
+            .get_mut(id)
+            .and_then(|children| children.get_mut(index))
+            .map(|slot| core::mem::replace(slot, child));
+
+        match old {
+            Some(old) => self.remove_subtree(old),
+            None => {
+                error!("Child index {index} out of bounds for element {id:?}");
+                self.remove_subtree(child);
+            },
+        }
+    }
+
+    /// Remove every child of `id` together with their subtrees.
+    pub fn clear_children(&mut self, id: ElId) {
+        if let Some(children) = self.children.get_or_default(id) {
+            let old = core::mem::take(children);
+            old.iter().for_each(|child| self.remove_subtree(*child));
+        }
+    }
+
+    /// Take the child at `index` out of `id`'s children without removing its
+    /// subtree. No pass reaches a parked child, it stays built (its widgets
+    /// keep their state) until [`unpark_child`] puts it back, and is removed
+    /// together with `id`.
+    ///
+    /// [`unpark_child`]: ElArena::unpark_child
+    pub fn park_child(&mut self, id: ElId, index: usize) -> Option<ElId> {
+        let parked = self
+            .children
+            .children
+            .get_mut(id)
+            .filter(|children| index < children.len())
+            .map(|children| children.remove(index));
+
+        match parked {
+            Some(child) => {
+                if let Some(parked) = self.parked.entry(id) {
+                    parked.or_default().push(child);
+                }
+            },
+            None => {
+                error!("Child index {index} out of bounds for element {id:?}")
+            },
+        }
+
+        parked
+    }
+
+    /// Put the parked `child` of `id` back at `index` in its children.
+    pub fn unpark_child(&mut self, id: ElId, index: usize, child: ElId) {
+        let parked = self.parked.get_mut(id).and_then(|parked| {
+            parked
+                .iter()
+                .position(|&parked| parked == child)
+                .map(|position| parked.swap_remove(position))
+        });
+
+        if parked.is_none() {
+            error!("Element {child:?} is not a parked child of {id:?}");
+            return;
+        }
+
+        match self.children.get_or_default(id) {
+            Some(children) if index <= children.len() => {
+                children.insert(index, child);
+            },
+            _ => {
+                error!("Child index {index} out of bounds for element {id:?}");
+                self.remove_subtree(child);
+            },
+        }
+    }
+
     pub fn add(&mut self, parent: Option<ElId>, el: &mut El<W>) -> ElId {
         let id = self.els.els.insert_with_key(|id| {
             let layout = el.layout();
@@ -61,25 +61,30 @@ impl<W: WidgetCtx> BuildCtx<W> {
         root
     }
 
-    // TODO: Is it possible to reconcile children preserving unchanged?
-    // This requires children memos to not return new children, reusing old, but
-    // it seems to require user to do this. Or we can compare previous
-    // widget with new, but comparison can be very expensive, so skip this
-    // variant. We better make something like a SignalVec datatype that will
-    // support diffing and preserving old values. So we would compare:
-    // remove(El::Stored) -> remove, remove(El::New) -> do nothing, add
-    // (El::Stored) -> keep, add(El::New) -> build. Or maybe ChildrenQueue
-    // command queue like "PushChild", "SetChild", "RemoveChild".
+    /// Replace all children, building every new one and removing every old
+    /// subtree. For lists that change item by item, drive the children with
+    /// the incremental methods below from a `SignalVec` (see
+    /// [`For`](crate::widget::for_each::For)) so unchanged children survive.
+    ///
+    /// Children already stored in the arena (e.g. kept in a reactive children
+    /// list across writes) keep their id and are not rebuilt.
     pub fn set_children(&mut self, children: &mut [El<W>]) -> &mut Self {
         let children_ids = children
             .iter_mut()
-            .map(|child| self.add_inner(child))
+            .map(|child| match child {
+                El::Stored { id, .. } => (*id, false),
+                El::New(_) => (self.add_inner(child), true),
+            })
             .collect::<Vec<_>>();
 
-        children_ids.iter().for_each(|child_id| {
-            self.build_el(*child_id);
+        children_ids.iter().for_each(|&(child_id, new)| {
+            if new {
+                self.build_el(child_id);
+            }
         });
 
+        let children_ids = children_ids.into_iter().map(|(id, _)| id).collect();
+
         self.arena.update_untracked(|arena| {
             arena.set_children(self.id, children_ids);
         });
@@ -98,6 +103,66 @@ impl<W: WidgetCtx> BuildCtx<W> {
         self
     }
 
+    /// Add and build `child` at `index` among this element's children.
+    pub fn insert_child(&mut self, index: usize, child: &mut El<W>) -> ElId {
+        let child_id = self.add_inner(child);
+        self.build_el(child_id);
+
+        self.arena.update_untracked(|arena| {
+            arena.insert_child(self.id, index, child_id);
+        });
+
+        child_id
+    }
+
+    /// Remove the child at `index` and its subtree.
+    pub fn remove_child(&mut self, index: usize) -> &mut Self {
+        self.arena
+            .update_untracked(|arena| arena.remove_child(self.id, index));
+        self
+    }
+
+    /// Move the child at `from` to `to`, keeping it built.
+    pub fn move_child(&mut self, from: usize, to: usize) -> &mut Self {
+        self.arena
+            .update_untracked(|arena| arena.move_child(self.id, from, to));
+        self
+    }
+
+    /// Build `child` in place of the child at `index`, removing the old one.
+    pub fn replace_child(&mut self, index: usize, child: &mut El<W>) -> ElId {
+        let child_id = self.add_inner(child);
+        self.build_el(child_id);
+
+        self.arena.update_untracked(|arena| {
+            arena.replace_child(self.id, index, child_id);
+        });
+
+        child_id
+    }
+
+    /// Take the child at `index` out of this element's children, keeping it
+    /// built for [`unpark_child`](Self::unpark_child).
+    pub fn park_child(&mut self, index: usize) -> Option<ElId> {
+        self.arena
+            .update_untracked(|arena| arena.park_child(self.id, index))
+    }
+
+    /// Put a child parked by [`park_child`](Self::park_child) back at `index`.
+    pub fn unpark_child(&mut self, index: usize, child: ElId) -> &mut Self {
+        self.arena.update_untracked(|arena| {
+            arena.unpark_child(self.id, index, child)
+        });
+        self
+    }
+
+    /// Remove every child and its subtree.
+    pub fn clear_children(&mut self) -> &mut Self {
+        self.arena
+            .update_untracked(|arena| arena.clear_children(self.id));
+        self
+    }
+
     fn build_el(&mut self, id: ElId) {
         let Some(data) = self.arena.update_untracked(|arena| arena.take_el(id))
         else {
@@ -165,9 +230,9 @@ impl<W: WidgetCtx> BuildCtx<W> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        el::{El, arena::ElArena, build::BuildCtx},
+        el::{El, View, arena::ElArena, build::BuildCtx},
         test_support::NullWtf,
-        widget::{Widget, combinators::Unit},
+        widget::{Widget, combinators::Unit, flex::Flex},
     };
     use rsact_reactive::prelude::*;
 
@@ -192,4 +257,33 @@ mod tests {
             });
         });
     }
+
+    /// Reactive children: a write re-sets the children, keeping the ones
+    /// already stored and building only the new ones.
+    #[test]
+    fn reactive_children_keep_stored_ones() {
+        with_new_runtime(|_| {
+            let mut children = create_signal(alloc::vec![Unit.el(), Unit.el()]);
+            let mut root = Flex::<NullWtf>::col(children).into_el();
+            let arena = create_signal(ElArena::new());
+            let root_id = BuildCtx::run(&mut root, arena);
+
+            let ids = || {
+                arena.with(|arena| arena.children(root_id).unwrap().to_vec())
+            };
+            let initial = ids();
+            assert_eq!(initial.len(), 2);
+
+            children.update(|children| children.push(Unit.el()));
+            let pushed = ids();
+            assert_eq!(pushed.len(), 3);
+            assert_eq!(&pushed[..2], &initial[..]);
+
+            children.update(|children| {
+                children.remove(0);
+            });
+            assert_eq!(ids(), &pushed[1..]);
+            arena.with(|arena| assert!(!arena.contains(initial[0])));
+        });
+    }
 }
@@ -116,6 +116,13 @@ pub struct PageState<W: WidgetCtx> {
     /// the window and we preserve last known position.
     pub pointer: PointerState,
 
+    /// Element owning the open overlay (a drop-down list, a context menu), if
+    /// any. While set, the page routes every event to this element first and
+    /// draws its [`Widget::render_overlay`] on top of the tree.
+    ///
+    /// [`Widget::render_overlay`]: crate::widget::Widget::render_overlay
+    pub overlay: Option<ElId>,
+
     ctx: PhantomData<W>,
 }
 
@@ -125,6 +132,7 @@ impl<W: WidgetCtx> PageState<W> {
             focused: None,
             focus_pressed: false,
             pointer: PointerState::new(),
+            overlay: None,
             ctx: PhantomData,
         }
     }
@@ -165,6 +173,11 @@ impl<W: WidgetCtx> PageState<W> {
         {
             self.pointer.pressed = None;
         }
+        if let Some(id) = self.overlay
+            && !arena.contains(id)
+        {
+            self.overlay = None;
+        }
     }
 
     // pub fn is_hovered(&self, id: ElId) -> bool {
@@ -273,6 +273,28 @@ impl<'a, W: WidgetCtx + 'static> EventCtx<'a, W> {
         self.page_state.pointer.captured_by = None;
     }
 
+    /// Open this widget's overlay: from the next event on, the page routes
+    /// every event to this widget before anything else and draws its
+    /// [`Widget::render_overlay`] above the rest of the page. Replaces the
+    /// overlay of any other widget.
+    ///
+    /// [`Widget::render_overlay`]: crate::widget::Widget::render_overlay
+    pub fn open_overlay(&mut self) {
+        self.page_state.overlay = Some(self.id);
+    }
+
+    /// Close this widget's overlay, if it owns the open one.
+    pub fn close_overlay(&mut self) {
+        if self.owns_overlay() {
+            self.page_state.overlay = None;
+        }
+    }
+
+    /// Whether this widget owns the open overlay.
+    pub fn owns_overlay(&self) -> bool {
+        self.page_state.overlay == Some(self.id)
+    }
+
     // TODO: Maybe better rename to `handle_behavior` or `handle_behavioral`?
     /// Automatic, source-of-truth behavioral bookkeeping for a widget: hover
     /// tracking plus **press claiming**. Call this first in `on_event`. It does
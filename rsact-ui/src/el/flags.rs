@@ -37,6 +37,11 @@ bitflags! {
         // /// Edge widgets are widgets without children. This flag is generally
         // needed for debugging purposes in cases when something went wrong and
         // layout or other tree mismatches with widget tree. const IS_EDGE = 1 << 5;
+
+        // Rendering //
+        /// Clip the widget and its children to its inner rect, for widgets
+        /// laying children out beyond their own bounds.
+        const CLIP_INNER = 1 << 5;
     }
 }
 
@@ -63,6 +68,10 @@ impl WidgetFlags {
         self | Self::FOCUSABLE
     }
 
+    pub fn clip_inner(self) -> Self {
+        self | Self::CLIP_INNER
+    }
+
     // pub fn is_edge(self) -> Self {
     //     self | Self::IS_EDGE
     // }
@@ -87,6 +96,10 @@ impl WidgetFlags {
     pub fn is_focusable(self) -> bool {
         self.contains(Self::FOCUSABLE)
     }
+
+    pub fn is_clip_inner(self) -> bool {
+        self.contains(Self::CLIP_INNER)
+    }
 }
 
 impl Default for WidgetFlags {
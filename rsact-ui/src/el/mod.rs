@@ -135,7 +135,8 @@ where
         Self::New(ElData::new(Box::new(builder)))
     }
 
-    pub(crate) fn layout(&self) -> Layout {
+    /// The element's layout, which a wrapping widget nests into its own.
+    pub fn layout(&self) -> Layout {
         match self {
             Self::New(data) => match &data.stage {
                 ElStage::Unbuilt(b) => b.layout(),
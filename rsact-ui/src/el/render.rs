@@ -192,7 +192,19 @@ impl<'a, W: WidgetCtx> RenderCtx<'a, W, CtxReady> {
     where
         W::Stylist: Stylist<S>,
     {
-        let pseudoclass = self.pseudoclass();
+        self.get_style_for(self.pseudoclass(), style)
+    }
+
+    /// Same as [`RenderCtx::get_style`] but for the given pseudoclass, e.g.
+    /// this element's one with the `active` state the widget keeps itself.
+    pub fn get_style_for<S: Style>(
+        &self,
+        pseudoclass: StylePseudoClass,
+        style: Option<&dyn Fn(S, &StyleSelector) -> S>,
+    ) -> S
+    where
+        W::Stylist: Stylist<S>,
+    {
         let selector = StyleSelector { pseudoclass };
         let base = self.shared.stylist.style(&S::base(), &selector);
         if let Some(style_fn) = style {
@@ -243,6 +255,17 @@ impl<'a, W: WidgetCtx> RenderCtx<'a, W, CtxUnready> {
         &mut self,
         hash_source: &'static str,
         f: impl FnOnce(RenderCtx<'_, W, CtxReady>) -> RenderResult,
+    ) -> RenderResult {
+        self.render_probe(hash_source, true, f)
+    }
+
+    /// Poll the probe of part `hash_source`, clearing the element rect before
+    /// `f` when `clear` is set (and the parent did not already clear it).
+    fn render_probe(
+        &mut self,
+        hash_source: &'static str,
+        clear: bool,
+        f: impl FnOnce(RenderCtx<'_, W, CtxReady>) -> RenderResult,
     ) -> RenderResult {
         // Imperative force-dirty flag that triggers redraw even if no reactive
         // dependency changed in the probe.
@@ -296,7 +319,7 @@ impl<'a, W: WidgetCtx> RenderCtx<'a, W, CtxUnready> {
             // the clear is always paired with an actual
             // redraw — never a clear-without-redraw or a
             // redraw-without-clear.
-            if !self.frame.parent_dirty {
+            if clear && !self.frame.parent_dirty {
                 self.clear_outer()?;
             }
 
@@ -342,6 +365,29 @@ impl<'a, W: WidgetCtx> RenderCtx<'a, W, CtxUnready> {
         // already unique per element — no per-frame `format!` (WS1.7).
         self.render_part("self", f)
     }
+
+    /// Like [`render_self`](Self::render_self), but for widgets that can draw
+    /// only what changed over their previous frame, e.g. a chart appending a
+    /// sample.
+    ///
+    /// `f` gets `full = true` when the whole widget must be drawn: on the
+    /// first frame, on a forced redraw (relayout, navigation), on a
+    /// pseudoclass change and when the parent repainted the area. The element
+    /// rect is then cleared as usual. Otherwise only the widget's own reactive
+    /// reads changed, the rect is left as it was and `f` draws the difference,
+    /// clearing whatever it redraws itself.
+    pub fn render_self_incremental(
+        &mut self,
+        f: impl FnOnce(RenderCtx<'_, W, CtxReady>, bool) -> RenderResult,
+    ) -> RenderResult {
+        let first = !self.part_probes.iter().any(|(key, _)| *key == "self");
+        let full = first
+            || self.frame.parent_dirty
+            || self.needs_redraw.is_some()
+            || self.shared.force_redraw.get_untracked();
+
+        self.render_probe("self", full, |ctx| f(ctx, full))
+    }
 }
 
 impl<'a, W: WidgetCtx, S> RenderCtx<'a, W, S> {
@@ -414,6 +460,90 @@ impl<'a, W: WidgetCtx> RenderPass<'a, W> {
             frame,
         )
     }
+
+    /// Draw the overlay of `target` (see [`Widget::render_overlay`]) on top of
+    /// the already rendered tree under `root`.
+    ///
+    /// [`Widget::render_overlay`]: crate::widget::Widget::render_overlay
+    pub fn render_overlay(
+        &mut self,
+        target: ElId,
+        root: ElId,
+        layout: &LayoutModelNode<'_>,
+        visual: RenderVisual<W>,
+    ) -> RenderResult {
+        OverlayPass {
+            els: &self.arena.els,
+            children: &self.arena.children,
+            renderer: self.renderer,
+            shared: self.shared,
+        }
+        .render_to(target, root, layout, visual)
+        .unwrap_or_else(|| {
+            error!("Overlay owner {target:?} is not in the rendered tree");
+            Ok(())
+        })
+    }
+}
+
+/// The arena and the render targets a search for an overlay owner descends
+/// with.
+struct OverlayPass<'a, 'r, W: WidgetCtx> {
+    els: &'a ArenaEls<W>,
+    children: &'a ArenaChildren,
+    renderer: &'r mut W::Renderer,
+    shared: RenderShared<'a, W>,
+}
+
+impl<'a, 'r, W: WidgetCtx> OverlayPass<'a, 'r, W> {
+    /// Finds `target` under `id` (descending like the event pass, so its
+    /// layout node is resolved correctly) and renders its overlay. `None`
+    /// while searching.
+    fn render_to(
+        &mut self,
+        target: ElId,
+        id: ElId,
+        layout: &LayoutModelNode<'_>,
+        visual: RenderVisual<W>,
+    ) -> Option<RenderResult> {
+        let data = self.els.expect(id)?;
+
+        if id == target {
+            let widget = data.stage.built()?;
+            let mut dirten = false;
+            let mut part_probes = TinyVec::new();
+            return Some(widget.render_overlay(RenderCtx {
+                id,
+                debug_name: data.state.debug_name,
+                dirten: &mut dirten,
+                needs_redraw: None,
+                hovered: data.state.hovered(),
+                pressed: data.state.pressed(),
+                part_probes: &mut part_probes,
+                renderer: self.renderer,
+                layout,
+                visual,
+                frame: RenderFrame::root(0),
+                shared: self.shared,
+                _marker: PhantomData,
+            }));
+        }
+
+        let children_ids = self.children.get(id)?;
+
+        if data.state.flags.is_transparent_layout() {
+            if children_ids.len() == 1 {
+                return self.render_to(target, children_ids[0], layout, visual);
+            }
+            return None;
+        }
+
+        children_ids.iter().zip(layout.children()).find_map(
+            |(child, child_layout)| {
+                self.render_to(target, *child, &child_layout, visual)
+            },
+        )
+    }
 }
 
 fn render_subtree<W: WidgetCtx>(
@@ -78,7 +78,7 @@ impl<W: WidgetCtx> ElState<W> {
             pressed: false,
 
             needs_redraw: None,
-            clip_path: None,
+            clip_path: flags.is_clip_inner().then_some(ClipPath::InnerRect),
             part_probes: TinyVec::new(),
             // pseudoclass: StylePseudoClass::default(),
         }
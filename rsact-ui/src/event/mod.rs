@@ -5,6 +5,7 @@ use crate::{
 use core::{fmt::Debug, ops::ControlFlow};
 
 pub mod message;
+mod record;
 pub mod select;
 #[cfg(feature = "simulator")]
 pub mod simulator;
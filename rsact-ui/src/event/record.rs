@@ -0,0 +1,166 @@
+//! [`Recordable`] encoding of input events, so [`UI`](crate::ui::UI) can log
+//! them into a session recording and feed them back on replay.
+
+use super::{
+    DevToolsEvent, Event, FocusEvent, MouseButton, MouseEvent, MouseWheelDir,
+    MoveDir, MoveEvent, PressEvent,
+};
+use crate::{el::ElId, render::prelude::*};
+use alloc::vec::Vec;
+use rsact_reactive::record::Recordable;
+use slotmap::{Key, KeyData};
+
+/// Encode a fieldless enum by its discriminant. Variants must be listed in
+/// declaration order.
+macro_rules! recordable_unit_enum {
+    ($ty: ident { $($variant: ident),* $(,)? }) => {
+        impl Recordable for $ty {
+            fn encode(&self, out: &mut Vec<u8>) {
+                out.push(*self as u8);
+            }
+
+            fn decode(input: &mut &[u8]) -> Option<Self> {
+                const ALL: &[$ty] = &[$($ty::$variant),*];
+                ALL.get(usize::from(u8::decode(input)?)).copied()
+            }
+        }
+    };
+}
+
+recordable_unit_enum!(DevToolsEvent { Activate, Deactivate, Toggle });
+recordable_unit_enum!(MoveDir { Left, Right, Up, Down });
+recordable_unit_enum!(PressEvent { Press, Release });
+recordable_unit_enum!(MouseButton { Left, Middle, Right });
+recordable_unit_enum!(MouseWheelDir { Normal, Flipped });
+
+// `Point` is foreign to this crate, so it is encoded by hand.
+fn encode_point(point: Point, out: &mut Vec<u8>) {
+    (point.x, point.y).encode(out);
+}
+
+fn decode_point(input: &mut &[u8]) -> Option<Point> {
+    let (x, y) = <(i32, i32)>::decode(input)?;
+    Some(Point::new(x, y))
+}
+
+impl Recordable for MoveEvent {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.dir.encode(out);
+        self.delta.encode(out);
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        Some(Self { dir: MoveDir::decode(input)?, delta: u16::decode(input)? })
+    }
+}
+
+impl Recordable for FocusEvent {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            FocusEvent::Focus(id) => id.data().as_ffi().encode(out),
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        Some(FocusEvent::Focus(ElId::from(KeyData::from_ffi(u64::decode(
+            input,
+        )?))))
+    }
+}
+
+impl Recordable for MouseEvent {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match *self {
+            MouseEvent::ButtonDown(button, point) => {
+                out.push(0);
+                button.encode(out);
+                point.map(|p| (p.x, p.y)).encode(out);
+            },
+            MouseEvent::ButtonUp(button, point) => {
+                out.push(1);
+                button.encode(out);
+                point.map(|p| (p.x, p.y)).encode(out);
+            },
+            MouseEvent::MouseMove(point) => {
+                out.push(2);
+                encode_point(point, out);
+            },
+            MouseEvent::Wheel(point, dir) => {
+                out.push(3);
+                encode_point(point, out);
+                dir.encode(out);
+            },
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        let button_point = |input: &mut &[u8]| {
+            let button = MouseButton::decode(input)?;
+            let point = Option::<(i32, i32)>::decode(input)?
+                .map(|(x, y)| Point::new(x, y));
+            Some((button, point))
+        };
+
+        Some(match u8::decode(input)? {
+            0 => {
+                let (button, point) = button_point(input)?;
+                MouseEvent::ButtonDown(button, point)
+            },
+            1 => {
+                let (button, point) = button_point(input)?;
+                MouseEvent::ButtonUp(button, point)
+            },
+            2 => MouseEvent::MouseMove(decode_point(input)?),
+            3 => MouseEvent::Wheel(
+                decode_point(input)?,
+                MouseWheelDir::decode(input)?,
+            ),
+            _ => return None,
+        })
+    }
+}
+
+impl<Custom: Recordable> Recordable for Event<Custom> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            Event::Move(event) => {
+                out.push(0);
+                event.encode(out);
+            },
+            Event::Focus(event) => {
+                out.push(1);
+                event.encode(out);
+            },
+            Event::Press(event) => {
+                out.push(2);
+                event.encode(out);
+            },
+            Event::Exit => out.push(3),
+            Event::DevTools(event) => {
+                out.push(4);
+                event.encode(out);
+            },
+            Event::Mouse(event) => {
+                out.push(5);
+                event.encode(out);
+            },
+            Event::Custom(event) => {
+                out.push(6);
+                event.encode(out);
+            },
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Option<Self> {
+        Some(match u8::decode(input)? {
+            0 => Event::Move(MoveEvent::decode(input)?),
+            1 => Event::Focus(FocusEvent::decode(input)?),
+            2 => Event::Press(PressEvent::decode(input)?),
+            3 => Event::Exit,
+            4 => Event::DevTools(DevToolsEvent::decode(input)?),
+            5 => Event::Mouse(MouseEvent::decode(input)?),
+            6 => Event::Custom(Custom::decode(input)?),
+            _ => return None,
+        })
+    }
+}
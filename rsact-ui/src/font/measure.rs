@@ -9,8 +9,14 @@
 //!
 //! Proportional fonts (future `fontdue`) do not use this module — they
 //! implement `FontHandler::text_height_for_width` directly via glyph metrics.
+//!
+//! The paragraph flow at the end of the module ([`FlowBuilder`],
+//! [`flow_lines`]) is font-agnostic: it wraps rich text made of runs in
+//! different fonts from word widths the fonts measured beforehand.
 
 use super::{TextIntrinsics, TextOverflow};
+use alloc::vec::Vec;
+use core::ops::Range;
 
 /// Pixel width of a single monospace line containing `char_count` characters.
 pub fn line_px(char_count: u32, char_w: u32, spacing: u32) -> u32 {
@@ -133,6 +139,218 @@ pub fn mono_intrinsics(
     }
 }
 
+/// How a [`FlowPiece`] joins the piece before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FlowBreak {
+    /// No whitespace in between: both are parts of one word, e.g. a word
+    /// continued in a run of another font, and never wrap apart.
+    #[default]
+    Glued,
+    /// Whitespace of this width in between, a soft wrap opportunity. The
+    /// space is dropped when the line wraps there.
+    Space(u32),
+    /// A hard `'\n'` in between.
+    Line,
+}
+
+/// A word, or the part of a word in one run, of a paragraph. Pieces are never
+/// broken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowPiece {
+    /// Index of the run the piece comes from.
+    pub run: usize,
+    /// Byte range of the piece in the text of the run, empty for inline boxes
+    /// and empty lines.
+    pub text: Range<usize>,
+    pub width: u32,
+    pub height: u32,
+    pub before: FlowBreak,
+}
+
+/// Splits the runs of a paragraph into [`FlowPiece`]s, in order.
+///
+/// Words are split on whitespace. A run's leading or trailing whitespace
+/// separates its words from the neighbouring runs; without it, a word goes on
+/// in the next run.
+#[derive(Debug, Default)]
+pub struct FlowBuilder {
+    pieces: Vec<FlowPiece>,
+    run: usize,
+    pending: FlowBreak,
+    line_height: u32,
+}
+
+impl FlowBuilder {
+    /// Add a run of text set in one font. `word_width` measures a single word
+    /// in that font.
+    pub fn text(
+        &mut self,
+        content: &str,
+        line_height: u32,
+        space_width: u32,
+        word_width: impl Fn(&str) -> u32,
+    ) {
+        self.line_height = line_height;
+
+        let mut word_start = None;
+        for (at, char) in content.char_indices() {
+            if !char.is_whitespace() {
+                word_start.get_or_insert(at);
+                continue;
+            }
+
+            if let Some(start) = word_start.take() {
+                self.piece(start..at, word_width(&content[start..at]));
+            }
+
+            if char == '\n' {
+                // Consecutive `'\n'` leave an empty line in between, as does
+                // one opening the paragraph.
+                if self.pending == FlowBreak::Line || self.pieces.is_empty() {
+                    self.piece(0..0, 0);
+                }
+                self.pending = FlowBreak::Line;
+            } else if self.pending != FlowBreak::Line {
+                self.pending = FlowBreak::Space(space_width);
+            }
+        }
+        if let Some(start) = word_start {
+            self.piece(start..content.len(), word_width(&content[start..]));
+        }
+
+        self.run += 1;
+    }
+
+    /// Add a run that is a single unbreakable box, such as an inline icon.
+    pub fn inline_box(&mut self, width: u32, height: u32) {
+        self.line_height = height;
+        self.piece(0..0, width);
+        self.run += 1;
+    }
+
+    /// The pieces of the paragraph. An empty paragraph, as one ending with
+    /// `'\n'`, still ends with a line as high as its last run.
+    pub fn finish(mut self) -> Vec<FlowPiece> {
+        if self.pending == FlowBreak::Line || self.pieces.is_empty() {
+            self.piece(0..0, 0);
+        }
+        self.pieces
+    }
+
+    fn piece(&mut self, text: Range<usize>, width: u32) {
+        self.pieces.push(FlowPiece {
+            run: self.run,
+            text,
+            width,
+            height: self.line_height,
+            before: self.pending,
+        });
+        self.pending = FlowBreak::Glued;
+    }
+}
+
+/// A visual line of a paragraph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowLine {
+    /// Range of the pieces on the line.
+    pub pieces: Range<usize>,
+    pub width: u32,
+    /// Height of the highest piece on the line.
+    pub height: u32,
+}
+
+/// Greedy word wrap of `pieces` into `width` pixels. A word wider than
+/// `width` gets a line of its own and overflows it.
+pub fn flow_lines(pieces: &[FlowPiece], width: u32) -> Vec<FlowLine> {
+    let mut lines = Vec::new();
+    let mut line = FlowLine { pieces: 0..0, width: 0, height: 0 };
+
+    let mut at = 0;
+    while at < pieces.len() {
+        let word = flow_word(pieces, at);
+        let word_width = word_width(&pieces[word.clone()]);
+        let word_height =
+            pieces[word.clone()].iter().map(|piece| piece.height).max();
+
+        let gap = match pieces[at].before {
+            FlowBreak::Space(gap) if at != 0 => Some(gap),
+            _ => None,
+        };
+        let wraps = at != 0
+            && match gap {
+                Some(gap) => {
+                    line.width.saturating_add(gap).saturating_add(word_width)
+                        > width
+                },
+                None => pieces[at].before == FlowBreak::Line,
+            };
+
+        if wraps {
+            let next = FlowLine { pieces: at..at, width: 0, height: 0 };
+            lines.push(core::mem::replace(&mut line, next));
+        } else if let Some(gap) = gap {
+            line.width = line.width.saturating_add(gap);
+        }
+
+        line.pieces.end = word.end;
+        line.width = line.width.saturating_add(word_width);
+        line.height = line.height.max(word_height.unwrap_or(0));
+        at = word.end;
+    }
+
+    if !pieces.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Total height of `pieces` wrapped into `width` pixels.
+pub fn flow_height_for_width(pieces: &[FlowPiece], width: u32) -> u32 {
+    flow_lines(pieces, width)
+        .iter()
+        .map(|line| line.height)
+        .sum()
+}
+
+/// Intrinsic width range of a paragraph: its widest word and its widest hard
+/// line, and the height of its first line.
+pub fn flow_intrinsics(pieces: &[FlowPiece]) -> TextIntrinsics {
+    let mut min_content_width = 0;
+    let mut at = 0;
+    while at < pieces.len() {
+        let word = flow_word(pieces, at);
+        min_content_width =
+            min_content_width.max(word_width(&pieces[word.clone()]));
+        at = word.end;
+    }
+
+    let lines = flow_lines(pieces, u32::MAX);
+    TextIntrinsics {
+        min_content_width,
+        max_content_width: lines
+            .iter()
+            .map(|line| line.width)
+            .max()
+            .unwrap_or(0),
+        line_height: lines.first().map_or(0, |line| line.height),
+    }
+}
+
+/// Range of the word starting with the piece at `at`: it and the pieces glued
+/// to it.
+fn flow_word(pieces: &[FlowPiece], at: usize) -> Range<usize> {
+    let glued = pieces[at + 1..]
+        .iter()
+        .take_while(|piece| piece.before == FlowBreak::Glued)
+        .count();
+    at..at + 1 + glued
+}
+
+fn word_width(word: &[FlowPiece]) -> u32 {
+    word.iter()
+        .fold(0, |width, piece| width.saturating_add(piece.width))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -255,4 +473,86 @@ mod tests {
             }
         );
     }
+
+    /// Monospace runs of 6px chars with 1px spacing, 10px high.
+    fn mono_flow(runs: &[&str]) -> Vec<FlowPiece> {
+        let mut flow = FlowBuilder::default();
+        for run in runs {
+            flow.text(run, 10, 8, |word| {
+                line_px(word.chars().count() as u32, 6, 1)
+            });
+        }
+        flow.finish()
+    }
+
+    #[test]
+    fn flow_splits_words_and_glues_them_across_runs() {
+        let pieces = mono_flow(&["Temp: 21", "5", "°C (target)"]);
+        let breaks =
+            pieces.iter().map(|piece| piece.before).collect::<Vec<_>>();
+        assert_eq!(
+            breaks,
+            [
+                FlowBreak::Glued,
+                FlowBreak::Space(8),
+                FlowBreak::Glued,
+                FlowBreak::Glued,
+                FlowBreak::Space(8),
+            ]
+        );
+        // "21" + "5" + "°C" is one word, measured per run.
+        assert_eq!(pieces[3].run, 2);
+        assert_eq!(pieces[3].text, 0..3);
+        assert_eq!(pieces[3].width, 13);
+    }
+
+    #[test]
+    fn flow_wraps_at_spaces_only() {
+        let pieces = mono_flow(&["Temp: 21", "5", "°C (target)"]);
+        // "Temp:" 34, "215°C" 13 + 6 + 13, "(target)" 55
+        assert_eq!(flow_lines(&pieces, 1000).len(), 1);
+        assert_eq!(flow_lines(&pieces, 1000)[0].width, 34 + 8 + 32 + 8 + 55);
+
+        let lines = flow_lines(&pieces, 80);
+        assert_eq!(
+            lines
+                .iter()
+                .map(|line| line.pieces.clone())
+                .collect::<Vec<_>>(),
+            [0..4, 4..5]
+        );
+        assert_eq!(lines[0].width, 34 + 8 + 32);
+        assert_eq!(flow_height_for_width(&pieces, 80), 20);
+
+        // The glued word overflows rather than breaks.
+        assert_eq!(flow_lines(&pieces, 10).len(), 3);
+    }
+
+    #[test]
+    fn flow_keeps_hard_and_empty_lines() {
+        assert_eq!(flow_lines(&mono_flow(&["a\n\nb"]), 1000).len(), 3);
+        assert_eq!(flow_lines(&mono_flow(&["a\n", "b"]), 1000).len(), 2);
+        assert_eq!(flow_lines(&mono_flow(&["a\n"]), 1000).len(), 2);
+        assert_eq!(flow_height_for_width(&mono_flow(&[]), 1000), 0);
+        assert_eq!(flow_height_for_width(&mono_flow(&[""]), 1000), 10);
+    }
+
+    #[test]
+    fn flow_line_is_as_high_as_its_highest_piece() {
+        let mut flow = FlowBuilder::default();
+        flow.text("a ", 10, 8, |_| 6);
+        flow.inline_box(16, 16);
+        flow.text(" b", 10, 8, |_| 6);
+        let pieces = flow.finish();
+
+        assert_eq!(
+            flow_intrinsics(&pieces),
+            TextIntrinsics {
+                min_content_width: 16,
+                max_content_width: 6 + 8 + 16 + 8 + 6,
+                line_height: 16
+            }
+        );
+        assert_eq!(flow_height_for_width(&pieces, 20), 10 + 16 + 10);
+    }
 }
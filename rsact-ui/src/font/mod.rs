@@ -1,7 +1,8 @@
 use crate::{el::ctx::WidgetCtx, render::prelude::*};
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 use core::fmt::{Debug, Display};
 use fixed::{FixedFont, FixedFontCollection};
+use measure::{FlowBuilder, FlowPiece};
 // portable-atomic gives `AtomicUsize::fetch_add` on no-CAS targets (thumbv6m),
 // where `core::sync::atomic::AtomicUsize` has no CAS ops. Native instructions
 // on thumbv7m+. See the thumbv6m note in the README for the fallback the end
@@ -186,6 +187,16 @@ pub enum FontStyle {
     // Oblique,
 }
 
+/// One run of a rich text paragraph, what layout needs to know to wrap it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TextRun {
+    /// Text in one font. Unset font properties are inherited from the
+    /// paragraph.
+    Text { content: String, font_props: FontProps },
+    /// An unbreakable box, such as an inline icon.
+    Box(Size),
+}
+
 /// Resolved font properties
 #[derive(Debug, Clone, Copy)]
 pub struct ResolvedFontProps {
@@ -502,6 +513,41 @@ impl FontCtx {
         }
     }
 
+    /// Split a rich text paragraph into the pieces [`measure::flow_lines`]
+    /// wraps. `parent` are the font properties the runs inherit.
+    pub fn flow_pieces(
+        &self,
+        runs: &[TextRun],
+        parent: &FontProps,
+        viewport: Size,
+    ) -> Vec<FlowPiece> {
+        let mut flow = FlowBuilder::default();
+        for run in runs {
+            match run {
+                TextRun::Text { content, font_props } => {
+                    let resolved = font_props.inherited(parent);
+                    let font = resolved.font();
+                    let props = resolved.resolve(viewport);
+                    let width = |text: &str| {
+                        self.measure_text(font, text, props, TextOverflow::Clip)
+                            .max_content_width
+                    };
+                    let line_height = self
+                        .measure_text(font, content, props, TextOverflow::Clip)
+                        .line_height;
+                    // What a space adds between two words, including the
+                    // spacing around it.
+                    let space_width =
+                        width("x x").saturating_sub(2 * width("x"));
+
+                    flow.text(content, line_height, space_width, width);
+                },
+                TextRun::Box(size) => flow.inline_box(size.width, size.height),
+            }
+        }
+        flow.finish()
+    }
+
     // TODO: Background color!
     // TODO: Alignment!
     pub fn render<W: WidgetCtx>(
@@ -1,6 +1,6 @@
 use crate::{
     el::ElId,
-    font::{FontCtx, FontProps, FontSize, TextOverflow},
+    font::{FontCtx, FontProps, FontSize, TextOverflow, TextRun, measure},
     layout::{
         length::LengthSize,
         node::Layout,
@@ -77,6 +77,11 @@ pub enum ContentLayout {
         content: MaybeReactive<String>,
         overflow: TextOverflow,
     },
+    /// A paragraph of runs in different fonts, wrapped as a whole.
+    Rich {
+        font_props: FontProps,
+        runs: MaybeReactive<Vec<TextRun>>,
+    },
     // TODO: MaybeReactive problem described in Icon widget
     Icon(Memo<FontSize>),
     Fixed(Size),
@@ -94,6 +99,11 @@ impl Debug for ContentLayout {
                 .field("content", &"<reactive>")
                 .field("overflow", overflow)
                 .finish(),
+            ContentLayout::Rich { font_props, .. } => f
+                .debug_struct("Rich")
+                .field("font_props", font_props)
+                .field("runs", &"<reactive>")
+                .finish(),
             ContentLayout::Icon(_) => {
                 f.debug_tuple("Icon").field(&"<reactive>").finish()
             },
@@ -120,6 +130,9 @@ impl Display for ContentLayout {
             ContentLayout::Text { content, .. } => content.with(|content| {
                 write!(f, "Text [{}]", DisplayTruncated::new(content, 16))
             }),
+            ContentLayout::Rich { runs, .. } => {
+                runs.with(|runs| write!(f, "Rich [{} runs]", runs.len()))
+            },
             ContentLayout::Icon(size) => {
                 size.with(|size| write!(f, "Icon [{size}]"))
             },
@@ -137,6 +150,10 @@ impl ContentLayout {
         }
     }
 
+    pub fn rich(runs: MaybeReactive<Vec<TextRun>>) -> Self {
+        Self::Rich { font_props: Default::default(), runs }
+    }
+
     pub fn icon(size: Memo<FontSize>) -> Self {
         Self::Icon(size)
     }
@@ -167,6 +184,16 @@ impl ContentLayout {
                     }
                 })
             },
+            ContentLayout::Rich { font_props, runs } => {
+                let intrinsics = measure::flow_intrinsics(
+                    &self.flow_pieces(ctx, font_props, runs),
+                );
+                ContentSizing {
+                    min_content: intrinsics.min_content_width,
+                    max_content: intrinsics.max_content_width,
+                    line_height: intrinsics.line_height,
+                }
+            },
             ContentLayout::Icon(memo) => {
                 let size = memo.with(|size| size.resolve(ctx.viewport));
                 ContentSizing {
@@ -200,6 +227,12 @@ impl ContentLayout {
                     )
                 })
             },
+            ContentLayout::Rich { font_props, runs } => {
+                measure::flow_height_for_width(
+                    &self.flow_pieces(ctx, font_props, runs),
+                    width,
+                )
+            },
             ContentLayout::Icon(memo) => {
                 memo.with(|size| size.resolve(ctx.viewport))
             },
@@ -207,6 +240,20 @@ impl ContentLayout {
         }
     }
 
+    fn flow_pieces(
+        &self,
+        ctx: &LayoutCtx,
+        font_props: &FontProps,
+        runs: &MaybeReactive<Vec<TextRun>>,
+    ) -> Vec<measure::FlowPiece> {
+        let parent = font_props.inherited(&ctx.font_props);
+        runs.with(|runs| {
+            #[cfg(feature = "layout-counters")]
+            crate::layout::counters::count_measure();
+            ctx.fonts.flow_pieces(runs, &parent, ctx.viewport)
+        })
+    }
+
     /// Honest lower-bound size: the min-content width and a single line's
     /// height. Used by flex to bound items. For wrapping text this is the
     /// widest unbreakable word, never `0`.
@@ -418,6 +465,17 @@ impl ScrollableLayout {
     }
 }
 
+/// Rows of a virtualized list: only the materialized window of rows is in the
+/// tree, stacked vertically from `start`, the position of row `first` relative
+/// to the list top (negative when it is scrolled partly out).
+#[derive(Clone, Debug, Copy, PartialEq, Default)]
+pub struct VirtualLayout {
+    pub first: usize,
+    pub len: usize,
+    pub start: i32,
+    pub font_props: FontProps,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct DevFlexLayout {
     // lines: Vec<Rectangle>,
@@ -456,6 +514,7 @@ pub enum DevLayoutKind {
     Container(ContainerLayout),
     Flex(DevFlexLayout),
     Scrollable(ScrollableLayout),
+    Virtual(VirtualLayout),
 }
 
 impl DevLayoutKind {
@@ -464,7 +523,8 @@ impl DevLayoutKind {
             DevLayoutKind::Zero
             | DevLayoutKind::Edge
             | DevLayoutKind::Content(_)
-            | DevLayoutKind::Scrollable(_) => None,
+            | DevLayoutKind::Scrollable(_)
+            | DevLayoutKind::Virtual(_) => None,
             DevLayoutKind::Container(ContainerLayout {
                 block_model, ..
             })
@@ -533,6 +593,14 @@ impl Display for DevLayoutKind {
             }) => {
                 write!(f, "Scrollable content")
             },
+            DevLayoutKind::Virtual(VirtualLayout {
+                first,
+                len,
+                start,
+                font_props: _,
+            }) => {
+                write!(f, "Virtual rows {first}..{} at {start}", first + len)
+            },
         }
     }
 }
@@ -583,6 +651,7 @@ pub enum LayoutKind {
     Container(ContainerLayout),
     Flex(FlexLayout),
     Scrollable(ScrollableLayout),
+    Virtual(VirtualLayout),
 }
 
 // WS4.1: contains `LayoutKind`, no longer `Copy`.
@@ -615,6 +684,13 @@ impl LayoutData {
         }
     }
 
+    pub fn expect_virtual_mut(&mut self) -> &mut VirtualLayout {
+        match &mut self.kind {
+            LayoutKind::Virtual(virtual_layout) => virtual_layout,
+            _ => unreachable!(),
+        }
+    }
+
     pub fn expect_flex_mut(&mut self) -> &mut FlexLayout {
         match &mut self.kind {
             LayoutKind::Flex(flex) => flex,
@@ -652,6 +728,9 @@ impl LayoutData {
             LayoutKind::Scrollable(scrollable_layout) => {
                 scrollable_layout.min_size(ctx, tree, id)
             },
+            // Rows come and go with scrolling, the list is sized by its own
+            // length only.
+            LayoutKind::Virtual(_) => Size::zero(),
         }
     }
 
@@ -661,7 +740,8 @@ impl LayoutData {
             LayoutKind::Zero
             | LayoutKind::Edge
             | LayoutKind::Content(..)
-            | LayoutKind::Scrollable(..) => BlockModel::zero(),
+            | LayoutKind::Scrollable(..)
+            | LayoutKind::Virtual(..) => BlockModel::zero(),
             LayoutKind::Container(ContainerLayout { block_model, .. })
             | LayoutKind::Flex(FlexLayout { block_model, .. }) => *block_model,
         }
@@ -672,7 +752,8 @@ impl LayoutData {
             LayoutKind::Zero => None,
             LayoutKind::Edge => None,
             LayoutKind::Content(content_layout) => match content_layout {
-                ContentLayout::Text { font_props, .. } => Some(*font_props),
+                ContentLayout::Text { font_props, .. }
+                | ContentLayout::Rich { font_props, .. } => Some(*font_props),
                 ContentLayout::Icon(_) => None,
                 ContentLayout::Fixed(_) => None,
             },
@@ -683,6 +764,9 @@ impl LayoutData {
             LayoutKind::Scrollable(scrollable_layout) => {
                 Some(scrollable_layout.font_props)
             },
+            LayoutKind::Virtual(virtual_layout) => {
+                Some(virtual_layout.font_props)
+            },
         }
     }
 
@@ -691,7 +775,8 @@ impl LayoutData {
             LayoutKind::Zero => None,
             LayoutKind::Edge => None,
             LayoutKind::Content(content_layout) => match content_layout {
-                ContentLayout::Text { font_props, .. } => Some(font_props),
+                ContentLayout::Text { font_props, .. }
+                | ContentLayout::Rich { font_props, .. } => Some(font_props),
                 ContentLayout::Icon(_) => None,
                 ContentLayout::Fixed(_) => None,
             },
@@ -702,6 +787,9 @@ impl LayoutData {
             LayoutKind::Scrollable(scrollable_layout) => {
                 Some(&mut scrollable_layout.font_props)
             },
+            LayoutKind::Virtual(virtual_layout) => {
+                Some(&mut virtual_layout.font_props)
+            },
         }
     }
 
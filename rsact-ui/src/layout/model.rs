@@ -5,10 +5,10 @@ use crate::{
     font::FontProps,
     layout::{
         Align, ContainerLayout, ContentLayout, DevHoveredLayout, LayoutCtx,
-        LayoutKind, Limits, ScrollableLayout,
+        LayoutKind, Limits, ScrollableLayout, VirtualLayout,
         flex::model_flex,
         length::LengthSize,
-        tree::{LayoutTree, effective_single_child},
+        tree::{LayoutTree, effective_children, effective_single_child},
     },
     render::prelude::*,
 };
@@ -294,6 +294,7 @@ pub fn model_layout<T: LayoutTree + ?Sized>(
                 let sizing = content_layout.content_sizing(ctx);
                 let layout_font_props = match content_layout {
                     ContentLayout::Text { font_props: text_fp, .. }
+                    | ContentLayout::Rich { font_props: text_fp, .. }
                         if text_fp.has_any() =>
                     {
                         let resolved = text_fp.inherited(&ctx.font_props);
@@ -418,6 +419,51 @@ pub fn model_layout<T: LayoutTree + ?Sized>(
             LayoutKind::Flex(flex_layout) => {
                 model_flex(ctx, tree, id, parent_limits, flex_layout, size)
             },
+            LayoutKind::Virtual(virtual_layout) => {
+                let &VirtualLayout {
+                    first: _,
+                    len: _,
+                    start,
+                    font_props: virtual_fp,
+                } = virtual_layout;
+
+                let child_fp = virtual_fp.inherited(&ctx.font_props);
+                let child_ctx = LayoutCtx { font_props: child_fp, ..*ctx };
+
+                let real_size =
+                    parent_limits.resolve_size(size, Size::zero(), None);
+
+                // Rows take the list width and their own height, stacked from
+                // `start` regardless of the list height: the out-of-view parts
+                // are clipped.
+                let row_limits = Limits::new(
+                    Size::zero(),
+                    Size::new(real_size.width, u32::MAX),
+                );
+                let mut top = start;
+                let rows = effective_children(tree, id)
+                    .into_iter()
+                    .map(|row_id| {
+                        let mut row = model_layout(
+                            &child_ctx, tree, row_id, row_limits, size,
+                        );
+                        row.translate_mut(Point::new(0, top));
+                        top += row.outer_size().height as i32;
+                        row
+                    })
+                    .collect();
+
+                LayoutModel::new(
+                    real_size,
+                    rows,
+                    #[cfg(feature = "debug-info")]
+                    DevLayout::new(
+                        size,
+                        DevLayoutKind::Virtual(virtual_layout.clone()),
+                    ),
+                )
+                .with_font_props(virtual_fp.has_any().then_some(child_fp))
+            },
         }
     })
 }
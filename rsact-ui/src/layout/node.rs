@@ -1,6 +1,5 @@
 use crate::layout::LayoutData;
-use core::panic::Location;
-use rsact_reactive::{prelude::*, storage::ValueId};
+use rsact_reactive::{prelude::*, stored::StoredValue};
 
 /**
  * Layout is a custom Signal type that is Reactive-on-Write, it means that
@@ -15,19 +14,34 @@ use rsact_reactive::{prelude::*, storage::ValueId};
  *   won't make it reactive, as we don't know if value in `.set` comes from
  *   reactive or inert source.
  */
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy)]
 pub enum Layout {
-    Static(ValueId),
+    Static(StoredValue<LayoutData>),
     Reactive(Signal<LayoutData>),
 }
 
+// By identity for `Static`, as reactive handles compare by value.
+impl PartialEq for Layout {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Static(this), Self::Static(other)) => {
+                this.id() == other.id()
+            },
+            (Self::Reactive(this), Self::Reactive(other)) => this == other,
+            _ => false,
+        }
+    }
+}
+
 // Manual Debug: reactive handles no longer implement Debug (WS1.4), and reading
 // the signal's value here would subscribe whatever observer is formatting it.
 // Print the handle's identity only.
 impl core::fmt::Debug for Layout {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            Layout::Static(id) => f.debug_tuple("Static").field(id).finish(),
+            Layout::Static(inert) => {
+                f.debug_tuple("Static").field(&inert.id()).finish()
+            },
             Layout::Reactive(signal) => {
                 write!(f, "Reactive(<signal {:?}>)", signal.id())
             },
@@ -38,27 +52,20 @@ impl core::fmt::Debug for Layout {
 impl Layout {
     #[track_caller]
     pub(super) fn inert(layout: LayoutData) -> Self {
-        let caller = Location::caller();
         // Note: We use Inert value, but it is fake Inert as we write to it,
         // reactive runtime allows this.
-
-        Self::Static(with_current_runtime(|rt| rt.create_inert(layout, caller)))
+        Self::Static(StoredValue::new(layout))
     }
 
     #[track_caller]
     fn now_reactive(&mut self) -> Signal<LayoutData> {
-        let caller = Location::caller();
-
         match self {
             Self::Static(inert) => {
                 // TODO: rsact-reactive unsafe-denoted method to convert between
                 // ValueId reactive types, for Inert -> Signal. So we don't have to clone but do move.
-                let signal = with_current_runtime(|rt| -> LayoutData {
-                    inert.with_untracked(rt, Clone::clone, caller)
-                })
-                .signal();
+                let signal = inert.with_untracked(Clone::clone).signal();
 
-                unsafe { with_current_runtime(|rt| rt.dispose(*inert)) };
+                unsafe { inert.dispose() };
 
                 *self = Self::Reactive(signal);
 
@@ -71,12 +78,8 @@ impl Layout {
     // Warn: Untracked! Don't expect to be tracked inside an observer
     #[track_caller]
     pub fn update_untracked(&mut self, f: impl FnOnce(&mut LayoutData)) {
-        let caller = Location::caller();
-
         match self {
-            Self::Static(inert) => {
-                with_current_runtime(|rt| inert.update_untracked(rt, f, caller))
-            },
+            Self::Static(inert) => inert.update_untracked(f),
             Self::Reactive(signal) => {
                 signal.update_untracked(|layout| f(layout))
             },
@@ -94,12 +97,8 @@ impl ReadSignal<LayoutData> for Layout {
 
     #[track_caller]
     fn with_untracked<U>(&self, f: impl FnOnce(&LayoutData) -> U) -> U {
-        let caller = Location::caller();
-
         match self {
-            Layout::Static(inert) => {
-                with_current_runtime(|rt| inert.with_untracked(rt, f, caller))
-            },
+            Layout::Static(inert) => inert.with_untracked(f),
             Layout::Reactive(signal) => signal.with_untracked(f),
         }
     }
@@ -27,7 +27,9 @@ pub mod widget;
 // #[macro_use]
 extern crate log;
 
-use rsact_render as render;
+// Public: `declare_widget_style!` expands to `$crate::render` paths, which
+// must resolve in the crates declaring their own widget styles.
+pub use rsact_render as render;
 
 pub mod prelude {
     #[cfg(feature = "tiny-icons")]
@@ -43,9 +45,11 @@ pub mod prelude {
         style::{declare_widget_style, theme::Theme},
         ui::{UI, render_once},
         widget::{
-            button::*, checkbox::*, container::*, dynamic::*, edge::*, flex::*,
-            label::*, prelude::*, scrollable::*, select::*, slider::*,
-            space::*,
+            button::*, chart::*, checkbox::*, container::*, dynamic::*,
+            edge::*, flex::*, for_each::*, gauge::*, label::*, meter::*,
+            number_input::*, prelude::*, qr_code::*, radio_group::*,
+            rich_text::*, scrollable::*, segment_display::*, select::*,
+            slider::*, space::*, switch::*, tabs::*, virtual_list::*,
         },
     };
 
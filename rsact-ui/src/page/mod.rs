@@ -16,7 +16,7 @@ use alloc::vec::Vec;
 use dev::{DevHoveredEl, DevTools};
 use log::{debug, info};
 use rsact_reactive::prelude::*;
-use rsact_reactive::scope::ScopeHandle;
+use rsact_reactive::scope::{ScopeHandle, ScopeProfile};
 
 pub mod dev;
 pub mod id;
@@ -211,10 +211,23 @@ impl<W: WidgetCtx> Page<W> {
         self
     }
 
+    /// Focus the root element (see [`crate::test_support::focused_page`]).
+    pub(crate) fn focus_root(&mut self) {
+        self.state.focused = Some((self.root, 0));
+    }
+
     pub fn take_draw_calls(&mut self) -> usize {
         core::mem::replace(&mut self.render_calls, 0)
     }
 
+    /// Live reactive nodes built by the page (its widgets and per-page nodes)
+    /// by kind, with their approximate heap bytes. The arena, created outside
+    /// the page scope, and the element render probes, created on render, are
+    /// not counted.
+    pub fn profile(&self) -> ScopeProfile {
+        self.scope.profile()
+    }
+
     // TODO
     // pub fn style(
     //     mut self,
@@ -436,6 +449,21 @@ impl<W: WidgetCtx> Page<W> {
     fn send_event(
         &mut self,
         event: Event<W::CustomEvent>,
+    ) -> Option<UnhandledEvent<W>> {
+        // The overlay is drawn above the tree, over parts that don't know
+        // about it, so opening or closing one redraws the whole page.
+        let overlay = self.state.overlay;
+        let unhandled = self.route_event(event);
+        if self.state.overlay != overlay {
+            self.force_redraw();
+        }
+        unhandled
+    }
+
+    #[must_use]
+    fn route_event(
+        &mut self,
+        event: Event<W::CustomEvent>,
     ) -> Option<UnhandledEvent<W>> {
         // WS3.3: drop any focus/pointer references to elements that have left
         // the arena since the last event (a `Dynamic` rebuild or list update
@@ -475,6 +503,26 @@ impl<W: WidgetCtx> Page<W> {
             return None;
         }
 
+        // === Overlay ===
+        // An open overlay (a drop-down list, a context menu) is modal: its
+        // owner receives every event exclusively until it closes the overlay,
+        // so a click outside of it dismisses it instead of reaching the widget
+        // under the cursor. The owner redraws its overlay on any event it
+        // handles, which again needs the whole page under it redrawn.
+        if let Some(owner) = self.state.overlay {
+            if let Event::Mouse(MouseEvent::MouseMove(point)) = &event {
+                self.state.pointer.pos = Some(*point);
+            }
+
+            return match self.send_event_to(owner, &event) {
+                EventResponse::Continue(()) => self.on_unhandled_event(event),
+                EventResponse::Break(_) => {
+                    self.force_redraw();
+                    None
+                },
+            };
+        }
+
         if let Event::Mouse(MouseEvent::MouseMove(point)) = event {
             if self.dev_tools.with(|dt| dt.enabled) {
                 let hovered_el = self.find_el_under_cursor(point);
@@ -623,6 +671,10 @@ impl<W: WidgetCtx> Page<W> {
         // Copy the probe handle out (it is `Copy`) so the poll closure can
         // borrow `self` mutably without aliasing `self.render_probe`.
         let render_probe = self.render_probe;
+        // Widgets may write signals from what they learn in the layout while
+        // rendering (e.g. `VirtualList` its viewport), their effects must wait
+        // until the arena and the layout are not borrowed anymore.
+        let defer_effects = defer_effects();
         let drawn = render_probe.poll(needs_redraw, || {
             info!(
                 "Render page {:?} (call: {})",
@@ -674,7 +726,16 @@ impl<W: WidgetCtx> Page<W> {
                     with!(|layout, stylist| {
                         debug!("Force redraw: {}", self.force_redraw.get());
                         self.arena.update_untracked(|arena| {
-                            RenderPass::new(
+                            let visual = RenderVisual {
+                                tree_style: TreeStyle::base(),
+                                font_props: FontProps {
+                                    font: Some(Font::Auto),
+                                    font_size: None,
+                                    font_style: None,
+                                },
+                            };
+                            let tree_root = layout.tree_root();
+                            let mut pass = RenderPass::new(
                                 arena,
                                 renderer,
                                 RenderShared {
@@ -685,20 +746,21 @@ impl<W: WidgetCtx> Page<W> {
                                     stylist,
                                     force_redraw: self.force_redraw,
                                 },
-                            )
-                            .render(
+                            );
+
+                            pass.render(
                                 self.root,
-                                &layout.tree_root(),
-                                RenderVisual {
-                                    tree_style: TreeStyle::base(),
-                                    font_props: FontProps {
-                                        font: Some(Font::Auto),
-                                        font_size: None,
-                                        font_style: None,
-                                    },
-                                },
+                                &tree_root,
+                                visual,
                                 RenderFrame::root(self.render_calls),
-                            )
+                            )?;
+
+                            match self.state.overlay {
+                                Some(owner) => pass.render_overlay(
+                                    owner, self.root, &tree_root, visual,
+                                ),
+                                None => Ok(()),
+                            }
                         })
                     })?;
 
@@ -721,6 +783,8 @@ impl<W: WidgetCtx> Page<W> {
                 });
         });
 
+        defer_effects.run();
+
         //
         self.force_redraw.set_untracked(false);
         self.needs_redraw = false;
@@ -974,6 +1038,32 @@ mod tests {
         });
     }
 
+    /// The page profile counts what the page built: every node the build adds
+    /// except the arena.
+    #[test]
+    fn page_profile_counts_built_nodes() {
+        use crate::widget::{checkbox::Checkbox, flex::Flex, label::Label};
+        use rsact_reactive::runtime::{
+            current_runtime_profile, with_new_runtime,
+        };
+
+        with_new_runtime(|_| {
+            let text = create_signal(String::from("x"));
+            let baseline = current_runtime_profile().total();
+
+            let page = create_null_page(move || {
+                Flex::col((Label::new(text), Checkbox::new(false))).into_el()
+            });
+
+            let built = current_runtime_profile().total() - baseline;
+            let profile = page.profile();
+            assert_eq!(profile.total(), built - 1, "all but the arena");
+            assert_eq!(profile.probes, 1, "the page render probe");
+            assert!(profile.signals > 0 && profile.memos > 0);
+            assert!(profile.heap_bytes > 0);
+        });
+    }
+
     /// Subtree disposal (WS3.2): rebuilding a `Dynamic` child must not leak the
     /// old subtree's *reactive* nodes (the WS2 `arena_rebuild_does_not_leak_subtree`
     /// test covers only arena `ElData`; this covers signals/memos/layouts). The
@@ -1491,6 +1581,38 @@ mod tests {
         });
     }
 
+    /// A checkbox bound to a lens writes its parent in place, so the parent
+    /// is up to date right after the press, even inside a batch.
+    #[test]
+    fn checkbox_binds_a_lens() {
+        use crate::event::{Event, PressEvent};
+
+        #[derive(Clone, Default, PartialEq)]
+        struct Settings {
+            wifi: bool,
+        }
+
+        with_new_runtime(|_| {
+            let settings = create_signal(Settings::default());
+            let mut page = create_null_page(Checkbox::new(
+                rsact_reactive::lens!(settings => wifi),
+            ));
+            page.use_renderer(|_| {});
+
+            page.state.focused = Some((page.root, 0));
+            batch(|| {
+                let _ = page.handle_events(
+                    [
+                        Event::Press(PressEvent::Press),
+                        Event::Press(PressEvent::Release),
+                    ]
+                    .into_iter(),
+                );
+                assert!(settings.with(|settings| settings.wifi));
+            });
+        });
+    }
+
     // A renderer that records primitive draw calls. NullRenderer is a no-op and
     // cannot reveal whether a primitive (e.g. the check-icon path) was actually
     // drawn.
@@ -1502,6 +1624,11 @@ mod tests {
         #[derive(Clone, Default)]
         pub struct RecordingRenderer {
             pub paths: Rc<Cell<usize>>,
+            pub lines: Rc<Cell<usize>>,
+            pub fills: Rc<Cell<usize>>,
+            pub pixels: Rc<Cell<usize>>,
+            /// Rects with a fill, e.g. the background of a `Block`.
+            pub filled_rects: Rc<Cell<usize>>,
         }
 
         impl RenderTarget for RecordingRenderer {
@@ -1543,6 +1670,7 @@ mod tests {
                 _rect: Rect,
                 _color: Self::Color,
             ) -> RenderResult {
+                self.fills.set(self.fills.get() + 1);
                 Ok(())
             }
             fn pixel(
@@ -1550,6 +1678,7 @@ mod tests {
                 _point: Point,
                 _color: Self::Color,
             ) -> RenderResult {
+                self.pixels.set(self.pixels.get() + 1);
                 Ok(())
             }
             fn line(
@@ -1558,6 +1687,7 @@ mod tests {
                 _to: Point,
                 _style: &DrawStyle<Self::Color>,
             ) -> RenderResult {
+                self.lines.set(self.lines.get() + 1);
                 Ok(())
             }
             fn rect(
@@ -1571,8 +1701,11 @@ mod tests {
                 &mut self,
                 _rect: Rect,
                 _corners: CornerRadii,
-                _style: &DrawStyle<Self::Color>,
+                style: &DrawStyle<Self::Color>,
             ) -> RenderResult {
+                if style.fill.is_some() {
+                    self.filled_rects.set(self.filled_rects.get() + 1);
+                }
                 Ok(())
             }
             fn circle(
@@ -1689,6 +1822,214 @@ mod tests {
         });
     }
 
+    // Appending a sample draws its segment only, a range change repaints the
+    // whole chart.
+    #[test]
+    fn chart_draws_only_appended_samples() {
+        use crate::widget::chart::{Chart, ChartMode, Samples};
+        use recording_renderer::RecordingRenderer;
+
+        type RecWtf = Wtf<RecordingRenderer, (), (), ()>;
+
+        with_new_runtime(|_| {
+            let renderer = RecordingRenderer::default();
+            let (lines, fills) =
+                (renderer.lines.clone(), renderer.fills.clone());
+            let mut samples = create_signal(Samples::new(4));
+            samples.update(|samples| {
+                for value in [1.0, 2.0, 3.0] {
+                    samples.push(value);
+                }
+            });
+
+            let arena = create_signal(ElArena::new());
+            let scope = new_scope();
+            let mut page: Page<RecWtf> = Page::new(
+                (),
+                Chart::<RecWtf>::new(samples)
+                    .mode(ChartMode::Sweep)
+                    .y_range(0.0, 10.0),
+                arena,
+                Size::new_equal(64).maybe_reactive(),
+                ().inert(),
+                DevTools::default().signal(),
+                renderer.signal(),
+                FontCtx::new().signal(),
+                scope,
+            );
+            let mut frame = || {
+                lines.set(0);
+                fills.set(0);
+                page.use_renderer(|_| {});
+                (lines.get(), fills.get())
+            };
+
+            let (drawn, _) = frame();
+            assert_eq!(drawn, 3, "a point and two segments");
+            assert_eq!(frame(), (0, 0), "nothing changed");
+
+            // The 4th and the 5th sample, wrapping to the first column, both
+            // clear their own columns only.
+            samples.update(|samples| samples.push(4.0));
+            assert_eq!(frame(), (1, 1));
+            samples.update(|samples| samples.push(5.0));
+            assert_eq!(frame(), (1, 1));
+        });
+    }
+
+    #[test]
+    fn qr_code_fills_page_and_draws_dark_runs() {
+        use crate::widget::qr_code::QrCode;
+        use recording_renderer::RecordingRenderer;
+
+        type RecWtf = Wtf<RecordingRenderer, (), (), ()>;
+
+        with_new_runtime(|_| {
+            let renderer = RecordingRenderer::default();
+            let fills = renderer.fills.clone();
+            let mut content = create_signal(String::from("1"));
+
+            let arena = create_signal(ElArena::new());
+            let scope = new_scope();
+            let mut page: Page<RecWtf> = Page::new(
+                (),
+                QrCode::<RecWtf>::new(content),
+                arena,
+                Size::new_equal(64).maybe_reactive(),
+                ().inert(),
+                DevTools::default().signal(),
+                renderer.signal(),
+                FontCtx::new().signal(),
+                scope,
+            );
+            let mut frame = || {
+                fills.set(0);
+                page.use_renderer(|_| {});
+                fills.get()
+            };
+
+            // The clear, the quiet zone, then at least a run per row.
+            let small = frame();
+            assert!(small > 1 + 1 + 21);
+            assert_eq!(frame(), 0, "nothing changed");
+
+            content.set("x".repeat(100));
+            assert!(frame() > small);
+            content.set("x".repeat(100));
+            assert_eq!(frame(), 0, "same string");
+
+            // Too long for any version.
+            content.set("x".repeat(3000));
+            assert_eq!(frame(), 1, "only the clear");
+        });
+    }
+
+    #[test]
+    fn rich_text_wraps_spans_as_one_paragraph() {
+        use crate::widget::rich_text::{RichText, TextSpan};
+        use recording_renderer::RecordingRenderer;
+
+        type RecWtf = Wtf<RecordingRenderer, (), (), ()>;
+
+        with_new_runtime(|_| {
+            let renderer = RecordingRenderer::default();
+            let pixels = renderer.pixels.clone();
+            // "cd" + "ef" is one word of two spans.
+            let mut spans = create_signal(vec![
+                TextSpan::new("ab "),
+                TextSpan::new("cd").font_style(FontStyle::Bold),
+                TextSpan::new("ef gh"),
+            ]);
+
+            let arena = create_signal(ElArena::new());
+            let scope = new_scope();
+            let mut page: Page<RecWtf> = Page::new(
+                (),
+                RichText::<RecWtf>::new(spans),
+                arena,
+                Size::new_equal(64).maybe_reactive(),
+                ().inert(),
+                DevTools::default().signal(),
+                renderer.signal(),
+                FontCtx::new().signal(),
+                scope,
+            );
+            let size = |page: &Page<RecWtf>| {
+                page.layout.with(|layout| layout.tree_root().outer.size)
+            };
+            let frame = |page: &mut Page<RecWtf>| {
+                pixels.set(0);
+                page.use_renderer(|_| {});
+                pixels.get()
+            };
+
+            // The default 8x13 font fits 8 columns: "ab cdef" and "gh".
+            assert_eq!(size(&page), Size::new(64, 26));
+            let two_lines = frame(&mut page);
+            assert!(two_lines > 0);
+
+            spans.set(vec![TextSpan::new("ab")]);
+            assert_eq!(size(&page), Size::new(16, 13));
+            let one_word = frame(&mut page);
+            assert!(0 < one_word && one_word < two_lines);
+        });
+    }
+
+    #[test]
+    fn segment_display_lights_segments_of_right_aligned_value() {
+        use crate::widget::segment_display::SegmentDisplay;
+        use recording_renderer::RecordingRenderer;
+
+        type RecWtf = Wtf<RecordingRenderer, (), (), ()>;
+
+        with_new_runtime(|_| {
+            let renderer = RecordingRenderer::default();
+            let fills = renderer.fills.clone();
+            let mut value = create_signal("1.5");
+            let mut ghosts = create_signal(false);
+
+            let arena = create_signal(ElArena::new());
+            let scope = new_scope();
+            let mut page: Page<RecWtf> = Page::new(
+                (),
+                SegmentDisplay::<RecWtf, _>::seven(value).digits(3).style(
+                    move |base, _| {
+                        let base = base.on_color(NullColor);
+                        if ghosts.get() {
+                            base.off_color(NullColor)
+                        } else {
+                            base
+                        }
+                    },
+                ),
+                arena,
+                Size::new_equal(64).maybe_reactive(),
+                ().inert(),
+                DevTools::default().signal(),
+                renderer.signal(),
+                FontCtx::new().signal(),
+                scope,
+            );
+            let mut frame = || {
+                fills.set(0);
+                page.use_renderer(|_| {});
+                fills.get()
+            };
+
+            // The clear, a blank cell, then `1.` (B, C, point) and `5` (A, C,
+            // D, F and the middle bar in two halves).
+            assert_eq!(frame(), 1 + 9);
+            assert_eq!(frame(), 0, "nothing changed");
+
+            value.set("-");
+            assert_eq!(frame(), 1 + 2, "the middle bar of the last cell");
+
+            // Every segment of every cell, lit or not.
+            ghosts.set(true);
+            assert_eq!(frame(), 1 + 3 * 9);
+        });
+    }
+
     // Same as above but the checkbox is NESTED (inside `dynamic`, like
     // widget_gallery). Reproduces the real bug: the page re-renders on toggle,
     // but the nested checkbox's render observer is skipped so the icon is never
@@ -1761,6 +2102,182 @@ mod tests {
         });
     }
 
+    /// Focusable widgets of the tree, in tree order.
+    fn focusables<W: WidgetCtx>(arena: &ElArena<W>, id: ElId) -> Vec<ElId> {
+        let mut found = Vec::new();
+        if arena
+            .expect(id)
+            .is_some_and(|d| d.state.flags.is_focusable())
+        {
+            found.push(id);
+        }
+        for &child in arena.children(id).unwrap_or(&[]) {
+            found.extend(focusables(arena, child));
+        }
+        found
+    }
+
+    fn click_focused<W: WidgetCtx>(page: &mut Page<W>, id: ElId) {
+        use crate::event::{Event, PressEvent};
+
+        page.state.focused = Some((id, 0));
+        let _ = page.handle_events(
+            [
+                Event::Press(PressEvent::Press),
+                Event::Press(PressEvent::Release),
+            ]
+            .into_iter(),
+        );
+    }
+
+    #[test]
+    fn radio_group_selects_clicked_option_only() {
+        use crate::widget::{radio_group::RadioGroup, select::SelectOption};
+
+        with_new_runtime(|_| {
+            let mode = create_signal("auto");
+            let arena = create_signal(ElArena::new());
+            let scope = new_scope();
+            let mut page: Page<NullWtf> = Page::new(
+                (),
+                RadioGroup::<NullWtf>::vertical(
+                    mode,
+                    ["auto", "manual", "off"].map(SelectOption::new),
+                ),
+                arena,
+                Size::new_equal(64).maybe_reactive(),
+                ().inert(),
+                DevTools::default().signal(),
+                NullRenderer::default().signal(),
+                FontCtx::new().signal(),
+                scope,
+            );
+            page.use_renderer(|_| {});
+
+            let options = page.arena.with(|arena| focusables(arena, page.root));
+            assert_eq!(options.len(), 3, "each option is focusable");
+
+            click_focused(&mut page, options[2]);
+            assert_eq!(mode.get(), "off");
+            click_focused(&mut page, options[1]);
+            assert_eq!(mode.get(), "manual");
+            // Choosing the chosen option again keeps it.
+            click_focused(&mut page, options[1]);
+            assert_eq!(mode.get(), "manual");
+        });
+    }
+
+    /// Options are drawn by the group's style function, with their own state.
+    #[test]
+    fn radio_options_follow_group_style_and_state() {
+        use crate::widget::{radio_group::RadioGroup, select::SelectOption};
+        use recording_renderer::RecordingRenderer;
+
+        type RecWtf = Wtf<RecordingRenderer, (), (), ()>;
+
+        with_new_runtime(|_| {
+            let renderer = RecordingRenderer::default();
+            let filled_rects = renderer.filled_rects.clone();
+            let mode = create_signal("auto");
+
+            let arena = create_signal(ElArena::new());
+            let scope = new_scope();
+            let mut page: Page<RecWtf> = Page::new(
+                (),
+                RadioGroup::<RecWtf>::vertical(
+                    mode,
+                    ["auto", "manual", "off"].map(SelectOption::new),
+                )
+                .style(|base, selector| {
+                    if selector.pseudoclass.focused {
+                        base.option_background_color(NullColor)
+                    } else {
+                        base
+                    }
+                }),
+                arena,
+                Size::new_equal(64).maybe_reactive(),
+                ().inert(),
+                DevTools::default().signal(),
+                renderer.signal(),
+                FontCtx::new().signal(),
+                scope,
+            );
+            let frame = |page: &mut Page<RecWtf>| {
+                filled_rects.set(0);
+                page.use_renderer(|_| {});
+                filled_rects.get()
+            };
+
+            assert_eq!(frame(&mut page), 0, "no option focused");
+
+            let options = page.arena.with(|arena| focusables(arena, page.root));
+            page.state.focused = Some((options[1], 0));
+            page.force_redraw.set(true);
+            assert_eq!(frame(&mut page), 1, "only the focused option");
+        });
+    }
+
+    #[test]
+    fn animated_switch_redraws_until_the_slide_ends() {
+        use crate::{event::message::UiQueue, widget::switch::Switch};
+        use recording_renderer::RecordingRenderer;
+
+        type RecWtf = Wtf<RecordingRenderer, (), (), ()>;
+
+        with_new_runtime(|_| {
+            let renderer = RecordingRenderer::default();
+            let fills = renderer.fills.clone();
+            let mut queue = UiQueue::<RecWtf>::new();
+            let enabled = create_signal(false);
+
+            let arena = create_signal(ElArena::new());
+            let scope = new_scope();
+            let mut page: Page<RecWtf> = Page::new(
+                (),
+                Switch::<RecWtf>::new(enabled).animated(queue),
+                arena,
+                Size::new_equal(64).maybe_reactive(),
+                ().inert(),
+                DevTools::default().signal(),
+                renderer.signal(),
+                FontCtx::new().signal(),
+                scope,
+            );
+            let redrawn = |page: &mut Page<RecWtf>| {
+                fills.set(0);
+                page.use_renderer(|_| {});
+                fills.get() > 0
+            };
+
+            assert!(redrawn(&mut page));
+            queue.tick(10);
+            assert!(!redrawn(&mut page), "the switch is at rest");
+
+            let switch =
+                page.arena.with(|arena| focusables(arena, page.root))[0];
+            click_focused(&mut page, switch);
+            assert!(enabled.get());
+            assert!(redrawn(&mut page));
+
+            // Frames every 20ms, for twice as long as the slide lasts.
+            let frames = (1..=16)
+                .map(|frame| {
+                    queue.tick(10 + frame * 20);
+                    redrawn(&mut page)
+                })
+                .collect::<Vec<_>>();
+            assert!(
+                frames.iter().filter(|&&redrawn| redrawn).count() > 3,
+                "the thumb slides over several frames: {frames:?}"
+            );
+            assert!(
+                frames[12..].iter().all(|&redrawn| !redrawn),
+                "the slide is over: {frames:?}"
+            );
+        });
+    }
+
     // The `View` migration: `row!`/`col!` and `impl View<W>` APIs accept bare
     // widgets *and* leaf values (`&str`, `String`, `Option<View>`, existing
     // `El`) uniformly, without an explicit `.el()`. A bare `Button` in `row!`
@@ -2135,6 +2652,306 @@ mod tests {
         ));
     }
 
+    /// `Tabs` switch by the wheel over the strip, by a click on a tab title and,
+    /// once activated, by encoder rotation.
+    #[test]
+    fn tabs_switch_by_wheel_click_and_rotation() {
+        use crate::{
+            event::{
+                Event, MouseButton, MouseEvent, MouseWheelDir, MoveDir,
+                PressEvent,
+            },
+            widget::{
+                combinators::Unit,
+                edge::Edge,
+                tabs::{Tab, Tabs},
+            },
+        };
+
+        with_new_runtime(|_| {
+            let tabs = (0..3).fold(Tabs::<NullWtf>::new(0), |tabs, _| {
+                tabs.tab(Tab::custom(
+                    Edge::new().width(10u32).height(5u32),
+                    || Unit.el(),
+                ))
+            });
+            let active = tabs.active();
+
+            let arena = create_signal(ElArena::new());
+            let scope = new_scope();
+            let mut page = Page::<NullWtf>::new(
+                (),
+                tabs,
+                arena,
+                Size::new_equal(64).maybe_reactive(),
+                ().inert(),
+                DevTools::default().signal(),
+                NullRenderer::default().signal(),
+                FontCtx::new().signal(),
+                scope,
+            );
+
+            let title = |tab: usize| {
+                page.layout.with(|layout| {
+                    let root = layout.tree_root();
+                    let strip = root.children().next().unwrap();
+                    strip.children().nth(tab).unwrap().outer.center()
+                })
+            };
+            let second = title(1);
+            let first = title(0);
+
+            let _ = page.handle_events(
+                [
+                    Event::Mouse(MouseEvent::MouseMove(second)),
+                    Event::Mouse(MouseEvent::Wheel(
+                        Point::new(0, 2),
+                        MouseWheelDir::Normal,
+                    )),
+                ]
+                .into_iter(),
+            );
+            assert_eq!(active.get(), 2, "wheel over the strip");
+
+            let _ = page.handle_events(
+                [
+                    Event::Mouse(MouseEvent::ButtonDown(
+                        MouseButton::Left,
+                        Some(first),
+                    )),
+                    Event::Mouse(MouseEvent::ButtonUp(
+                        MouseButton::Left,
+                        Some(first),
+                    )),
+                ]
+                .into_iter(),
+            );
+            assert_eq!(active.get(), 0, "click on the first title");
+
+            page.state.focused = Some((page.root, 0));
+            let _ = page.handle_events(
+                [
+                    Event::move_1(MoveDir::Right),
+                    Event::Press(PressEvent::Press),
+                    Event::Press(PressEvent::Release),
+                    Event::move_1(MoveDir::Right),
+                ]
+                .into_iter(),
+            );
+            assert_eq!(active.get(), 1, "rotation once activated");
+        });
+    }
+
+    /// A list filling the page learns its height from the layout it renders
+    /// in, without waiting for an event.
+    #[test]
+    fn virtual_list_fills_height_from_layout() {
+        use crate::widget::{combinators::Unit, virtual_list::VirtualList};
+
+        with_new_runtime(|_| {
+            let list =
+                VirtualList::<NullWtf>::fixed(100, 10, |_| Unit.el()).margin(1);
+
+            let arena = create_signal(ElArena::new());
+            let scope = new_scope();
+            let mut page = Page::<NullWtf>::new(
+                (),
+                list,
+                arena,
+                Size::new_equal(64).maybe_reactive(),
+                ().inert(),
+                DevTools::default().signal(),
+                NullRenderer::default().signal(),
+                FontCtx::new().signal(),
+                scope,
+            );
+            let root = page.root;
+            let rows =
+                || arena.with(|arena| arena.children(root).unwrap().len());
+            assert_eq!(rows(), 2, "one row and the margin before a layout");
+
+            assert!(page.use_renderer(|_| {}));
+            assert_eq!(rows(), 8, "rows 0..7 in 64px and 1 margin row");
+
+            // The new window is laid out and drawn, then the list is settled.
+            assert!(page.use_renderer(|_| {}));
+            assert!(!page.use_renderer(|_| {}));
+            assert_eq!(rows(), 8);
+        });
+    }
+
+    /// A measured `VirtualList` learns its row heights from the layout, and
+    /// encoder rotation moves its focused row, scrolling it into view.
+    #[test]
+    fn virtual_list_scrolls_to_focused_row() {
+        use crate::{
+            event::{Event, MoveDir, PressEvent},
+            widget::{edge::Edge, virtual_list::VirtualList},
+        };
+
+        with_new_runtime(|_| {
+            let list = VirtualList::<NullWtf>::measured(100, 10, |row| {
+                Edge::new().height(if row % 2 == 0 { 20u32 } else { 10 })
+            })
+            .height(30u32)
+            .margin(0);
+            let focused = list.focused_row();
+            let state = list.scroll_state();
+
+            let arena = create_signal(ElArena::new());
+            let scope = new_scope();
+            let mut page = Page::<NullWtf>::new(
+                (),
+                list,
+                arena,
+                Size::new_equal(64).maybe_reactive(),
+                ().inert(),
+                DevTools::default().signal(),
+                NullRenderer::default().signal(),
+                FontCtx::new().signal(),
+                scope,
+            );
+            let root = page.root;
+            let rows =
+                || arena.with(|arena| arena.children(root).unwrap().len());
+            assert_eq!(rows(), 3, "rows 0..3 by the estimate");
+
+            page.state.focused = Some((page.root, 0));
+            let _ = page.handle_events(
+                [
+                    Event::Press(PressEvent::Press),
+                    Event::Press(PressEvent::Release),
+                ]
+                .into_iter(),
+            );
+            assert_eq!(rows(), 2, "rows 0..2 by their measured heights");
+
+            let _ = page
+                .handle_events((0..3).map(|_| Event::move_1(MoveDir::Down)));
+            assert_eq!(focused.get(), Some(2));
+            assert_eq!(state.with(|state| state.offset), 20);
+
+            // Rows 1..3, the first one at the list top.
+            let tops = page.layout.with(|layout| {
+                layout
+                    .tree_root()
+                    .children()
+                    .map(|row| row.outer.top_left.y)
+                    .collect::<Vec<_>>()
+            });
+            assert_eq!(tops, [0, 10]);
+        });
+    }
+
+    #[test]
+    fn number_input_steps_clamps_reverts_and_accelerates() {
+        use crate::{
+            event::{Event, MoveDir, PressEvent},
+            widget::number_input::NumberInput,
+        };
+        use rsact_reactive::timer::tick_timers;
+
+        with_new_runtime(|_| {
+            let value = create_signal(10i32);
+            let arena = create_signal(ElArena::new());
+            let scope = new_scope();
+            let mut page = Page::<NullWtf>::new(
+                (),
+                NumberInput::new(value).min(0).max(1000).step(5).unit("mm"),
+                arena,
+                Size::new_equal(64).maybe_reactive(),
+                ().inert(),
+                DevTools::default().signal(),
+                NullRenderer::default().signal(),
+                FontCtx::new().signal(),
+                scope,
+            );
+            page.state.focused = Some((page.root, 0));
+            let press = || {
+                [
+                    Event::Press(PressEvent::Press),
+                    Event::Press(PressEvent::Release),
+                ]
+                .into_iter()
+            };
+
+            // Not editing: rotation moves focus, not the value.
+            let _ = page.handle_events([Event::hor_move(1)].into_iter());
+            assert_eq!(value.get(), 10);
+
+            let _ = page.handle_events(press());
+            let _ = page.handle_events([Event::hor_move(1)].into_iter());
+            assert_eq!(value.get(), 15);
+            let _ = page
+                .handle_events((0..4).map(|_| Event::move_1(MoveDir::Left)));
+            assert_eq!(value.get(), 0, "clamped to min");
+
+            // Press commits, a new edit reverts to it on exit.
+            let _ = page.handle_events(press());
+            let _ = page.handle_events(press());
+            let _ = page.handle_events([Event::hor_move(3)].into_iter());
+            assert_eq!(value.get(), 15);
+            let _ = page.handle_events([Event::Exit].into_iter());
+            assert_eq!(value.get(), 0);
+
+            // Fast turns: 1 slow + 5 fast steps of 5, then steps of 50.
+            let _ = page.handle_events(press());
+            for turn in 0..7 {
+                tick_timers(1000 + turn * 10);
+                let _ = page.handle_events([Event::hor_move(1)].into_iter());
+            }
+            assert_eq!(value.get(), 5 * 5 + 50 + 50);
+
+            // A pause resets acceleration.
+            tick_timers(5000);
+            let _ = page.handle_events([Event::hor_move(1)].into_iter());
+            assert_eq!(value.get(), 130);
+        });
+    }
+
+    /// An `f32` steps by ones from any value, not only within `0.0..=1.0`.
+    #[test]
+    fn number_input_steps_f32() {
+        use crate::{
+            event::{Event, PressEvent},
+            widget::number_input::NumberInput,
+        };
+
+        with_new_runtime(|_| {
+            let value = create_signal(215.0f32);
+            let arena = create_signal(ElArena::new());
+            let scope = new_scope();
+            let mut page = Page::<NullWtf>::new(
+                (),
+                NumberInput::new(value)
+                    .min(0.0)
+                    .max(300.0)
+                    .step(5)
+                    .unit("°C"),
+                arena,
+                Size::new_equal(64).maybe_reactive(),
+                ().inert(),
+                DevTools::default().signal(),
+                NullRenderer::default().signal(),
+                FontCtx::new().signal(),
+                scope,
+            );
+            page.state.focused = Some((page.root, 0));
+            let _ = page.handle_events(
+                [
+                    Event::Press(PressEvent::Press),
+                    Event::Press(PressEvent::Release),
+                ]
+                .into_iter(),
+            );
+
+            let _ = page.handle_events([Event::hor_move(1)].into_iter());
+            assert_eq!(value.get(), 220.0);
+            let _ = page.handle_events([Event::hor_move(-3)].into_iter());
+            assert_eq!(value.get(), 205.0);
+        });
+    }
+
     // WS13.4 (Task 5.11): `Canvas` is split, but like `Label`/`Space`/
     // `Edge`/`Bar`/`Checkbox`/`Slider`/`Knob` it has no build-only field to
     // drop — `draw`/`layout` are both read by `render`/`layout`, so
@@ -2160,20 +2977,12 @@ mod tests {
         page.use_renderer(|_| {});
     }
 
-    // WS13.4 (Task 5.13): `Select` is split, but like `Label`/`Space`/`Edge`/
-    // `Bar`/`Checkbox`/`Slider`/`Knob`/`Canvas` it has no build-only field to
-    // drop — `layout`/`state`/`style`/`options` are all read by
-    // `render`/`on_event`, so `SelectBuilder` moves all four fields into the
-    // retained `Select` unchanged (a `size_of` `<` assertion would be false,
-    // not true). `Dir: Direction` was de-genericized like Bar/Slider/
-    // Scrollable into a runtime `axis: Axis` field (`render` reads
-    // `Dir::AXIS` for the selected-option highlight box, not just `new()`'s
-    // size computation); `K: PartialEq` stays generic (no canonical key
-    // type, same call as Bar's undecided `V`). This is a mechanical
-    // rename-only split — the `options: Rc<MaybeReactive<Vec<SelectOption<W,
-    // K>>>>` storage and the `selected.setter(...)` reactive-effect wiring
-    // (both backlogged as awkward under A18) are untouched — see
-    // select.rs's WS13.4 comment.
+    // WS13.4 (Task 5.13): `Select` is split. Its options are real arena
+    // children (one highlight cell per option, highlighted through a
+    // `Selector`), so the builder carries them as `#[children(reactive)]`
+    // while the retained `Select` keeps only the option keys. `K: PartialEq`
+    // stays generic (no canonical key type, same call as Bar's undecided `V`).
+    // See select.rs's WS13.4 comment.
     #[test]
     fn select_split_builder_exists_and_page_builds() {
         use crate::widget::select::{Select, SelectBuilder};
@@ -2200,6 +3009,76 @@ mod tests {
         ));
     }
 
+    /// The selected highlight is drawn with the `Select`'s own style fn and
+    /// state, here a background only while the select is active.
+    #[test]
+    fn select_highlight_follows_select_style_and_state() {
+        use crate::{
+            event::{Event, PressEvent},
+            widget::select::Select,
+        };
+        use recording_renderer::RecordingRenderer;
+
+        type RecWtf = Wtf<RecordingRenderer, (), (), ()>;
+
+        with_new_runtime(|_| {
+            let renderer = RecordingRenderer::default();
+            let filled_rects = renderer.filled_rects.clone();
+            let selected = create_signal(2u32);
+
+            let arena = create_signal(ElArena::new());
+            let scope = new_scope();
+            let mut page: Page<RecWtf> = Page::new(
+                (),
+                Select::<RecWtf, u32>::vertical(
+                    selected,
+                    alloc::vec![1u32, 2, 3].inert(),
+                )
+                .style(|base, selector| {
+                    if selector.pseudoclass.active {
+                        base.selected_background_color(NullColor)
+                    } else {
+                        base
+                    }
+                }),
+                arena,
+                Size::new_equal(64).maybe_reactive(),
+                ().inert(),
+                DevTools::default().signal(),
+                renderer.signal(),
+                FontCtx::new().signal(),
+                scope,
+            );
+            let frame = |page: &mut Page<RecWtf>| {
+                filled_rects.set(0);
+                page.use_renderer(|_| {});
+                filled_rects.get()
+            };
+            let press = |page: &mut Page<RecWtf>| {
+                let _ = page.handle_events(
+                    [
+                        Event::Press(PressEvent::Press),
+                        Event::Press(PressEvent::Release),
+                    ]
+                    .into_iter(),
+                );
+            };
+
+            // The highlight of the selected option has no background while
+            // the select is inactive.
+            assert_eq!(frame(&mut page), 0);
+
+            // Activating the select redraws the highlight with the
+            // background of its `active` style.
+            page.state.focused = Some((page.root, 0));
+            press(&mut page);
+            assert_eq!(frame(&mut page), 1);
+
+            press(&mut page);
+            assert_eq!(frame(&mut page), 0);
+        });
+    }
+
     // WS13.4 (Task 5.15): `Icon` is split after its WS4.5 repair (icon.rs's
     // own commit). Like `Label`/`Space`/`Edge`/…, its only build-only field
     // is a ZST (`is_reactive: PhantomData<R>` — the `ReactivityMarker`
@@ -1,5 +1,6 @@
 use crate::render::prelude::*;
-use alloc::boxed::Box;
+use alloc::{boxed::Box, rc::Rc};
+use core::cell::RefCell;
 
 pub mod primary_gray;
 pub mod stylist;
@@ -62,6 +63,11 @@ pub struct StyleSelector {
 // TODO: Should there be StyledWidget with exposed fn style() builder method?
 pub type WidgetStyleFn<S> = Option<Box<dyn Fn(S, &StyleSelector) -> S>>;
 
+/// A widget's [`WidgetStyleFn`] shared with the cells it creates for its parts
+/// (options, tabs), each resolving it with its own state. The widget's `style`
+/// builder method sets it, possibly after the cells are created.
+pub type SharedStyleFn<S> = Rc<RefCell<WidgetStyleFn<S>>>;
+
 pub trait StyleFn<S: Style + 'static>:
     Fn(S, &StyleSelector) -> S + 'static
 {
@@ -3,10 +3,14 @@ use crate::widget::icon::IconStyle;
 use crate::{
     style::{Style, StyleSelector},
     widget::{
-        bar::BarStyle, button::ButtonStyle, checkbox::CheckboxStyle,
-        container::ContainerStyle, edge::EdgeStyle, knob::KnobStyle,
-        label::LabelStyle, scrollable::ScrollableStyle, select::SelectStyle,
-        slider::SliderStyle,
+        bar::BarStyle, button::ButtonStyle, chart::ChartStyle,
+        checkbox::CheckboxStyle, container::ContainerStyle, edge::EdgeStyle,
+        gauge::GaugeStyle, knob::KnobStyle, label::LabelStyle,
+        meter::MeterStyle, number_input::NumberInputStyle,
+        qr_code::QrCodeStyle, radio_group::RadioGroupStyle,
+        rich_text::RichTextStyle, scrollable::ScrollableStyle,
+        segment_display::SegmentDisplayStyle, select::SelectStyle,
+        slider::SliderStyle, switch::SwitchStyle, tabs::TabsStyle,
     },
 };
 use core::marker::PhantomData;
@@ -36,15 +40,25 @@ pub trait Stylist<S: Style> {
 pub trait InternalStylist<C: Color>:
     Stylist<BarStyle<C>>
     + Stylist<ButtonStyle<C>>
+    + Stylist<ChartStyle<C>>
     + Stylist<CheckboxStyle<C>>
     + Stylist<ContainerStyle<C>>
     + Stylist<EdgeStyle<C>>
+    + Stylist<GaugeStyle<C>>
     + Stylist<IconStyle<C>>
     + Stylist<KnobStyle<C>>
     + Stylist<LabelStyle<C>>
+    + Stylist<MeterStyle<C>>
+    + Stylist<NumberInputStyle<C>>
+    + Stylist<QrCodeStyle<C>>
+    + Stylist<RadioGroupStyle<C>>
+    + Stylist<RichTextStyle<C>>
     + Stylist<ScrollableStyle<C>>
+    + Stylist<SegmentDisplayStyle<C>>
     + Stylist<SelectStyle<C>>
     + Stylist<SliderStyle<C>>
+    + Stylist<SwitchStyle<C>>
+    + Stylist<TabsStyle<C>>
 {
 }
 
@@ -52,14 +66,24 @@ pub trait InternalStylist<C: Color>:
 pub trait InternalStylist<C: Color>:
     Stylist<BarStyle<C>>
     + Stylist<ButtonStyle<C>>
+    + Stylist<ChartStyle<C>>
     + Stylist<CheckboxStyle<C>>
     + Stylist<ContainerStyle<C>>
     + Stylist<EdgeStyle<C>>
+    + Stylist<GaugeStyle<C>>
     + Stylist<KnobStyle<C>>
     + Stylist<LabelStyle<C>>
+    + Stylist<MeterStyle<C>>
+    + Stylist<NumberInputStyle<C>>
+    + Stylist<QrCodeStyle<C>>
+    + Stylist<RadioGroupStyle<C>>
+    + Stylist<RichTextStyle<C>>
     + Stylist<ScrollableStyle<C>>
+    + Stylist<SegmentDisplayStyle<C>>
     + Stylist<SelectStyle<C>>
     + Stylist<SliderStyle<C>>
+    + Stylist<SwitchStyle<C>>
+    + Stylist<TabsStyle<C>>
 {
 }
 
@@ -102,14 +126,24 @@ macro_rules! declare_null_stylist {
 declare_null_stylist!(
     BarStyle<NullColor>,
     ButtonStyle<NullColor>,
+    ChartStyle<NullColor>,
     CheckboxStyle<NullColor>,
     ContainerStyle<NullColor>,
     EdgeStyle<NullColor>,
+    GaugeStyle<NullColor>,
     KnobStyle<NullColor>,
     LabelStyle<NullColor>,
+    MeterStyle<NullColor>,
+    NumberInputStyle<NullColor>,
+    QrCodeStyle<NullColor>,
+    RadioGroupStyle<NullColor>,
+    RichTextStyle<NullColor>,
     ScrollableStyle<NullColor>,
+    SegmentDisplayStyle<NullColor>,
     SelectStyle<NullColor>,
     SliderStyle<NullColor>,
+    SwitchStyle<NullColor>,
+    TabsStyle<NullColor>,
 );
 
 // Kept as a separate gated call (rather than folded into the list above) so
@@ -35,14 +35,24 @@ use crate::{
     widget::{
         bar::BarStyle,
         button::ButtonStyle,
+        chart::ChartStyle,
         checkbox::CheckboxStyle,
         container::ContainerStyle,
         edge::EdgeStyle,
+        gauge::GaugeStyle,
         knob::KnobStyle,
         label::LabelStyle,
+        meter::MeterStyle,
+        number_input::NumberInputStyle,
+        qr_code::QrCodeStyle,
+        radio_group::RadioGroupStyle,
+        rich_text::RichTextStyle,
         scrollable::{ScrollableStyle, ScrollbarShow},
+        segment_display::SegmentDisplayStyle,
         select::SelectStyle,
         slider::{SliderStyle, SliderThumbShape},
+        switch::SwitchStyle,
+        tabs::TabsStyle,
     },
 };
 use embedded_graphics::pixelcolor::BinaryColor;
@@ -146,6 +156,21 @@ impl Stylist<ButtonStyle<BinaryColor>> for BinaryTheme {
     }
 }
 
+impl Stylist<ChartStyle<BinaryColor>> for BinaryTheme {
+    fn style(
+        &self,
+        base: &ChartStyle<BinaryColor>,
+        _selector: &StyleSelector,
+    ) -> ChartStyle<BinaryColor> {
+        // Nothing is drawn over the area under the line, so it can be filled.
+        // Gridlines are dotted, which keeps them lighter than the series.
+        base.series_color(self.fg)
+            .fill_color(self.fg)
+            .grid_color(self.fg)
+            .label_color(self.fg)
+    }
+}
+
 impl Stylist<CheckboxStyle<BinaryColor>> for BinaryTheme {
     fn style(
         &self,
@@ -179,6 +204,24 @@ impl Stylist<EdgeStyle<BinaryColor>> for BinaryTheme {
     }
 }
 
+impl Stylist<GaugeStyle<BinaryColor>> for BinaryTheme {
+    fn style(
+        &self,
+        base: &GaugeStyle<BinaryColor>,
+        _selector: &StyleSelector,
+    ) -> GaugeStyle<BinaryColor> {
+        // Zones drawn over an `On` track would vanish, so the track is left
+        // out and only the zones and ticks mark the dial.
+        base.transparent_track_color()
+            .needle_color(self.fg)
+            .tick_color(self.fg)
+            .text_color(self.fg)
+            .good_color(self.fg)
+            .warning_color(self.fg)
+            .critical_color(self.fg)
+    }
+}
+
 impl Stylist<KnobStyle<BinaryColor>> for BinaryTheme {
     fn style(
         &self,
@@ -201,6 +244,78 @@ impl Stylist<LabelStyle<BinaryColor>> for BinaryTheme {
     }
 }
 
+impl Stylist<MeterStyle<BinaryColor>> for BinaryTheme {
+    fn style(
+        &self,
+        base: &MeterStyle<BinaryColor>,
+        _selector: &StyleSelector,
+    ) -> MeterStyle<BinaryColor> {
+        // Same as the bar: the fill alone on the background, whatever the
+        // level. Marks show on the empty part of the track.
+        base.transparent_track_color()
+            .fill_color(self.fg)
+            .mark_color(self.fg)
+            .good_color(self.fg)
+            .warning_color(self.fg)
+            .critical_color(self.fg)
+    }
+}
+
+impl Stylist<NumberInputStyle<BinaryColor>> for BinaryTheme {
+    fn style(
+        &self,
+        base: &NumberInputStyle<BinaryColor>,
+        selector: &StyleSelector,
+    ) -> NumberInputStyle<BinaryColor> {
+        // Editing keeps the bolder outline for as long as it lasts, the value
+        // itself stays on an `Off` background.
+        base.container(self.interactive_container(selector))
+            .editing(
+                self.mono_container().outline(self.mono_outline().width(2)),
+            )
+    }
+}
+
+impl Stylist<QrCodeStyle<BinaryColor>> for BinaryTheme {
+    fn style(
+        &self,
+        base: &QrCodeStyle<BinaryColor>,
+        _selector: &StyleSelector,
+    ) -> QrCodeStyle<BinaryColor> {
+        // Lit `fg` pixels read as light, so the code is drawn inverted
+        // relative to text: dark modules in `bg` on a lit quiet zone.
+        base.dark_color(self.bg).light_color(self.fg)
+    }
+}
+
+impl Stylist<RadioGroupStyle<BinaryColor>> for BinaryTheme {
+    fn style(
+        &self,
+        base: &RadioGroupStyle<BinaryColor>,
+        selector: &StyleSelector,
+    ) -> RadioGroupStyle<BinaryColor> {
+        // Hover is an outline around the option, the ring and the label stay
+        // on the background.
+        let option = if selector.pseudoclass.hovered {
+            BlockStyle::base().outline(self.mono_outline())
+        } else {
+            BlockStyle::base()
+        };
+
+        base.option(option).ring_color(self.fg).dot_color(self.fg)
+    }
+}
+
+impl Stylist<RichTextStyle<BinaryColor>> for BinaryTheme {
+    fn style(
+        &self,
+        base: &RichTextStyle<BinaryColor>,
+        _selector: &StyleSelector,
+    ) -> RichTextStyle<BinaryColor> {
+        base.text_color(self.fg)
+    }
+}
+
 impl Stylist<ScrollableStyle<BinaryColor>> for BinaryTheme {
     fn style(
         &self,
@@ -216,6 +331,17 @@ impl Stylist<ScrollableStyle<BinaryColor>> for BinaryTheme {
     }
 }
 
+impl Stylist<SegmentDisplayStyle<BinaryColor>> for BinaryTheme {
+    fn style(
+        &self,
+        base: &SegmentDisplayStyle<BinaryColor>,
+        _selector: &StyleSelector,
+    ) -> SegmentDisplayStyle<BinaryColor> {
+        // No second color for ghost segments.
+        base.on_color(self.fg).transparent_off_color()
+    }
+}
+
 impl Stylist<SelectStyle<BinaryColor>> for BinaryTheme {
     fn style(
         &self,
@@ -248,6 +374,41 @@ impl Stylist<SliderStyle<BinaryColor>> for BinaryTheme {
     }
 }
 
+impl Stylist<SwitchStyle<BinaryColor>> for BinaryTheme {
+    fn style(
+        &self,
+        base: &SwitchStyle<BinaryColor>,
+        selector: &StyleSelector,
+    ) -> SwitchStyle<BinaryColor> {
+        // The switch has no text to hide, so on is a filled track with an
+        // `Off` thumb: the state reads at a glance even on tiny displays.
+        let container = self.interactive_container(selector);
+
+        base.container(container)
+            .checked(container.background_color(self.fg))
+            .thumb_color(self.fg)
+            .checked_thumb_color(self.bg)
+    }
+}
+
+impl Stylist<TabsStyle<BinaryColor>> for BinaryTheme {
+    fn style(
+        &self,
+        base: &TabsStyle<BinaryColor>,
+        selector: &StyleSelector,
+    ) -> TabsStyle<BinaryColor> {
+        // Like the selected `Select` option: the active tab gets a border, a
+        // hovered one an outline, neither is filled behind the `fg` title.
+        let tab = if selector.pseudoclass.hovered {
+            BlockStyle::base().outline(self.mono_outline().offset(0))
+        } else {
+            BlockStyle::base()
+        };
+
+        base.tab(tab).active_tab(self.mono_container())
+    }
+}
+
 #[cfg(feature = "tiny-icons")]
 impl Stylist<IconStyle<BinaryColor>> for BinaryTheme {
     fn style(
@@ -384,6 +545,27 @@ mod tests {
         assert_eq!(style.text_color.get(), Some(BinaryColor::On));
     }
 
+    #[test]
+    fn qr_code_is_off_on_lit_background() {
+        let style = Stylist::<QrCodeStyle<_>>::style(
+            &BinaryTheme::default(),
+            &QrCodeStyle::base(),
+            &selector(false, false),
+        );
+        assert_eq!(style.dark_color.get(), Some(BinaryColor::Off));
+        assert_eq!(style.light_color.get(), Some(BinaryColor::On));
+    }
+
+    #[test]
+    fn rich_text_is_on() {
+        let style = Stylist::<RichTextStyle<_>>::style(
+            &BinaryTheme::default(),
+            &RichTextStyle::base(),
+            &selector(false, false),
+        );
+        assert_eq!(style.text_color.get(), Some(BinaryColor::On));
+    }
+
     #[test]
     fn select_marks_selection_with_border_not_fill() {
         let style = Stylist::<SelectStyle<_>>::style(
@@ -399,6 +581,111 @@ mod tests {
         assert_eq!(style.text_color.get(), Some(BinaryColor::On));
     }
 
+    #[test]
+    fn chart_draws_everything_in_fg() {
+        let style = Stylist::<ChartStyle<_>>::style(
+            &BinaryTheme::default(),
+            &ChartStyle::base(),
+            &selector(false, false),
+        );
+
+        assert_eq!(style.series_color.get(), Some(BinaryColor::On));
+        assert_eq!(style.grid_color.get(), Some(BinaryColor::On));
+        assert_eq!(style.label_color.get(), Some(BinaryColor::On));
+        assert_eq!(style.container.background_color.get(), None);
+    }
+
+    #[test]
+    fn switch_inverts_thumb_on_filled_track() {
+        let style = Stylist::<SwitchStyle<_>>::style(
+            &BinaryTheme::default(),
+            &SwitchStyle::base(),
+            &selector(false, false),
+        );
+
+        assert_eq!(
+            style.container.background_color.get(),
+            Some(BinaryColor::Off)
+        );
+        assert_eq!(style.thumb_color.get(), Some(BinaryColor::On));
+        assert_eq!(style.checked.background_color.get(), Some(BinaryColor::On));
+        assert_eq!(style.checked_thumb_color.get(), Some(BinaryColor::Off));
+    }
+
+    #[test]
+    fn radio_group_dot_is_on() {
+        let style = Stylist::<RadioGroupStyle<_>>::style(
+            &BinaryTheme::default(),
+            &RadioGroupStyle::base(),
+            &selector(false, false),
+        );
+
+        assert_eq!(style.ring_color.get(), Some(BinaryColor::On));
+        assert_eq!(style.dot_color.get(), Some(BinaryColor::On));
+        assert_eq!(style.option.background_color.get(), None);
+    }
+
+    #[test]
+    fn hmi_widgets_draw_only_in_fg() {
+        let gauge = Stylist::<GaugeStyle<_>>::style(
+            &BinaryTheme::default(),
+            &GaugeStyle::base(),
+            &selector(false, false),
+        );
+        assert_eq!(gauge.track_color.get(), None);
+        assert_eq!(gauge.needle_color.get(), Some(BinaryColor::On));
+        assert_eq!(gauge.critical_color.get(), Some(BinaryColor::On));
+
+        let meter = Stylist::<MeterStyle<_>>::style(
+            &BinaryTheme::default(),
+            &MeterStyle::base(),
+            &selector(false, false),
+        );
+        assert_eq!(meter.track_color.get(), None);
+        assert_eq!(meter.fill_color.get(), Some(BinaryColor::On));
+
+        let segments = Stylist::<SegmentDisplayStyle<_>>::style(
+            &BinaryTheme::default(),
+            &SegmentDisplayStyle::base(),
+            &selector(false, false),
+        );
+        assert_eq!(segments.on_color.get(), Some(BinaryColor::On));
+        assert_eq!(segments.off_color.get(), None);
+    }
+
+    #[test]
+    fn number_input_marks_editing_with_outline_not_fill() {
+        let style = Stylist::<NumberInputStyle<_>>::style(
+            &BinaryTheme::default(),
+            &NumberInputStyle::base(),
+            &selector(false, false),
+        );
+
+        assert_eq!(style.container.outline.color.get(), None);
+        assert_eq!(style.editing.outline.color.get(), Some(BinaryColor::On));
+        assert_eq!(
+            style.editing.background_color.get(),
+            Some(BinaryColor::Off)
+        );
+    }
+
+    #[test]
+    fn tabs_mark_active_tab_with_border_not_fill() {
+        let style = Stylist::<TabsStyle<_>>::style(
+            &BinaryTheme::default(),
+            &TabsStyle::base(),
+            &selector(false, false),
+        );
+
+        assert_eq!(style.active_tab.border.color.get(), Some(BinaryColor::On));
+        assert_eq!(
+            style.active_tab.background_color.get(),
+            Some(BinaryColor::Off)
+        );
+        // Inactive tabs are plain titles.
+        assert_eq!(style.tab.border.color.get(), None);
+    }
+
     #[test]
     fn slider_thumb_is_hollow_over_on_track() {
         let style = Stylist::<SliderStyle<_>>::style(
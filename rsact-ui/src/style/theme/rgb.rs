@@ -7,10 +7,14 @@ use crate::{
         stylist::{InternalStylist, Stylist},
     },
     widget::{
-        bar::BarStyle, button::ButtonStyle, checkbox::CheckboxStyle,
-        container::ContainerStyle, edge::EdgeStyle, knob::KnobStyle,
-        label::LabelStyle, scrollable::ScrollableStyle, select::SelectStyle,
-        slider::SliderStyle,
+        bar::BarStyle, button::ButtonStyle, chart::ChartStyle,
+        checkbox::CheckboxStyle, container::ContainerStyle, edge::EdgeStyle,
+        gauge::GaugeStyle, knob::KnobStyle, label::LabelStyle,
+        meter::MeterStyle, number_input::NumberInputStyle,
+        qr_code::QrCodeStyle, radio_group::RadioGroupStyle,
+        rich_text::RichTextStyle, scrollable::ScrollableStyle,
+        segment_display::SegmentDisplayStyle, select::SelectStyle,
+        slider::SliderStyle, switch::SwitchStyle, tabs::TabsStyle,
     },
 };
 use rsact_render::{
@@ -86,6 +90,19 @@ impl<C: RgbColor> Stylist<ButtonStyle<C>> for Theme<C> {
     }
 }
 
+impl<C: RgbColor> Stylist<ChartStyle<C>> for Theme<C> {
+    fn style(
+        &self,
+        base: &ChartStyle<C>,
+        _selector: &StyleSelector,
+    ) -> ChartStyle<C> {
+        base.series_color(self.primary)
+            .fill_color(self.bg_muted)
+            .grid_color(self.bg_muted)
+            .label_color(self.fg)
+    }
+}
+
 impl<C: RgbColor> Stylist<CheckboxStyle<C>> for Theme<C> {
     fn style(
         &self,
@@ -116,6 +133,23 @@ impl<C: RgbColor> Stylist<EdgeStyle<C>> for Theme<C> {
     }
 }
 
+impl<C: RgbColor> Stylist<GaugeStyle<C>> for Theme<C> {
+    fn style(
+        &self,
+        base: &GaugeStyle<C>,
+        _selector: &StyleSelector,
+    ) -> GaugeStyle<C> {
+        let [red, green, _, yellow, ..] = C::accents();
+        base.track_color(self.bg_muted)
+            .needle_color(self.primary)
+            .tick_color(self.fg)
+            .text_color(self.fg)
+            .good_color(green)
+            .warning_color(yellow)
+            .critical_color(red)
+    }
+}
+
 impl<C: RgbColor> Stylist<KnobStyle<C>> for Theme<C> {
     fn style(
         &self,
@@ -138,6 +172,76 @@ impl<C: RgbColor> Stylist<LabelStyle<C>> for Theme<C> {
     }
 }
 
+impl<C: RgbColor> Stylist<MeterStyle<C>> for Theme<C> {
+    fn style(
+        &self,
+        base: &MeterStyle<C>,
+        _selector: &StyleSelector,
+    ) -> MeterStyle<C> {
+        let [red, green, _, yellow, ..] = C::accents();
+        base.track_color(self.bg_muted)
+            .fill_color(self.primary)
+            .mark_color(self.fg)
+            .good_color(green)
+            .warning_color(yellow)
+            .critical_color(red)
+    }
+}
+
+impl<C: RgbColor> Stylist<NumberInputStyle<C>> for Theme<C> {
+    fn style(
+        &self,
+        base: &NumberInputStyle<C>,
+        selector: &StyleSelector,
+    ) -> NumberInputStyle<C> {
+        base.container(self.interactive_container(selector))
+            .editing(
+                self.container()
+                    .background_color(self.bg_muted)
+                    .border(self.border().color(self.primary)),
+            )
+    }
+}
+
+impl<C: RgbColor> Stylist<QrCodeStyle<C>> for Theme<C> {
+    fn style(
+        &self,
+        base: &QrCodeStyle<C>,
+        _selector: &StyleSelector,
+    ) -> QrCodeStyle<C> {
+        // Black on white even in a dark theme, for scanners.
+        base.dark_color(C::BLACK).light_color(C::WHITE)
+    }
+}
+
+impl<C: RgbColor> Stylist<RadioGroupStyle<C>> for Theme<C> {
+    fn style(
+        &self,
+        base: &RadioGroupStyle<C>,
+        selector: &StyleSelector,
+    ) -> RadioGroupStyle<C> {
+        let option = if selector.pseudoclass.hovered {
+            BlockStyle::base().background_color(self.bg_muted)
+        } else {
+            BlockStyle::base()
+        };
+
+        base.option(option)
+            .ring_color(self.fg)
+            .dot_color(self.primary)
+    }
+}
+
+impl<C: RgbColor> Stylist<RichTextStyle<C>> for Theme<C> {
+    fn style(
+        &self,
+        base: &RichTextStyle<C>,
+        _selector: &StyleSelector,
+    ) -> RichTextStyle<C> {
+        base.text_color(self.fg)
+    }
+}
+
 impl<C: RgbColor> Stylist<ScrollableStyle<C>> for Theme<C> {
     fn style(
         &self,
@@ -151,6 +255,16 @@ impl<C: RgbColor> Stylist<ScrollableStyle<C>> for Theme<C> {
     }
 }
 
+impl<C: RgbColor> Stylist<SegmentDisplayStyle<C>> for Theme<C> {
+    fn style(
+        &self,
+        base: &SegmentDisplayStyle<C>,
+        _selector: &StyleSelector,
+    ) -> SegmentDisplayStyle<C> {
+        base.on_color(self.primary).off_color(self.bg_muted)
+    }
+}
+
 impl<C: RgbColor> Stylist<SelectStyle<C>> for Theme<C> {
     fn style(
         &self,
@@ -176,6 +290,44 @@ impl<C: RgbColor> Stylist<SliderStyle<C>> for Theme<C> {
     }
 }
 
+impl<C: RgbColor> Stylist<SwitchStyle<C>> for Theme<C> {
+    fn style(
+        &self,
+        base: &SwitchStyle<C>,
+        selector: &StyleSelector,
+    ) -> SwitchStyle<C> {
+        let container = self.interactive_container(selector);
+
+        base.container(container)
+            .checked(
+                container
+                    .background_color(self.primary)
+                    .border(self.border().color(self.primary)),
+            )
+            .thumb_color(self.fg)
+            .checked_thumb_color(self.bg)
+    }
+}
+
+impl<C: RgbColor> Stylist<TabsStyle<C>> for Theme<C> {
+    fn style(
+        &self,
+        base: &TabsStyle<C>,
+        selector: &StyleSelector,
+    ) -> TabsStyle<C> {
+        let tab = if selector.pseudoclass.hovered {
+            BlockStyle::base()
+                .background_color(self.bg_muted)
+                .border(BorderStyle::base().radius(self.border_radius))
+        } else {
+            BlockStyle::base()
+        };
+
+        base.tab(tab)
+            .active_tab(self.container().background_color(self.bg_muted))
+    }
+}
+
 #[cfg(feature = "tiny-icons")]
 impl<C: RgbColor> Stylist<IconStyle<C>> for Theme<C> {
     fn style(
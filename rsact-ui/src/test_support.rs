@@ -4,7 +4,9 @@
 //! numbers stay comparable. Not part of the public API.
 
 use crate::{
-    el::ctx::Wtf,
+    el::{arena::ElArena, ctx::Wtf},
+    font::FontCtx,
+    page::{Page, dev::DevTools},
     prelude::*,
     ui::{UI, WithPages},
 };
@@ -80,3 +82,23 @@ pub fn nested_flex_page(
     let _ = ui.current_page();
     (ui, labels)
 }
+
+/// Build a headless page of `root` on a `size` viewport with the root element
+/// focused, for widget crates to drive through [`Page::handle_events`] and
+/// [`Page::use_renderer`].
+pub fn focused_page(root: impl View<NullWtf>, size: Size) -> Page<NullWtf> {
+    let scope = rsact_reactive::scope::new_scope();
+    let mut page = Page::new(
+        (),
+        root,
+        create_signal(ElArena::new()),
+        size.maybe_reactive(),
+        ().inert(),
+        DevTools::default().signal(),
+        NullRenderer.signal(),
+        FontCtx::new().signal(),
+        scope,
+    );
+    page.focus_root();
+    page
+}
@@ -11,9 +11,12 @@ use crate::{
 };
 use alloc::{boxed::Box, vec::Vec};
 use core::{fmt::Debug, marker::PhantomData};
-use log::info;
+use log::{info, warn};
 use rsact_reactive::prelude::*;
-use rsact_reactive::scope::new_scope;
+use rsact_reactive::{
+    record::{Recordable, Recorder, Replay, ReplayStep},
+    scope::new_scope,
+};
 use tinyvec::TinyVec;
 
 pub struct UiOptions {
@@ -72,6 +75,9 @@ pub struct UI<W: WidgetCtx, P: HasPages> {
     options: UiOptions,
     has_pages: PhantomData<P>,
     fonts: Signal<FontCtx>,
+    /// Session recorder with the event encoder, set by [`UI::record`] where
+    /// the custom event type is [`Recordable`].
+    recorder: Option<(Recorder, fn(Recorder, &[Event<W::CustomEvent>]))>,
 }
 
 impl<R, I, S, E> UI<Wtf<R, I, S, E>, NoPages>
@@ -107,6 +113,7 @@ where
             options: Default::default(),
             has_pages: PhantomData,
             fonts,
+            recorder: None,
         }
     }
 
@@ -126,6 +133,17 @@ impl<W: WidgetCtx, P: HasPages> UI<W, P> {
         self
     }
 
+    /// Log clock ticks and events into `recorder`, for [`UI::replay_frame`]
+    /// to reproduce the session. See [`rsact_reactive::record`].
+    pub fn record(mut self, recorder: Recorder) -> Self
+    where
+        W::CustomEvent: Recordable,
+    {
+        self.recorder =
+            Some((recorder, |recorder, events| recorder.tick(events)));
+        self
+    }
+
     /// Add ExitEvent handler that eats exit event
     pub fn on_exit(mut self, on_exit: impl Fn() + 'static) -> Self {
         self.on_exit = Some(Box::new(on_exit));
@@ -163,6 +181,7 @@ impl<W: WidgetCtx, P: HasPages> UI<W, P> {
             options: self.options,
             has_pages: PhantomData,
             fonts: self.fonts,
+            recorder: self.recorder,
         };
 
         // Go to page if it is the first one
@@ -398,17 +417,82 @@ impl<W: WidgetCtx> UI<W, WithPages> {
         self.tick_time(now as u32)
     }
 
+    /// Advance the UI clock: drives animations (through the message queue)
+    /// and fires due [timers](rsact_reactive::timer).
     pub fn tick_time(&mut self, now_millis: u32) -> &mut Self {
-        self.message_queue
-            .as_mut()
-            .map(|queue| queue.tick(now_millis));
+        let mut tick = || {
+            self.message_queue
+                .as_mut()
+                .map(|queue| queue.tick(now_millis));
+
+            rsact_reactive::timer::tick_timers(now_millis);
+        };
+
+        // Writes made by the tick are not recorded: replaying the tick
+        // reproduces them.
+        match self.recorder {
+            Some((recorder, _)) => {
+                recorder.time(now_millis);
+                recorder.muted(tick);
+            },
+            None => tick(),
+        }
 
         self
     }
 
+    /// The time (same clock as [`UI::tick_time`]) at which the next timer is
+    /// due, so the main loop can sleep until then. `None` if no timer is
+    /// armed. Running animations are not included: they need a tick every
+    /// frame.
+    pub fn next_deadline(&self) -> Option<u32> {
+        rsact_reactive::timer::next_timer_deadline()
+    }
+
     pub fn tick(
         &mut self,
         events: impl Iterator<Item = Event<W::CustomEvent>>,
+    ) -> Vec<UnhandledEvent<W>> {
+        match self.recorder {
+            Some((recorder, record_events)) => {
+                let events = events.collect::<Vec<_>>();
+                record_events(recorder, &events);
+                recorder.muted(|| self.handle_tick(events.into_iter()))
+            },
+            None => self.handle_tick(events),
+        }
+    }
+
+    /// Replay one frame of a session recorded with [`UI::record`]: advance
+    /// the clock, apply the recorded signal writes and run the recorded
+    /// ticks. Returns `false` once the replay is over.
+    ///
+    /// Unhandled events of the replayed ticks are dropped: the app reacts to
+    /// them through signals, whose writes are replayed as well.
+    pub fn replay_frame(&mut self, replay: &mut Replay) -> bool
+    where
+        W::CustomEvent: Recordable,
+    {
+        replay.frame(|step| match step {
+            ReplayStep::Time(now) => {
+                self.tick_time(now);
+            },
+            ReplayStep::Tick(events) => {
+                let events = events.filter_map(|mut bytes| {
+                    let event = Event::decode(&mut bytes);
+                    if event.is_none() {
+                        warn!("Failed to decode a recorded event, skipping");
+                    }
+                    event
+                });
+                self.tick(events);
+            },
+        })
+    }
+
+    fn handle_tick(
+        &mut self,
+        events: impl Iterator<Item = Event<W::CustomEvent>>,
     ) -> Vec<UnhandledEvent<W>> {
         let unhandled = self
             .current_page()
@@ -484,11 +568,15 @@ impl<W: WidgetCtx> UI<W, WithPages> {
 
 #[cfg(test)]
 mod tests {
-    use super::{UI, render_once};
+    use super::{UI, WithPages, render_once};
     use crate::prelude::*;
+    use alloc::{rc::Rc, vec::Vec};
+    use core::cell::Cell;
     use rsact_reactive::{
         leak::{leak_report, leak_snapshot},
+        record::{Recorder, Replay},
         runtime::with_new_runtime,
+        timer::create_interval,
     };
 
     /// WS3.4: `render_once` builds, lays out and renders a single frame, then
@@ -520,4 +608,69 @@ mod tests {
             );
         });
     }
+
+    /// A recorded session replayed into a fresh UI ends in the same state:
+    /// app writes are applied by the replay, timer writes and exit handling
+    /// are reproduced by replaying the clock and the events.
+    #[test]
+    fn replay_reproduces_recorded_session() {
+        // Final `(count, ticks, exits)` of a session; `drive` runs it.
+        fn session(
+            drive: impl FnOnce(
+                &mut UI<Wtf<NullRenderer, (), (), ()>, WithPages>,
+                Signal<u32>,
+            ),
+            record: bool,
+        ) -> (u32, u32, u32) {
+            with_new_runtime(|_| {
+                let count = create_signal(0u32);
+                let mut ticks = create_signal(0u32);
+                create_interval(10, move || ticks.update(|t| *t += 1));
+
+                let exits = Rc::new(Cell::new(0));
+                let on_exit = exits.clone();
+                let mut ui = UI::new((), NullRenderer).no_events();
+                if record {
+                    let recorder = Recorder::new();
+                    recorder.watch("count", count);
+                    ui = ui.record(recorder);
+                }
+                let mut ui = ui
+                    .on_exit(move || on_exit.set(on_exit.get() + 1))
+                    .with_page((), || Label::new("x".inert()).into_el());
+
+                drive(&mut ui, count);
+                (count.get(), ticks.get(), exits.get())
+            })
+        }
+
+        let mut bytes = Vec::new();
+        let recorded = session(
+            |ui, mut count| {
+                let recorder = ui.recorder.unwrap().0;
+                for now in [0, 10, 25] {
+                    ui.tick_time(now);
+                    count.update(|c| *c += now);
+                    ui.tick([Event::Exit].into_iter());
+                }
+                bytes = recorder.bytes();
+            },
+            true,
+        );
+        assert_eq!(recorded, (35, 2, 3));
+
+        let mut replay = Replay::new(bytes);
+        let mut frames = 0;
+        let replayed = session(
+            |ui, count| {
+                replay.bind("count", count);
+                while ui.replay_frame(&mut replay) {
+                    frames += 1;
+                }
+            },
+            false,
+        );
+        assert_eq!(frames, 3);
+        assert_eq!(replayed, recorded);
+    }
 }
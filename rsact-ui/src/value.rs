@@ -1,4 +1,4 @@
-use core::{fmt::Display, ops::Deref, u8};
+use core::{fmt::Display, ops::Deref};
 
 pub trait RangeValue: PartialEq + Copy + 'static {
     // fn min() -> Self;
@@ -9,39 +9,47 @@ pub trait RangeValue: PartialEq + Copy + 'static {
     fn offset(&self, offset: i32) -> Self;
 }
 
-// macro_rules! impl_range_value_ints {
-//     ($($ty:ty),* $(,)?) => {
-//         $(
-//             impl RangeValue for $ty {
-//                 fn min() -> Self {
-//                     Self::MIN
-//                 }
-
-//                 fn max() -> Self {
-//                     Self::MAX
-//                 }
-
-//                 // fn values() -> u64 {
-//                 //     (2 as Self).pow(Self::BITS) as u64
-//                 // }
-
-//                 fn real_point(&self) -> f32 {
-//                     *self as f32 / (Self::MAX as f32 - Self::MIN as f32)
-//                 }
-
-//                 fn point(&self, len: u32) -> u32 {
-//                     (self.real_point() * len as f32) as u32
-//                 }
+/// A number stepped with no range of its own, as by a [`NumberInput`]:
+/// `step` moves it by `steps` of its unit, ones for integers and `f32`, the
+/// last decimal for a [`FixedPoint`].
+///
+/// [`NumberInput`]: crate::widget::number_input::NumberInput
+pub trait StepValue: PartialOrd + Copy + 'static {
+    fn step(&self, steps: i32) -> Self;
+}
 
-//                 fn offset(&self, offset: i32) -> Self {
-//                     ((*self as i64 + offset as i64)).clamp(Self::MIN as i64,
-// Self::MAX as i64)as Self                 }
-//             }
-//         )*
-//     };
-// }
+/// Integers span their whole type: `offset` moves by ones, saturating at the
+/// type's bounds.
+macro_rules! impl_range_value_ints {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl RangeValue for $ty {
+                fn real_point(&self) -> f32 {
+                    (*self as f32 - Self::MIN as f32)
+                        / (Self::MAX as f32 - Self::MIN as f32)
+                }
+
+                fn point(&self, len: u32) -> u32 {
+                    (self.real_point() * len as f32) as u32
+                }
+
+                fn offset(&self, offset: i32) -> Self {
+                    (*self as i64 + offset as i64)
+                        .clamp(Self::MIN as i64, Self::MAX as i64)
+                        as Self
+                }
+            }
+
+            impl StepValue for $ty {
+                fn step(&self, steps: i32) -> Self {
+                    self.offset(steps)
+                }
+            }
+        )*
+    };
+}
 
-// impl_range_value_ints!(u8, u16, u32);
+impl_range_value_ints!(u8, u16, u32, i8, i16, i32);
 
 impl<const MIN: u8, const MAX: u8, const STEP: u8> core::ops::Add<u8>
     for RangeU8<MIN, MAX, STEP>
@@ -189,26 +197,147 @@ impl<const MIN: u8, const MAX: u8, const STEP: u8> RangeValue
     }
 }
 
-// impl RangeValue for f32 {
-//     fn min() -> Self {
-//         0.0
-//     }
+impl<const MIN: u8, const MAX: u8, const STEP: u8> StepValue
+    for RangeU8<MIN, MAX, STEP>
+{
+    fn step(&self, steps: i32) -> Self {
+        self.offset(steps)
+    }
+}
 
-//     fn max() -> Self {
-//         1.0
-//     }
+/// Step of an `f32` [`RangeValue`]: a hundredth of its range.
+const F32_STEP: f32 = 0.01;
 
-//     fn real_point(&self) -> f32 {
-//         *self
-//     }
+/// `f32` is a fraction of its range, `0.0..=1.0`, and `offset` moves it by
+/// hundredths. For exact decimal steps over other ranges use [`FixedPoint`].
+impl RangeValue for f32 {
+    fn real_point(&self) -> f32 {
+        self.clamp(0.0, 1.0)
+    }
 
-//     fn point(&self, len: u32) -> u32 {
-//         (*self / len as f32) as u32
-//     }
+    fn point(&self, len: u32) -> u32 {
+        (self.real_point() * len as f32) as u32
+    }
 
-//     fn offset(&self, offset: i32) -> Self {}
-// }
+    fn offset(&self, offset: i32) -> Self {
+        (*self + offset as f32 * F32_STEP).clamp(0.0, 1.0)
+    }
+}
+
+/// Unlike its [`RangeValue`] fraction, a stepped `f32` moves by ones.
+impl StepValue for f32 {
+    fn step(&self, steps: i32) -> Self {
+        *self + steps as f32
+    }
+}
+
+/// A decimal number with `DECIMALS` digits after the point, stored as a count
+/// of its smallest units (`10^-DECIMALS`). Steps like `0.05` stay exact, where
+/// an `f32` would drift.
+///
+/// ```ignore
+/// let feed = FixedPoint::<2>::from_units(5);
+/// assert_eq!(feed.to_string(), "0.05");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedPoint<const DECIMALS: u32>(i32);
+
+impl<const DECIMALS: u32> FixedPoint<DECIMALS> {
+    const SCALE: i32 = 10i32.pow(DECIMALS);
+
+    pub const fn from_units(units: i32) -> Self {
+        Self(units)
+    }
+
+    /// The whole number `value`, saturating if it does not fit.
+    pub const fn from_int(value: i32) -> Self {
+        Self(value.saturating_mul(Self::SCALE))
+    }
 
-// TODO: f32/f64
+    pub const fn units(&self) -> i32 {
+        self.0
+    }
+
+    pub fn to_f32(&self) -> f32 {
+        self.0 as f32 / Self::SCALE as f32
+    }
+}
+
+impl<const DECIMALS: u32> Display for FixedPoint<DECIMALS> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let scale = Self::SCALE.unsigned_abs();
+        let units = self.0.unsigned_abs();
+        let sign = if self.0 < 0 { "-" } else { "" };
+
+        write!(f, "{sign}{}", units / scale)?;
+        if DECIMALS > 0 {
+            write!(f, ".{:0width$}", units % scale, width = DECIMALS as usize)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const DECIMALS: u32> RangeValue for FixedPoint<DECIMALS> {
+    fn real_point(&self) -> f32 {
+        self.0.real_point()
+    }
+
+    fn point(&self, len: u32) -> u32 {
+        self.0.point(len)
+    }
+
+    fn offset(&self, offset: i32) -> Self {
+        Self(self.0.offset(offset))
+    }
+}
+
+impl<const DECIMALS: u32> StepValue for FixedPoint<DECIMALS> {
+    fn step(&self, steps: i32) -> Self {
+        self.offset(steps)
+    }
+}
+
+// TODO: f64
 // TODO: Events with offsets must return f32 or other type with known offset
 // properties
+
+#[cfg(test)]
+mod tests {
+    use super::{FixedPoint, RangeValue, StepValue};
+    use alloc::string::ToString;
+
+    #[test]
+    fn ints_offset_saturating_at_their_bounds() {
+        assert_eq!(250u8.offset(10), u8::MAX);
+        assert_eq!((-120i8).offset(-10), i8::MIN);
+        assert_eq!(215u16.offset(-15), 200);
+        assert_eq!(0i32.real_point(), 0.5f32);
+    }
+
+    #[test]
+    fn f32_is_a_fraction_stepped_by_hundredths() {
+        assert_eq!(0.995f32.offset(1), 1.0);
+        assert_eq!(0.0f32.offset(-1), 0.0);
+        assert!((0.5f32.offset(5) - 0.55).abs() < f32::EPSILON);
+        assert_eq!(1.5f32.real_point(), 1.0);
+    }
+
+    #[test]
+    fn f32_steps_by_ones() {
+        assert_eq!(215.0f32.step(5), 220.0);
+        assert_eq!((-0.5f32).step(-2), -2.5);
+        assert_eq!(250u8.step(10), u8::MAX);
+    }
+
+    #[test]
+    fn fixed_point_displays_its_decimals() {
+        assert_eq!(FixedPoint::<2>::from_units(5).to_string(), "0.05");
+        assert_eq!(FixedPoint::<2>::from_units(-1234).to_string(), "-12.34");
+        assert_eq!(FixedPoint::<1>::from_int(215).to_string(), "215.0");
+        assert_eq!(FixedPoint::<0>::from_units(7).to_string(), "7");
+        assert_eq!(
+            FixedPoint::<2>::from_units(5).offset(-10),
+            FixedPoint::from_units(-5)
+        );
+    }
+}
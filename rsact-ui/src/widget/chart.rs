@@ -0,0 +1,854 @@
+use super::prelude::*;
+use crate::font::TextOverflow;
+use alloc::{collections::VecDeque, string::String, vec::Vec};
+use core::cell::Cell;
+use num::Float;
+
+declare_widget_style! {
+    ChartStyle () {
+        container: container,
+        series_color: color {
+            transparent_series_color: transparent,
+        },
+        fill_color: color {
+            transparent_fill_color: transparent,
+        },
+        grid_color: color {
+            transparent_grid_color: transparent,
+        },
+        label_color: color {
+            transparent_label_color: transparent,
+        },
+        series_width: u32 = 1,
+    }
+}
+
+impl<C: Color> ChartStyle<C> {
+    fn series_draw_style(&self, color: C) -> DrawStyle<C> {
+        DrawStyle {
+            fill: None,
+            stroke: Some(color),
+            stroke_width: self.series_width,
+            stroke_alignment: StrokeAlignment::Center,
+        }
+    }
+}
+
+/// Fixed-capacity ring buffer of chart samples: pushing into a full buffer
+/// drops the oldest sample.
+///
+/// Keep it in a signal and push into it, the [`Chart`] redraws only what was
+/// appended:
+///
+/// ```ignore
+/// let mut temperatures = create_signal(Samples::new(60));
+/// temperatures.update(|samples| samples.push(215.3));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Samples {
+    values: VecDeque<f32>,
+    capacity: usize,
+    /// Samples ever pushed, telling the appended ones from those drawn.
+    pushed: u64,
+}
+
+impl Samples {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self { values: VecDeque::with_capacity(capacity), capacity, pushed: 0 }
+    }
+
+    pub fn push(&mut self, value: f32) {
+        if self.values.len() == self.capacity {
+            self.values.pop_front();
+        }
+        self.values.push_back(value);
+        self.pushed += 1;
+    }
+
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.values.len() == self.capacity
+    }
+
+    /// Samples from the oldest to the newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = f32> + '_ {
+        self.values.iter().copied()
+    }
+
+    pub fn last(&self) -> Option<f32> {
+        self.values.back().copied()
+    }
+
+    /// Smallest and largest sample, `NaN`s skipped.
+    pub fn min_max(&self) -> Option<(f32, f32)> {
+        self.iter().filter(|value| !value.is_nan()).fold(
+            None,
+            |range, value| {
+                Some(match range {
+                    Some((min, max)) => (value.min(min), value.max(max)),
+                    None => (value, value),
+                })
+            },
+        )
+    }
+}
+
+/// How samples are drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ChartKind {
+    /// Samples joined by straight lines.
+    #[default]
+    Line,
+    /// Each sample held until the next one.
+    Step,
+    /// A bar per sample, from zero (or the nearest edge of the range).
+    Bars,
+    /// A line with the area down to zero filled.
+    Area,
+}
+
+/// Where samples go horizontally.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ChartMode {
+    /// The newest sample on the right, older ones shifted left. Once the
+    /// buffer is full every sample moves on every push, so each push repaints
+    /// the whole chart.
+    #[default]
+    Scroll,
+    /// A cursor sweeps left to right, overwriting the previous pass like an
+    /// oscilloscope. Every push draws only its own column, also once the
+    /// buffer is full.
+    Sweep,
+}
+
+/// Vertical range of a chart.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum YRange {
+    /// Fit the samples, rounded out to the grid step.
+    #[default]
+    Auto,
+    Fixed {
+        min: f32,
+        max: f32,
+    },
+}
+
+/// What was drawn last frame, to draw only the difference on the next one.
+#[derive(Clone, Copy, PartialEq)]
+struct Drawn {
+    pushed: u64,
+    len: usize,
+    plot: Plot,
+}
+
+#[derive(Builder)]
+#[builds(Chart<W>)]
+pub struct ChartBuilder<W: WidgetCtx> {
+    #[widget]
+    layout: Layout,
+    #[widget]
+    samples: MaybeReactive<Samples>,
+    #[widget]
+    kind: ChartKind,
+    #[widget]
+    mode: ChartMode,
+    #[widget]
+    y_range: YRange,
+    #[widget]
+    grid: u32,
+    #[widget]
+    labels: bool,
+    #[widget]
+    x_labels: Option<(&'static str, &'static str)>,
+    #[widget]
+    drawn: Cell<Option<Drawn>>,
+    #[widget]
+    style: WidgetStyleFn<ChartStyle<W::Color>>,
+}
+
+/// A time-series chart over a [`Samples`] ring buffer, with horizontal
+/// gridlines and value labels on the left.
+///
+/// A push that keeps the Y range draws only the new sample's column, see
+/// [`ChartMode`] for when the whole chart moves instead.
+///
+/// ```ignore
+/// Chart::new(temperatures)
+///     .kind(ChartKind::Area)
+///     .y_range(0.0, 300.0)
+///     .x_labels("-60s", "now")
+/// ```
+pub struct Chart<W: WidgetCtx> {
+    layout: Layout,
+    samples: MaybeReactive<Samples>,
+    kind: ChartKind,
+    mode: ChartMode,
+    y_range: YRange,
+    grid: u32,
+    labels: bool,
+    x_labels: Option<(&'static str, &'static str)>,
+    drawn: Cell<Option<Drawn>>,
+    style: WidgetStyleFn<ChartStyle<W::Color>>,
+}
+
+impl<W: WidgetCtx + 'static> Chart<W> {
+    pub fn new(samples: impl IntoMaybeReactive<Samples>) -> ChartBuilder<W> {
+        ChartBuilder {
+            layout: Layout::edge(
+                Axis::X.canon(Length::fill(), Length::Fixed(64)),
+            ),
+            samples: samples.maybe_reactive(),
+            kind: ChartKind::Line,
+            mode: ChartMode::Scroll,
+            y_range: YRange::Auto,
+            grid: 4,
+            labels: true,
+            x_labels: None,
+            drawn: Cell::new(None),
+            style: None,
+        }
+    }
+
+    /// A compact line chart for inline use, e.g. next to a value in a row:
+    /// no grid, no labels and a range tight around the samples.
+    pub fn sparkline(
+        samples: impl IntoMaybeReactive<Samples>,
+    ) -> ChartBuilder<W> {
+        ChartBuilder {
+            layout: Layout::edge(LengthSize::fixed_length(48, 12)),
+            ..Self::new(samples).grid(0).labels(false)
+        }
+    }
+}
+
+impl<W: WidgetCtx + 'static> ChartBuilder<W> {
+    pub fn kind(mut self, kind: ChartKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn mode(mut self, mode: ChartMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Fixed Y range, samples outside of it are clamped to its edges.
+    pub fn y_range(mut self, min: f32, max: f32) -> Self {
+        self.y_range = YRange::Fixed { min, max };
+        self
+    }
+
+    /// Number of intervals between gridlines, `4` by default. `0` removes the
+    /// grid and its labels.
+    pub fn grid(mut self, intervals: u32) -> Self {
+        self.grid = intervals;
+        self
+    }
+
+    /// Show gridline values on the left, `true` by default.
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Labels under the oldest and the newest end of the X axis.
+    pub fn x_labels(
+        mut self,
+        oldest: &'static str,
+        newest: &'static str,
+    ) -> Self {
+        self.x_labels = Some((oldest, newest));
+        self
+    }
+
+    pub fn style(mut self, style: impl StyleFn<ChartStyle<W::Color>>) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+}
+
+impl<W: WidgetCtx + 'static> LayoutWidget<W> for ChartBuilder<W> {
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+impl<W: WidgetCtx + 'static> SizedWidget<W> for ChartBuilder<W> {}
+
+/// The plot area and the value range mapped onto it.
+#[derive(Clone, Copy, PartialEq)]
+struct Plot {
+    rect: Rect,
+    min: f32,
+    max: f32,
+    /// Distance between gridlines, `0` without a grid.
+    step: f32,
+    slots: usize,
+}
+
+impl Plot {
+    /// Columns `left..right` of `slot`.
+    fn band(&self, slot: usize) -> (i32, i32) {
+        let width = self.rect.size.width as usize;
+        let left = self.rect.top_left.x;
+        (
+            left + (slot * width / self.slots) as i32,
+            left + ((slot + 1) * width / self.slots) as i32,
+        )
+    }
+
+    fn x(&self, slot: usize) -> i32 {
+        let (left, right) = self.band(slot);
+        left + (right - left) / 2
+    }
+
+    fn y(&self, value: f32) -> i32 {
+        let height = self.rect.size.height.saturating_sub(1) as f32;
+        let span = self.max - self.min;
+        let fraction = if span > 0.0 {
+            ((value - self.min) / span).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        self.rect.top_left.y + height as i32
+            - Float::round(fraction * height) as i32
+    }
+
+    fn point(&self, slot: usize, value: f32) -> Point {
+        Point::new(self.x(slot), self.y(value))
+    }
+
+    /// Where bars and areas start: zero, or the range edge nearest to it.
+    fn baseline(&self) -> i32 {
+        self.y(0f32.clamp(self.min, self.max))
+    }
+
+    /// Gridline values, from the bottom up.
+    fn ticks(&self) -> impl Iterator<Item = f32> + '_ {
+        let count = if self.step > 0.0 {
+            Float::round((self.max - self.min) / self.step) as u32 + 1
+        } else {
+            0
+        };
+        (0..count).map(|index| self.min + self.step * index as f32)
+    }
+
+    /// Digits after the point telling gridline values apart.
+    fn precision(&self) -> usize {
+        if self.step > 0.0 && self.step < 1.0 {
+            (-Float::floor(Float::log10(self.step))).min(3.0) as usize
+        } else {
+            0
+        }
+    }
+}
+
+/// 1, 2 or 5 times a power of ten, at least `value`.
+fn nice_step(value: f32) -> f32 {
+    let magnitude =
+        Float::powi(10.0f32, Float::floor(Float::log10(value)) as i32);
+    let fraction = value / magnitude;
+    let nice = if fraction <= 1.0 {
+        1.0
+    } else if fraction <= 2.0 {
+        2.0
+    } else if fraction <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * magnitude
+}
+
+/// The range and grid step showing `samples` with `intervals` gridlines.
+fn value_range(
+    y_range: YRange,
+    intervals: u32,
+    samples: &Samples,
+) -> (f32, f32, f32) {
+    match y_range {
+        YRange::Fixed { min, max } => {
+            let step = if intervals > 0 {
+                (max - min) / intervals as f32
+            } else {
+                0.0
+            };
+            (min, max, step)
+        },
+        YRange::Auto => {
+            let (mut min, mut max) = samples.min_max().unwrap_or((0.0, 1.0));
+            if max <= min {
+                min -= 0.5;
+                max += 0.5;
+            }
+            if intervals == 0 {
+                return (min, max, 0.0);
+            }
+
+            let step = nice_step((max - min) / intervals as f32);
+            (
+                Float::floor(min / step) * step,
+                Float::ceil(max / step) * step,
+                step,
+            )
+        },
+    }
+}
+
+impl<W: WidgetCtx + 'static> Chart<W> {
+    /// Horizontal slot of the sample at `index` of `samples`.
+    fn slot(&self, samples: &Samples, index: usize) -> usize {
+        match self.mode {
+            ChartMode::Scroll => index,
+            ChartMode::Sweep => {
+                let oldest = samples.pushed - samples.len() as u64;
+                ((oldest + index as u64) % samples.capacity() as u64) as usize
+            },
+        }
+    }
+
+    /// Whether the sample at `index` is joined to the previous one. The sweep
+    /// cursor starting a new pass breaks the line.
+    fn joined(&self, samples: &Samples, index: usize) -> bool {
+        index > self.first_drawn(samples)
+            && self.slot(samples, index) > self.slot(samples, index - 1)
+    }
+
+    /// The first sample to draw: in a full sweep the oldest one sits in the
+    /// gap ahead of the cursor.
+    fn first_drawn(&self, samples: &Samples) -> usize {
+        match self.mode {
+            ChartMode::Sweep if samples.is_full() && samples.len() > 1 => 1,
+            _ => 0,
+        }
+    }
+
+    /// Whether `drawn` can be brought to `samples` drawn on `plot` by drawing
+    /// the appended samples only.
+    fn appends_to(
+        drawn: Drawn,
+        samples: &Samples,
+        plot: Plot,
+        mode: ChartMode,
+    ) -> bool {
+        let Some(appended) = samples.pushed.checked_sub(drawn.pushed) else {
+            return false;
+        };
+        let appended = appended as usize;
+        let len = (drawn.len + appended).min(samples.capacity());
+
+        drawn.plot == plot
+            && appended > 0
+            && samples.len() == len
+            && match mode {
+                ChartMode::Scroll => drawn.len + appended <= samples.capacity(),
+                ChartMode::Sweep => appended < samples.capacity(),
+            }
+    }
+
+    fn draw_sample(
+        &self,
+        renderer: &mut W::Renderer,
+        style: &ChartStyle<W::Color>,
+        plot: &Plot,
+        samples: &Samples,
+        index: usize,
+        value: f32,
+    ) -> RenderResult {
+        if value.is_nan() {
+            return Ok(());
+        }
+
+        let color = style
+            .series_color
+            .get()
+            .unwrap_or_else(W::default_foreground);
+        let line = style.series_draw_style(color);
+        let slot = self.slot(samples, index);
+        let point = plot.point(slot, value);
+        let prev = if self.joined(samples, index) {
+            samples
+                .values
+                .get(index - 1)
+                .filter(|prev| !prev.is_nan())
+                .map(|&prev| plot.point(slot - 1, prev))
+        } else {
+            None
+        };
+
+        match self.kind {
+            ChartKind::Line => {
+                renderer.line(prev.unwrap_or(point), point, &line)
+            },
+            ChartKind::Step => match prev {
+                Some(prev) => {
+                    let corner = Point::new(point.x, prev.y);
+                    renderer.line(prev, corner, &line)?;
+                    renderer.line(corner, point, &line)
+                },
+                None => renderer.line(point, point, &line),
+            },
+            ChartKind::Bars => {
+                let (left, right) = plot.band(slot);
+                // Keep bars apart once they are wide enough to spare a column.
+                let right = if right - left >= 3 { right - 1 } else { right };
+                let base = plot.baseline();
+                let top = point.y.min(base);
+                renderer.fill_solid(
+                    Rect::new(
+                        Point::new(left, top),
+                        Size::new(
+                            (right - left).max(1) as u32,
+                            ((point.y.max(base) - top) + 1) as u32,
+                        ),
+                    ),
+                    color,
+                )
+            },
+            ChartKind::Area => {
+                if let Some(prev) = prev
+                    && let Some(fill) = style.fill_color.get()
+                {
+                    let base = plot.baseline();
+                    renderer.polygon(
+                        &[
+                            prev,
+                            point,
+                            Point::new(point.x, base),
+                            Point::new(prev.x, base),
+                        ],
+                        &DrawStyle {
+                            fill: Some(fill),
+                            stroke: None,
+                            stroke_width: 0,
+                            stroke_alignment: StrokeAlignment::Inside,
+                        },
+                    )?;
+                }
+                renderer.line(prev.unwrap_or(point), point, &line)
+            },
+        }
+    }
+
+    /// Dotted gridlines of `plot` over columns `left..right`.
+    fn draw_grid(
+        renderer: &mut W::Renderer,
+        style: &ChartStyle<W::Color>,
+        plot: &Plot,
+        (left, right): (i32, i32),
+    ) -> RenderResult {
+        let Some(color) = style.grid_color.get() else {
+            return Ok(());
+        };
+
+        // Dots on even columns of the plot, wherever the drawing starts.
+        let start = left + (left - plot.rect.top_left.x).rem_euclid(2);
+        for value in plot.ticks() {
+            let y = plot.y(value);
+            for x in (start..right).step_by(2) {
+                renderer.pixel(Point::new(x, y), color)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn draw_all(
+        &self,
+        renderer: &mut W::Renderer,
+        style: &ChartStyle<W::Color>,
+        plot: &Plot,
+        samples: &Samples,
+    ) -> RenderResult {
+        let (left, _) = plot.band(0);
+        let (_, right) = plot.band(plot.slots - 1);
+        Self::draw_grid(renderer, style, plot, (left, right))?;
+
+        for (index, value) in
+            samples.iter().enumerate().skip(self.first_drawn(samples))
+        {
+            self.draw_sample(renderer, style, plot, samples, index, value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Clear the columns of the samples appended since `drawn` (and in a sweep
+    /// the gap ahead of the cursor) and draw them.
+    fn draw_appended(
+        &self,
+        renderer: &mut W::Renderer,
+        style: &ChartStyle<W::Color>,
+        plot: &Plot,
+        samples: &Samples,
+        drawn: Drawn,
+        background: W::Color,
+    ) -> RenderResult {
+        let appended = (samples.pushed - drawn.pushed) as usize;
+        let gap = usize::from(self.mode == ChartMode::Sweep);
+
+        for index in samples.len() - appended..samples.len() {
+            let slot = self.slot(samples, index);
+            let (left, _) = plot.band(slot);
+            let (_, right) = plot.band((slot + gap).min(plot.slots - 1));
+            let columns = Rect::new(
+                Point::new(left, plot.rect.top_left.y),
+                Size::new((right - left) as u32, plot.rect.size.height),
+            );
+
+            renderer.fill_solid(columns, background)?;
+            Self::draw_grid(renderer, style, plot, (left, right))?;
+            self.draw_sample(
+                renderer,
+                style,
+                plot,
+                samples,
+                index,
+                samples.values[index],
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: WidgetCtx + 'static> Widget<W> for Chart<W> {
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self_incremental(|ctx, full| {
+            let style = ctx.get_style(self.style.as_deref());
+            let inner = ctx.layout.inner;
+
+            self.samples.with(|samples| {
+                let (min, max, step) =
+                    value_range(self.y_range, self.grid, samples);
+                let mut plot = Plot {
+                    rect: inner,
+                    min,
+                    max,
+                    step,
+                    slots: samples.capacity(),
+                };
+
+                let props = ctx.visual.font_props;
+                let font = props.font();
+                let props = props.resolve(ctx.shared.viewport.get());
+                let precision = plot.precision();
+                let labels = if self.labels {
+                    plot.ticks()
+                        .map(|value| format!("{:.precision$}", value + 0.0))
+                        .collect::<Vec<String>>()
+                } else {
+                    Vec::new()
+                };
+
+                let fonts = ctx.shared.fonts;
+                let measure = |text: &str| {
+                    fonts
+                        .try_with(|fonts| {
+                            let text = fonts.measure_text(
+                                font,
+                                text,
+                                props,
+                                TextOverflow::Clip,
+                            );
+                            (text.max_content_width, text.line_height)
+                        })
+                        .unwrap_or((0, 0))
+                };
+
+                let gutter = labels
+                    .iter()
+                    .map(|label| measure(label).0)
+                    .max()
+                    .map_or(0, |width| width + 2);
+                let line_height =
+                    self.x_labels.map_or(0, |(oldest, newest)| {
+                        measure(oldest).1.max(measure(newest).1)
+                    });
+                plot.rect = Rect::new(
+                    inner.top_left + Point::new(gutter as i32, 0),
+                    Size::new(
+                        inner.size.width.saturating_sub(gutter),
+                        inner.size.height.saturating_sub(line_height),
+                    ),
+                );
+                if plot.rect.size.width == 0 || plot.rect.size.height == 0 {
+                    return Ok(());
+                }
+
+                let background = style
+                    .container
+                    .background_color
+                    .get()
+                    .or_else(|| {
+                        ctx.shared
+                            .page_style
+                            .try_with(|page| page.background_color)
+                            .flatten()
+                    })
+                    .unwrap_or_else(W::default_background);
+
+                let drawn = self.drawn.get().filter(|_| !full);
+                if let Some(drawn) = drawn
+                    && Self::appends_to(drawn, samples, plot, self.mode)
+                {
+                    self.draw_appended(
+                        ctx.renderer,
+                        &style,
+                        &plot,
+                        samples,
+                        drawn,
+                        background,
+                    )?;
+                } else {
+                    if !full {
+                        // The previous frame is still there, repaint it all.
+                        ctx.renderer
+                            .fill_solid(ctx.layout.outer, background)?;
+                    }
+                    Block::from_layout_style(
+                        ctx.layout.outer,
+                        BlockModel::zero(),
+                        style.container,
+                    )
+                    .render(ctx.renderer)?;
+
+                    let label_color = style
+                        .label_color
+                        .get()
+                        .unwrap_or_else(W::default_foreground);
+                    let label_x = inner.top_left.x;
+                    for (value, label) in plot.ticks().zip(&labels) {
+                        let (width, height) = measure(label);
+                        let top = (plot.y(value) - height as i32 / 2).clamp(
+                            inner.top_left.y,
+                            inner.top_left.y + inner.size.height as i32
+                                - height as i32,
+                        );
+                        let bounds = Rect::new(
+                            Point::new(
+                                label_x + (gutter - 2 - width) as i32,
+                                top,
+                            ),
+                            Size::new(width, height),
+                        );
+                        fonts
+                            .try_with(|fonts| {
+                                fonts.render::<W>(
+                                    font,
+                                    label,
+                                    props,
+                                    bounds,
+                                    label_color,
+                                    ctx.renderer,
+                                )
+                            })
+                            .unwrap_or(Ok(()))?;
+                    }
+
+                    if let Some((oldest, newest)) = self.x_labels {
+                        let top =
+                            plot.rect.top_left.y + plot.rect.size.height as i32;
+                        let (newest_width, _) = measure(newest);
+                        for (text, x) in [
+                            (oldest, plot.rect.top_left.x),
+                            (
+                                newest,
+                                plot.rect.top_left.x
+                                    + plot.rect.size.width as i32
+                                    - newest_width as i32,
+                            ),
+                        ] {
+                            let bounds = Rect::new(
+                                Point::new(x, top),
+                                Size::new(measure(text).0, line_height),
+                            );
+                            fonts
+                                .try_with(|fonts| {
+                                    fonts.render::<W>(
+                                        font,
+                                        text,
+                                        props,
+                                        bounds,
+                                        label_color,
+                                        ctx.renderer,
+                                    )
+                                })
+                                .unwrap_or(Ok(()))?;
+                        }
+                    }
+
+                    let renderer = &mut *ctx.renderer;
+                    renderer.clipped(plot.rect, |renderer| {
+                        self.draw_all(renderer, &style, &plot, samples)
+                    })?;
+                }
+
+                self.drawn.set(Some(Drawn {
+                    pushed: samples.pushed,
+                    len: samples.len(),
+                    plot,
+                }));
+
+                Ok(())
+            })
+        })
+    }
+
+    fn on_event(&mut self, ctx: EventCtx<'_, W>) -> EventResponse {
+        ctx.ignore()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn samples_drop_the_oldest_when_full() {
+        let mut samples = Samples::new(3);
+        for value in [1.0, 2.0, 3.0, 4.0] {
+            samples.push(value);
+        }
+
+        assert!(samples.is_full());
+        assert_eq!(samples.iter().collect::<Vec<_>>(), [2.0, 3.0, 4.0]);
+        assert_eq!(samples.min_max(), Some((2.0, 4.0)));
+        assert_eq!(samples.last(), Some(4.0));
+    }
+
+    #[test]
+    fn auto_range_is_rounded_out_to_the_grid_step() {
+        let mut samples = Samples::new(8);
+        for value in [21.3, 24.8, 22.0] {
+            samples.push(value);
+        }
+
+        assert_eq!(value_range(YRange::Auto, 4, &samples), (21.0, 25.0, 1.0));
+        // Without a grid the range is tight.
+        assert_eq!(value_range(YRange::Auto, 0, &samples), (21.3, 24.8, 0.0));
+        assert_eq!(
+            value_range(YRange::Fixed { min: 0.0, max: 300.0 }, 3, &samples),
+            (0.0, 300.0, 100.0)
+        );
+    }
+}
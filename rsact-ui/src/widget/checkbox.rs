@@ -19,7 +19,7 @@ declare_widget_style! {
 // WS13.4 (Task 5.6): every field here is read by `render`/`on_event`
 // (`value`), by `layout`/the layout tree (`layout`), or by `render` (`style`)
 // — there is no build-only field to drop, unlike `Button`/`Flex`/`Show`.
-// `value: Signal<bool>` is the widget's JOB (WS4.5 audit: the checked/toggled
+// `value: Binding<bool>` is the widget's JOB (WS4.5 audit: the checked/toggled
 // state IS what a Checkbox is), so it stays a retained `#[widget]` field
 // rather than a build-only handle. `CheckboxBuilder` therefore moves all
 // three fields into the retained `Checkbox` unchanged — the same degenerate
@@ -32,28 +32,28 @@ pub struct CheckboxBuilder<W: WidgetCtx> {
     #[widget]
     layout: Layout,
     #[widget]
-    value: Signal<bool>,
+    value: Binding<bool>,
     #[widget]
     style: WidgetStyleFn<CheckboxStyle<W::Color>>,
 }
 
 pub struct Checkbox<W: WidgetCtx> {
     layout: Layout,
-    value: Signal<bool>,
+    value: Binding<bool>,
     style: WidgetStyleFn<CheckboxStyle<W::Color>>,
 }
 
 impl<W: WidgetCtx> Checkbox<W> {
-    pub fn new(value: impl IntoSignal<bool>) -> CheckboxBuilder<W> {
+    pub fn new(value: impl IntoBinding<bool>) -> CheckboxBuilder<W> {
         CheckboxBuilder {
             // TODO: Maybe ContentLayout::Icon should be used as a single
             // char-sized square layout?
             layout: Layout::edge(Size::new_equal(16).into()),
-            // Promote to a real `Signal` so the checked state is tracked on
+            // Bind a real reactive value so the checked state is tracked on
             // read in `render` and notified on write in `on_event`. A plain
             // value (`Checkbox::new(true)`) becomes an owned signal; a passed
-            // `Signal` is reused, preserving two-way binding.
-            value: value.signal(),
+            // `Signal` or `Lens` is reused, preserving two-way binding.
+            value: value.binding(),
             style: None,
         }
     }
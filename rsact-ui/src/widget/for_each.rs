@@ -0,0 +1,325 @@
+use crate::widget::{BlockModelWidget, SizedWidget, prelude::*};
+use rsact_reactive::scope::{ScopeHandle, new_scope};
+
+/// Keyed list of children driven by a [`SignalVec`].
+///
+/// `Flex` rebuilds all of its children whenever its `Signal<Vec<El>>` changes.
+/// `For` instead consumes the [`VecDiff`]s of a `SignalVec` and touches only
+/// the affected slots: an `Insert` builds one child, a `Remove` disposes one
+/// child, a `Move` reorders without rebuilding and an `Update` rebuilds just
+/// the updated item. Every other child keeps its arena id, built widget and
+/// render probes.
+///
+/// Each row is built inside its own reactive scope, so the nodes a row creates
+/// (its widgets' signals, memos and effects) are disposed together with the
+/// row, not kept until the page is dropped.
+///
+/// Lays its children out as a flex row/column.
+///
+/// ```ignore
+/// let mut items = create_signal_vec(vec![1, 2, 3]);
+/// For::col(items, |item: &u32| format!("Item {item}"));
+/// items.push(4); // builds only the new label
+/// ```
+///
+/// For an existing `Signal<Vec<T>>`/`Memo<Vec<T>>`, use
+/// [`For::keyed_col`]/[`For::keyed_row`]: the source is reconciled by key into
+/// an internal `SignalVec`, so unchanged items survive a whole-list write.
+#[derive(View)]
+pub struct For<W: WidgetCtx> {
+    layout: Layout,
+    /// Installs the diff effect on build. `None` once built.
+    attach: Option<Box<dyn FnOnce(BuildCtx<W>)>>,
+}
+
+impl<W: WidgetCtx + 'static> For<W> {
+    #[track_caller]
+    pub fn row<T, V>(
+        items: SignalVec<T>,
+        view: impl Fn(&T) -> V + 'static,
+    ) -> Self
+    where
+        T: Clone + 'static,
+        V: View<W>,
+    {
+        Self::new(items, view, Axis::X)
+    }
+
+    #[track_caller]
+    pub fn col<T, V>(
+        items: SignalVec<T>,
+        view: impl Fn(&T) -> V + 'static,
+    ) -> Self
+    where
+        T: Clone + 'static,
+        V: View<W>,
+    {
+        Self::new(items, view, Axis::Y)
+    }
+
+    #[track_caller]
+    pub fn keyed_row<T, K, V>(
+        source: impl ReadSignal<Vec<T>> + 'static,
+        key: impl Fn(&T) -> K + 'static,
+        view: impl Fn(&T) -> V + 'static,
+    ) -> Self
+    where
+        T: Clone + PartialEq + 'static,
+        K: PartialEq,
+        V: View<W>,
+    {
+        Self::row(create_keyed_signal_vec(source, key), view)
+    }
+
+    #[track_caller]
+    pub fn keyed_col<T, K, V>(
+        source: impl ReadSignal<Vec<T>> + 'static,
+        key: impl Fn(&T) -> K + 'static,
+        view: impl Fn(&T) -> V + 'static,
+    ) -> Self
+    where
+        T: Clone + PartialEq + 'static,
+        K: PartialEq,
+        V: View<W>,
+    {
+        Self::col(create_keyed_signal_vec(source, key), view)
+    }
+
+    #[track_caller]
+    fn new<T, V>(
+        items: SignalVec<T>,
+        view: impl Fn(&T) -> V + 'static,
+        axis: Axis,
+    ) -> Self
+    where
+        T: Clone + 'static,
+        V: View<W>,
+    {
+        // Mirrors the children's layouts like `Flex`'s `layout_children`: the
+        // layout itself reads children from the arena, but the page relayout
+        // memo tracks this list to notice structure changes.
+        let layouts = create_signal(Vec::<Layout>::new());
+
+        let layout = Layout::shrink(LayoutKind::Flex(FlexLayout::base(
+            axis,
+            layouts.maybe_reactive(),
+        )));
+
+        let attach = move |ctx: BuildCtx<W>| {
+            let mut cursor = DiffCursor::new();
+            let mut rows = Vec::<ScopeHandle>::new();
+
+            create_effect(move |_| {
+                let mut diffs = Vec::new();
+                items.diffs(&mut cursor, |diff| diffs.push(diff));
+
+                // Rows must neither subscribe this effect to what they read
+                // nor be owned by it (it would dispose them on its next run):
+                // each row is owned by its own scope in `rows`.
+                untrack(|| apply_diffs(ctx, &mut rows, layouts, &view, diffs));
+            });
+        };
+
+        Self { layout, attach: Some(Box::new(attach)) }
+    }
+
+    pub fn gap<G: Into<Size> + Copy + PartialEq + 'static>(
+        mut self,
+        gap: impl IntoMaybeReactive<G>,
+    ) -> Self {
+        self.layout.setter(gap.maybe_reactive(), |layout, &gap| {
+            layout.expect_flex_mut().gap = gap.into();
+        });
+        self
+    }
+}
+
+fn apply_diffs<W: WidgetCtx + 'static, T, V: View<W>>(
+    mut ctx: BuildCtx<W>,
+    rows: &mut Vec<ScopeHandle>,
+    mut layouts: Signal<Vec<Layout>>,
+    view: &impl Fn(&T) -> V,
+    diffs: Vec<VecDiff<T>>,
+) {
+    if diffs.is_empty() {
+        return;
+    }
+
+    // `BuildCtx` is a `Copy` handle: the closure gets its own copy.
+    let build_row = move |index: usize, value: &T, replace: bool| {
+        let mut ctx = ctx;
+        let scope = new_scope();
+        let mut el = view(value).into_el();
+        let layout = el.layout();
+        if replace {
+            ctx.replace_child(index, &mut el);
+        } else {
+            ctx.insert_child(index, &mut el);
+        }
+        scope.leave();
+        (scope, layout)
+    };
+
+    let mut layout_diffs = Vec::with_capacity(diffs.len());
+    for diff in diffs {
+        let (row_diff, layout_diff) = match diff {
+            VecDiff::Insert { index, value } => {
+                let (scope, layout) = build_row(index, &value, false);
+                (
+                    VecDiff::Insert { index, value: scope },
+                    VecDiff::Insert { index, value: layout },
+                )
+            },
+            VecDiff::Remove { index } => {
+                ctx.remove_child(index);
+                (VecDiff::Remove { index }, VecDiff::Remove { index })
+            },
+            VecDiff::Move { from, to } => {
+                ctx.move_child(from, to);
+                (VecDiff::Move { from, to }, VecDiff::Move { from, to })
+            },
+            VecDiff::Update { index, value } => {
+                let (scope, layout) = build_row(index, &value, true);
+                (
+                    VecDiff::Update { index, value: scope },
+                    VecDiff::Update { index, value: layout },
+                )
+            },
+            VecDiff::Clear => {
+                ctx.clear_children();
+                (VecDiff::Clear, VecDiff::Clear)
+            },
+        };
+
+        // Dropping a replaced/removed row's scope disposes its nodes, after
+        // its element already left the arena (and disposed its probes).
+        row_diff.apply(rows);
+        layout_diffs.push(layout_diff);
+    }
+
+    layouts.update(|layouts| {
+        layout_diffs
+            .into_iter()
+            .for_each(|diff| diff.apply(layouts))
+    });
+}
+
+impl<W: WidgetCtx + 'static> LayoutWidget<W> for For<W> {
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+impl<W: WidgetCtx + 'static> SizedWidget<W> for For<W> {}
+impl<W: WidgetCtx + 'static> BlockModelWidget<W> for For<W> {}
+impl<W: WidgetCtx + 'static> FontSettingWidget<W> for For<W> {}
+
+impl<W: WidgetCtx + 'static> Widget<W> for For<W> {
+    fn debug_name(&self) -> &'static str {
+        "For"
+    }
+
+    fn build(&mut self, ctx: BuildCtx<W>) {
+        match self.attach.take() {
+            Some(attach) => attach(ctx),
+            None => log::error!("For widget built twice"),
+        }
+    }
+
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn render(&self, _ctx: RenderCtx<'_, W>) -> RenderResult {
+        Ok(())
+    }
+
+    fn on_event(&mut self, ctx: EventCtx<'_, W>) -> EventResponse {
+        ctx.ignore()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::For;
+    use crate::{
+        el::{arena::ElArena, build::BuildCtx},
+        test_support::NullWtf,
+        widget::{Widget, combinators::Unit},
+    };
+    use alloc::{rc::Rc, vec, vec::Vec};
+    use core::cell::Cell;
+    use rsact_reactive::prelude::*;
+
+    /// Only changed items are built: a push builds one child, a remove and a
+    /// move keep the other children's arena ids.
+    #[test]
+    fn builds_only_changed_children() {
+        with_new_runtime(|_| {
+            let builds = Rc::new(Cell::new(0));
+            let mut items = create_signal_vec(vec![1, 2, 3]);
+
+            let counter = builds.clone();
+            let mut root = For::<NullWtf>::col(items, move |_: &i32| {
+                counter.set(counter.get() + 1);
+                Unit.el()
+            })
+            .el();
+            let arena = create_signal(ElArena::new());
+            let root_id = BuildCtx::run(&mut root, arena);
+
+            let children = || {
+                arena.with(|arena| arena.children(root_id).unwrap().to_vec())
+            };
+
+            let initial = children();
+            assert_eq!(initial.len(), 3);
+            assert_eq!(builds.get(), 3);
+
+            items.push(4);
+            assert_eq!(builds.get(), 4, "push must build only the new item");
+            let pushed = children();
+            assert_eq!(&pushed[..3], &initial[..]);
+
+            items.remove(0);
+            items.move_item(0, 2);
+            assert_eq!(builds.get(), 4, "remove/move must not rebuild");
+            assert_eq!(children(), vec![pushed[2], pushed[3], pushed[1]]);
+            arena.with(|arena| {
+                assert!(!arena.contains(initial[0]), "removed child disposed");
+                assert_eq!(arena.el_count(), 4);
+            });
+
+            items.set(1, 10);
+            assert_eq!(builds.get(), 5, "update rebuilds only that item");
+            let updated = children();
+            assert_eq!(updated[0], pushed[2]);
+            assert_ne!(updated[1], pushed[3]);
+        });
+    }
+
+    /// Nodes created by a row are disposed with the row.
+    #[test]
+    fn removed_row_disposes_its_nodes() {
+        with_new_runtime(|_| {
+            let created = Rc::new(Cell::new(Vec::<Signal<i32>>::new()));
+            let mut items = create_signal_vec(vec![1, 2]);
+
+            let sink = created.clone();
+            let mut root = For::<NullWtf>::col(items, move |item: &i32| {
+                let mut all = sink.take();
+                all.push(create_signal(*item));
+                sink.set(all);
+                Unit.el()
+            })
+            .el();
+            let arena = create_signal(ElArena::new());
+            BuildCtx::run(&mut root, arena);
+
+            items.remove(0);
+
+            let signals = created.take();
+            assert!(!signals[0].is_alive(), "removed row leaked its signal");
+            assert!(signals[1].is_alive());
+        });
+    }
+}
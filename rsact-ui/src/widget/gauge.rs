@@ -0,0 +1,344 @@
+use super::prelude::*;
+use crate::{
+    font::TextOverflow, layout::length::LengthSize, render::geometry::*,
+    value::RangeValue,
+};
+use alloc::vec::Vec;
+use core::{fmt::Display, ops::RangeInclusive};
+use num::Float;
+
+/// Meaning of a [`Gauge`] zone or a [`Meter`](super::meter::Meter)
+/// threshold, colored by the widget style.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZoneLevel {
+    Good,
+    Warning,
+    Critical,
+}
+
+/// Real points of the values at both ends of a scale, mapping values onto
+/// `0.0..=1.0` of a dial or a track.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct Scale {
+    min: f32,
+    max: f32,
+}
+
+impl Scale {
+    pub(super) const FULL: Self = Self { min: 0.0, max: 1.0 };
+
+    pub(super) fn new<V: RangeValue>(range: RangeInclusive<V>) -> Self {
+        Self { min: range.start().real_point(), max: range.end().real_point() }
+    }
+
+    pub(super) fn fraction(&self, real_point: f32) -> f32 {
+        if self.max <= self.min {
+            return 0.0;
+        }
+        ((real_point - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+}
+
+declare_widget_style! {
+    GaugeStyle () {
+        container: container,
+        track_color: color {
+            transparent_track_color: transparent,
+        },
+        needle_color: color {
+            transparent_needle_color: transparent,
+        },
+        tick_color: color {
+            transparent_tick_color: transparent,
+        },
+        text_color: color,
+        good_color: color {
+            transparent_good_color: transparent,
+        },
+        warning_color: color {
+            transparent_warning_color: transparent,
+        },
+        critical_color: color {
+            transparent_critical_color: transparent,
+        },
+        track_width: u32 = 4,
+        tick_length: u32 = 3,
+        // Clockwise from 3 o'clock, as for the knob. The default opening is
+        // at the bottom.
+        angle_start: Angle = Angle::from_degrees(135.0),
+        angle: Angle = Angle::from_degrees(270.0),
+    }
+}
+
+impl<C: Color> GaugeStyle<C> {
+    fn zone_color(&self, level: ZoneLevel) -> Option<C> {
+        match level {
+            ZoneLevel::Good => self.good_color.get(),
+            ZoneLevel::Warning => self.warning_color.get(),
+            ZoneLevel::Critical => self.critical_color.get(),
+        }
+    }
+
+    fn ring_draw_style(&self, color: C) -> DrawStyle<C> {
+        DrawStyle {
+            fill: None,
+            stroke: Some(color),
+            stroke_width: self.track_width,
+            stroke_alignment: StrokeAlignment::Center,
+        }
+    }
+}
+
+#[derive(Builder)]
+#[builds(Gauge<W, V>)]
+pub struct GaugeBuilder<W: WidgetCtx, V: RangeValue + Display> {
+    #[widget]
+    layout: Layout,
+    #[widget]
+    value: MaybeReactive<V>,
+    #[widget]
+    scale: Scale,
+    #[widget]
+    zones: Vec<(f32, f32, ZoneLevel)>,
+    #[widget]
+    ticks: u32,
+    #[widget]
+    unit: &'static str,
+    #[widget]
+    style: WidgetStyleFn<GaugeStyle<W::Color>>,
+}
+
+/// A radial dial: a track with colored zones and ticks, a needle pointing at
+/// the value and the value itself under the needle hub.
+///
+/// The dial spans the whole range of the value type unless narrowed with
+/// [`GaugeBuilder::range`].
+///
+/// ```ignore
+/// Gauge::new(temperature)
+///     .range(0..=300u16)
+///     .zone(230..=260, ZoneLevel::Warning)
+///     .zone(260..=300, ZoneLevel::Critical)
+///     .unit("°C")
+/// ```
+pub struct Gauge<W: WidgetCtx, V: RangeValue + Display> {
+    layout: Layout,
+    value: MaybeReactive<V>,
+    scale: Scale,
+    /// Zones as real points of their ends.
+    zones: Vec<(f32, f32, ZoneLevel)>,
+    ticks: u32,
+    unit: &'static str,
+    style: WidgetStyleFn<GaugeStyle<W::Color>>,
+}
+
+impl<W: WidgetCtx, V: RangeValue + Display + 'static> Gauge<W, V> {
+    pub fn new(value: impl IntoMaybeReactive<V>) -> GaugeBuilder<W, V> {
+        GaugeBuilder {
+            layout: Layout::edge(LengthSize::new_equal(Length::Fixed(64))),
+            value: value.maybe_reactive(),
+            scale: Scale::FULL,
+            zones: Vec::new(),
+            ticks: 5,
+            unit: "",
+            style: None,
+        }
+    }
+}
+
+impl<W: WidgetCtx, V: RangeValue + Display + 'static> GaugeBuilder<W, V> {
+    pub fn size(mut self, size: impl Into<u32>) -> Self {
+        self.layout.update_untracked(|layout| {
+            layout.size = LengthSize::new_equal(Length::Fixed(size.into()));
+        });
+        self
+    }
+
+    /// Values at the ends of the dial.
+    pub fn range(mut self, range: RangeInclusive<V>) -> Self {
+        self.scale = Scale::new(range);
+        self
+    }
+
+    /// Color the track over `range` by `level`. Later zones are drawn over
+    /// earlier ones.
+    pub fn zone(mut self, range: RangeInclusive<V>, level: ZoneLevel) -> Self {
+        self.zones.push((
+            range.start().real_point(),
+            range.end().real_point(),
+            level,
+        ));
+        self
+    }
+
+    /// Intervals between ticks, `5` by default. `0` removes the ticks.
+    pub fn ticks(mut self, intervals: u32) -> Self {
+        self.ticks = intervals;
+        self
+    }
+
+    /// Suffix shown after the value.
+    pub fn unit(mut self, unit: &'static str) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    pub fn style(mut self, style: impl StyleFn<GaugeStyle<W::Color>>) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+}
+
+impl<W: WidgetCtx, V: RangeValue + Display + 'static> LayoutWidget<W>
+    for GaugeBuilder<W, V>
+{
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+
+/// Point `radius` away from `center` at `angle`, clockwise from 3 o'clock.
+fn polar(center: Point, radius: f32, angle: Angle) -> Point {
+    let angle = angle.to_radians();
+    center
+        + Point::new(
+            Float::round(radius * Float::cos(angle)) as i32,
+            Float::round(radius * Float::sin(angle)) as i32,
+        )
+}
+
+impl<W: WidgetCtx, V: RangeValue + Display + 'static> Widget<W>
+    for Gauge<W, V>
+{
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    #[track_caller]
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|ctx| {
+            let style = ctx.get_style(self.style.as_deref());
+
+            Block::from_layout_style(
+                ctx.layout.outer,
+                BlockModel::zero(),
+                style.container,
+            )
+            .render(ctx.renderer)?;
+
+            let inner = ctx.layout.inner;
+            let diameter = inner.size.max_square().width;
+            let top_left = inner.top_left
+                + Point::new(
+                    ((inner.size.width - diameter) / 2) as i32,
+                    ((inner.size.height - diameter) / 2) as i32,
+                );
+            let radius = diameter as f32 / 2.0;
+            let center = top_left + Point::new_equal(diameter as i32 / 2);
+            let angle_at = |fraction: f32| {
+                style.angle_start
+                    + Angle::from_radians(style.angle.to_radians() * fraction)
+            };
+
+            // The ring is stroked on its middle line, inset to stay inside.
+            let ring_top_left =
+                top_left + Point::new_equal(style.track_width as i32 / 2);
+            let ring_diameter = diameter.saturating_sub(style.track_width);
+            if let Some(track) = style.track_color.get() {
+                ctx.renderer.arc(
+                    ring_top_left,
+                    ring_diameter,
+                    style.angle_start,
+                    style.angle,
+                    &style.ring_draw_style(track),
+                )?;
+            }
+            for &(from, to, level) in &self.zones {
+                let (from, to) =
+                    (self.scale.fraction(from), self.scale.fraction(to));
+                if let Some(color) = style.zone_color(level)
+                    && to > from
+                {
+                    ctx.renderer.arc(
+                        ring_top_left,
+                        ring_diameter,
+                        angle_at(from),
+                        angle_at(to) - angle_at(from),
+                        &style.ring_draw_style(color),
+                    )?;
+                }
+            }
+
+            let inside = radius - style.track_width as f32 - 1.0;
+            if let Some(color) = style.tick_color.get()
+                && self.ticks > 0
+            {
+                let tick = DrawStyle::default().stroke(color).stroke_width(1);
+                for index in 0..=self.ticks {
+                    let angle = angle_at(index as f32 / self.ticks as f32);
+                    ctx.renderer.line(
+                        polar(center, inside, angle),
+                        polar(center, inside - style.tick_length as f32, angle),
+                        &tick,
+                    )?;
+                }
+            }
+
+            let value = self.value.get();
+            let text = format!("{value}{}", self.unit);
+            let props = ctx.visual.font_props;
+            let font = props.font();
+            let props = props.resolve(ctx.shared.viewport.get());
+            let text_color =
+                style.text_color.get().unwrap_or_else(W::default_foreground);
+            let fonts = ctx.shared.fonts;
+            fonts
+                .try_with(|fonts| {
+                    let size = fonts.measure_text(
+                        font,
+                        &text,
+                        props,
+                        TextOverflow::Clip,
+                    );
+                    // Under the hub, in the opening of the dial.
+                    let bounds = Rect::new(
+                        Point::new(
+                            center.x - size.max_content_width as i32 / 2,
+                            center.y + (radius / 2.0) as i32
+                                - size.line_height as i32 / 2,
+                        ),
+                        Size::new(size.max_content_width, size.line_height),
+                    );
+                    fonts.render::<W>(
+                        font,
+                        &text,
+                        props,
+                        bounds,
+                        text_color,
+                        ctx.renderer,
+                    )
+                })
+                .unwrap_or(Ok(()))?;
+
+            if let Some(color) = style.needle_color.get() {
+                let angle = angle_at(self.scale.fraction(value.real_point()));
+                ctx.renderer.line(
+                    center,
+                    polar(center, inside - 1.0, angle),
+                    &DrawStyle::default().stroke(color).stroke_width(2),
+                )?;
+                ctx.renderer.circle(
+                    center - Point::new_equal(2),
+                    5,
+                    &DrawStyle::default().fill(color),
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn on_event(&mut self, ctx: EventCtx<'_, W>) -> EventResponse {
+        ctx.ignore()
+    }
+}
@@ -0,0 +1,228 @@
+use super::{
+    gauge::{Scale, ZoneLevel},
+    prelude::*,
+};
+use crate::{render::geometry::*, value::RangeValue};
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+declare_widget_style! {
+    MeterStyle () {
+        container: container,
+        track_color: color {
+            transparent_track_color: transparent,
+        },
+        fill_color: color {
+            transparent_fill_color: transparent,
+        },
+        mark_color: color {
+            transparent_mark_color: transparent,
+        },
+        good_color: color {
+            transparent_good_color: transparent,
+        },
+        warning_color: color {
+            transparent_warning_color: transparent,
+        },
+        critical_color: color {
+            transparent_critical_color: transparent,
+        },
+    }
+}
+
+impl<C: Color> MeterStyle<C> {
+    /// Fill color of a value that crossed a threshold of `level`, or below
+    /// all of them.
+    fn level_color(&self, level: Option<ZoneLevel>) -> Option<C> {
+        match level {
+            None => self.fill_color.get(),
+            Some(ZoneLevel::Good) => self.good_color.get(),
+            Some(ZoneLevel::Warning) => self.warning_color.get(),
+            Some(ZoneLevel::Critical) => self.critical_color.get(),
+        }
+    }
+}
+
+#[derive(Builder)]
+#[builds(Meter<W, V>)]
+pub struct MeterBuilder<W: WidgetCtx, V: RangeValue> {
+    #[widget]
+    layout: Layout,
+    #[widget]
+    value: MaybeReactive<V>,
+    #[widget]
+    axis: Axis,
+    #[widget]
+    scale: Scale,
+    #[widget]
+    thresholds: Vec<(f32, ZoneLevel)>,
+    #[widget]
+    style: WidgetStyleFn<MeterStyle<W::Color>>,
+}
+
+/// A linear level meter: a track filled up to the value, marked and colored by
+/// thresholds.
+///
+/// The fill takes the color of the highest threshold the value reached.
+/// Horizontal meters fill from the left, vertical ones from the bottom.
+///
+/// ```ignore
+/// Meter::vertical(tank_level)
+///     .range(0..=1000u16)
+///     .threshold(800, ZoneLevel::Warning)
+///     .threshold(950, ZoneLevel::Critical)
+/// ```
+pub struct Meter<W: WidgetCtx, V: RangeValue> {
+    layout: Layout,
+    value: MaybeReactive<V>,
+    axis: Axis,
+    scale: Scale,
+    /// Sorted by real point.
+    thresholds: Vec<(f32, ZoneLevel)>,
+    style: WidgetStyleFn<MeterStyle<W::Color>>,
+}
+
+impl<W: WidgetCtx, V: RangeValue + 'static> Meter<W, V> {
+    pub fn vertical(value: impl IntoMaybeReactive<V>) -> MeterBuilder<W, V> {
+        Self::new(Axis::Y, value)
+    }
+
+    pub fn horizontal(value: impl IntoMaybeReactive<V>) -> MeterBuilder<W, V> {
+        Self::new(Axis::X, value)
+    }
+
+    pub fn new(
+        axis: Axis,
+        value: impl IntoMaybeReactive<V>,
+    ) -> MeterBuilder<W, V> {
+        MeterBuilder {
+            layout: Layout::edge(axis.canon(Length::fill(), Length::Fixed(10))),
+            value: value.maybe_reactive(),
+            axis,
+            scale: Scale::FULL,
+            thresholds: Vec::new(),
+            style: None,
+        }
+    }
+}
+
+impl<W: WidgetCtx, V: RangeValue + 'static> MeterBuilder<W, V> {
+    /// Values at the ends of the track.
+    pub fn range(mut self, range: RangeInclusive<V>) -> Self {
+        self.scale = Scale::new(range);
+        self
+    }
+
+    /// Values at or above `at` are at `level`, up to the next threshold.
+    pub fn threshold(mut self, at: V, level: ZoneLevel) -> Self {
+        let at = at.real_point();
+        let index = self
+            .thresholds
+            .partition_point(|&(threshold, _)| threshold <= at);
+        self.thresholds.insert(index, (at, level));
+        self
+    }
+
+    pub fn style(mut self, style: impl StyleFn<MeterStyle<W::Color>>) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+}
+
+impl<W: WidgetCtx, V: RangeValue + 'static> LayoutWidget<W>
+    for MeterBuilder<W, V>
+{
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+impl<W: WidgetCtx, V: RangeValue + 'static> SizedWidget<W>
+    for MeterBuilder<W, V>
+{
+}
+impl<W: WidgetCtx, V: RangeValue + 'static> BlockModelWidget<W>
+    for MeterBuilder<W, V>
+{
+}
+
+impl<W: WidgetCtx, V: RangeValue + 'static> Meter<W, V> {
+    /// Level of the highest threshold `real_point` reached.
+    fn level(&self, real_point: f32) -> Option<ZoneLevel> {
+        self.thresholds
+            .iter()
+            .take_while(|&&(threshold, _)| threshold <= real_point)
+            .last()
+            .map(|&(_, level)| level)
+    }
+
+    /// Part of `track` from its start up to `len` along the main axis.
+    fn filled(&self, track: Rect, len: u32) -> Rect {
+        track.resized_axis(
+            self.axis,
+            len,
+            match self.axis {
+                Axis::X => Anchor::Start,
+                Axis::Y => Anchor::End,
+            },
+        )
+    }
+}
+
+impl<W: WidgetCtx + 'static, V: RangeValue + 'static> Widget<W>
+    for Meter<W, V>
+{
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    #[track_caller]
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|ctx| {
+            let style = ctx.get_style(self.style.as_deref());
+
+            Block::from_layout_style(
+                ctx.layout.outer,
+                self.layout.with(|layout| layout.block_model()),
+                style.container,
+            )
+            .render(ctx.renderer)?;
+
+            let track = ctx.layout.inner;
+            let full_len = track.size.main(self.axis);
+            let point = |fraction: f32| (fraction * full_len as f32) as u32;
+
+            if let Some(color) = style.track_color.get() {
+                ctx.renderer.fill_solid(track, color)?;
+            }
+
+            let value = self.value.get().real_point();
+            if let Some(color) = style.level_color(self.level(value)) {
+                let len = point(self.scale.fraction(value));
+                ctx.renderer.fill_solid(self.filled(track, len), color)?;
+            }
+
+            if let Some(color) = style.mark_color.get() {
+                for &(threshold, _) in &self.thresholds {
+                    let len = point(self.scale.fraction(threshold))
+                        .min(full_len.saturating_sub(1));
+                    // A line across the track at the end of the fill the
+                    // threshold would have.
+                    let filled = self.filled(track, len);
+                    let at = match self.axis {
+                        Axis::X => filled.top_left + Point::new(len as i32, 0),
+                        Axis::Y => filled.top_left - Point::new(0, 1),
+                    };
+                    let across =
+                        self.axis.canon::<Size>(1, track.size.cross(self.axis));
+                    ctx.renderer.fill_solid(Rect::new(at, across), color)?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    fn on_event(&mut self, ctx: EventCtx<'_, W>) -> EventResponse {
+        ctx.ignore()
+    }
+}
@@ -1,23 +1,35 @@
 pub mod bar;
 pub mod button;
 pub mod canvas;
+pub mod chart;
 pub mod checkbox;
 pub mod combinators;
 pub mod container;
 pub mod dynamic;
 pub mod edge;
 pub mod flex;
+pub mod for_each;
+pub mod gauge;
 #[cfg(feature = "tiny-icons")]
 pub mod icon;
 // #[cfg(feature = "embedded-graphics")]
 // pub mod image;
 pub mod knob;
 pub mod label;
+pub mod meter;
+pub mod number_input;
+pub mod qr_code;
+pub mod radio_group;
+pub mod rich_text;
 pub mod scrollable;
+pub mod segment_display;
 pub mod select;
 pub mod show;
 pub mod slider;
 pub mod space;
+pub mod switch;
+pub mod tabs;
+pub mod virtual_list;
 
 use crate::{
     el::{build::BuildCtx, update::UpdateCtx},
@@ -72,6 +84,16 @@ where
     // TODO: Reactive event context? Is it possible?
     fn on_event(&mut self, ctx: EventCtx<'_, W>) -> EventResponse;
     fn render(&self, ctx: RenderCtx<'_, W>) -> RenderResult;
+
+    /// Draw the widget's overlay above the rest of the page. Only called
+    /// while the widget owns the page overlay (see
+    /// [`EventCtx::open_overlay`]), after the tree is rendered, with the
+    /// widget's own layout and the page's default visual. Overlays draw
+    /// directly, without render parts.
+    fn render_overlay(&self, ctx: RenderCtx<'_, W, CtxReady>) -> RenderResult {
+        let _ = ctx;
+        Ok(())
+    }
 }
 
 /// Mutable access to a widget's own [`Layout`], used by the reactive-on-write
@@ -284,7 +306,8 @@ pub mod prelude {
         },
         render::prelude::*,
         style::{
-            StyleFn, StylePseudoClass, WidgetStyleFn, declare_widget_style,
+            SharedStyleFn, StyleFn, StylePseudoClass, WidgetStyleFn,
+            declare_widget_style,
         },
         widget::{
             BlockModelWidget, FontSettingWidget, LayoutWidget, SizedWidget,
@@ -0,0 +1,325 @@
+use super::{label::Label, prelude::*};
+use crate::{layout::LayoutKind, value::StepValue};
+use alloc::string::String;
+use core::fmt::Display;
+use rsact_reactive::timer::now_millis;
+
+/// Turns closer in time than this are fast and build up acceleration.
+const FAST_TURN_MILLIS: u32 = 80;
+
+/// Fast turns in a row that multiply the step by the acceleration once more.
+const TURNS_PER_LEVEL: u32 = 5;
+
+/// How many times the step is multiplied by the acceleration at most.
+const MAX_ACCELERATION_LEVEL: u32 = 2;
+
+declare_widget_style! {
+    NumberInputStyle () {
+        container: container,
+        editing: container {
+            editing_background_color: background_color,
+            editing_border_color: border_color,
+            editing_border_radius: border_radius,
+        },
+    }
+}
+
+/// How the value is shown: `{value}{unit}`, with `precision` digits after the
+/// point for floats.
+#[derive(Clone, Copy, PartialEq, Default)]
+struct NumberFormat {
+    unit: &'static str,
+    precision: Option<usize>,
+}
+
+impl NumberFormat {
+    fn display(&self, value: &impl Display) -> String {
+        match self.precision {
+            Some(precision) => format!("{value:.precision$}{}", self.unit),
+            None => format!("{value}{}", self.unit),
+        }
+    }
+}
+
+/// Recent turns, to accelerate on fast ones.
+#[derive(Clone, Copy, Default)]
+struct Turns {
+    /// Time and direction of the last turn.
+    last: Option<(u32, i32)>,
+    /// Fast turns in a row in the same direction.
+    fast: u32,
+}
+
+#[derive(Builder)]
+#[builds(NumberInput<W, V>)]
+#[flags(hoverable, clickable, focusable)]
+pub struct NumberInputBuilder<W: WidgetCtx, V: StepValue + Display> {
+    #[widget]
+    layout: Layout,
+    #[child(single)]
+    label: El<W>,
+    #[widget]
+    value: Binding<V>,
+    #[widget]
+    min: Option<V>,
+    #[widget]
+    max: Option<V>,
+    #[widget]
+    step: u32,
+    #[widget]
+    acceleration: u32,
+    #[widget]
+    editing: Signal<Option<V>>,
+    #[widget]
+    turns: Turns,
+    #[widget]
+    style: WidgetStyleFn<NumberInputStyle<W::Color>>,
+    // Read by the label only.
+    format: Signal<NumberFormat>,
+}
+
+/// A stepper editing a precise number, like "215°C" or "0.05mm".
+///
+/// Shows the value with its unit. A press or a click starts editing in place:
+/// rotation or the wheel then step the value, faster on fast turns, within
+/// `min` and `max`. Another press keeps the new value, `Exit` restores the one
+/// editing started with.
+///
+/// Steps are in units of the value's [`StepValue::step`]: ones for integers
+/// and `f32`, the last decimal for a [`FixedPoint`].
+///
+/// [`FixedPoint`]: crate::value::FixedPoint
+///
+/// ```ignore
+/// let temperature = create_signal(215u16);
+/// NumberInput::new(temperature).min(0).max(300).step(5).unit("°C")
+/// ```
+pub struct NumberInput<W: WidgetCtx, V: StepValue + Display> {
+    layout: Layout,
+    value: Binding<V>,
+    min: Option<V>,
+    max: Option<V>,
+    step: u32,
+    acceleration: u32,
+    /// The value editing started with, while editing.
+    editing: Signal<Option<V>>,
+    turns: Turns,
+    style: WidgetStyleFn<NumberInputStyle<W::Color>>,
+}
+
+impl<W, V> NumberInput<W, V>
+where
+    W: WidgetCtx + 'static,
+    V: StepValue + Display,
+{
+    pub fn new(value: impl IntoBinding<V>) -> NumberInputBuilder<W, V> {
+        let value = value.binding();
+        let format = create_signal(NumberFormat::default());
+
+        let label = Label::new(
+            value.map(move |value| format.with(|format| format.display(value))),
+        )
+        .into_el();
+
+        let layout = Layout::shrink(LayoutKind::Container(
+            ContainerLayout::base(label.layout())
+                .block_model(BlockModel::zero().border_width(1).padding(2u32)),
+        ));
+
+        NumberInputBuilder {
+            layout,
+            label,
+            value,
+            min: None,
+            max: None,
+            step: 1,
+            acceleration: 10,
+            editing: create_signal(None),
+            turns: Turns::default(),
+            style: None,
+            format,
+        }
+    }
+}
+
+impl<W, V> NumberInputBuilder<W, V>
+where
+    W: WidgetCtx + 'static,
+    V: StepValue + Display,
+{
+    pub fn min(mut self, min: V) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: V) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Change per turn, in units of [`StepValue::step`]. `1` by default.
+    pub fn step(mut self, step: u32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Factor multiplying the step on fast turns, up to twice. `10` by
+    /// default, `1` turns acceleration off.
+    ///
+    /// Fast turns are timed by the UI clock, so acceleration needs
+    /// [`UI::tick_time`](crate::ui::UI::tick_time) to be called.
+    pub fn acceleration(mut self, acceleration: u32) -> Self {
+        self.acceleration = acceleration;
+        self
+    }
+
+    /// Suffix shown after the value.
+    pub fn unit(mut self, unit: &'static str) -> Self {
+        self.format.update_untracked(|format| format.unit = unit);
+        self
+    }
+
+    /// Digits shown after the point, for float values.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.format
+            .update_untracked(|format| format.precision = Some(precision));
+        self
+    }
+
+    pub fn style(
+        mut self,
+        style: impl StyleFn<NumberInputStyle<W::Color>>,
+    ) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+}
+
+impl<W, V> LayoutWidget<W> for NumberInputBuilder<W, V>
+where
+    W: WidgetCtx + 'static,
+    V: StepValue + Display,
+{
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+impl<W, V> SizedWidget<W> for NumberInputBuilder<W, V>
+where
+    W: WidgetCtx + 'static,
+    V: StepValue + Display,
+{
+}
+impl<W, V> BlockModelWidget<W> for NumberInputBuilder<W, V>
+where
+    W: WidgetCtx + 'static,
+    V: StepValue + Display,
+{
+}
+impl<W, V> FontSettingWidget<W> for NumberInputBuilder<W, V>
+where
+    W: WidgetCtx + 'static,
+    V: StepValue + Display,
+{
+}
+
+impl<W, V> NumberInput<W, V>
+where
+    W: WidgetCtx + 'static,
+    V: StepValue + Display,
+{
+    fn turn(&mut self, delta: i32) {
+        let now = now_millis();
+        let direction = delta.signum();
+
+        // The clock reads `0` until it is first ticked: without it every turn
+        // would look fast.
+        let fast = now != 0
+            && self.turns.last.is_some_and(|(last, last_direction)| {
+                last_direction == direction
+                    && now.wrapping_sub(last) <= FAST_TURN_MILLIS
+            });
+        self.turns.fast = if fast { self.turns.fast + 1 } else { 0 };
+        self.turns.last = Some((now, direction));
+
+        let level =
+            (self.turns.fast / TURNS_PER_LEVEL).min(MAX_ACCELERATION_LEVEL);
+        let step = self
+            .step
+            .saturating_mul(self.acceleration.saturating_pow(level))
+            .min(i32::MAX as u32) as i32;
+
+        let current = self.value.get();
+        let mut new = current.step(delta.saturating_mul(step));
+        if let Some(min) = self.min
+            && new < min
+        {
+            new = min;
+        }
+        if let Some(max) = self.max
+            && new > max
+        {
+            new = max;
+        }
+
+        if new != current {
+            self.value.set(new);
+        }
+    }
+}
+
+impl<W, V> Widget<W> for NumberInput<W, V>
+where
+    W: WidgetCtx + 'static,
+    V: StepValue + Display,
+{
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|mut ctx| {
+            let style = ctx.get_style(self.style.as_deref());
+            let editing = self.editing.with(Option::is_some);
+
+            Block::from_layout_style(
+                ctx.layout.outer,
+                self.layout.with(|layout| layout.block_model()),
+                if editing { style.editing } else { style.container },
+            )
+            .render(ctx.renderer)?;
+
+            if editing { Ok(()) } else { ctx.render_focus_outline(ctx.id) }
+        })
+    }
+
+    fn on_event(&mut self, mut ctx: EventCtx<'_, W>) -> EventResponse {
+        if let Some(initial) = self.editing.get()
+            && (ctx.is_focused() || ctx.cursor_in_bounds())
+        {
+            if let Some(delta) = ctx.event.interpret_as_rotation() {
+                self.turn(delta);
+                return ctx.capture();
+            }
+
+            if let Event::Exit = ctx.event {
+                if self.value.get() != initial {
+                    self.value.set(initial);
+                }
+                self.editing.set(None);
+                return ctx.capture();
+            }
+        }
+
+        ctx.handle()?; // hover + press claim + pointer capture (automatic)
+        ctx.handle_click(|ctx| {
+            if self.editing.with(Option::is_some) {
+                self.editing.set(None);
+            } else {
+                self.turns = Turns::default();
+                self.editing.set(Some(self.value.get()));
+            }
+            ctx.capture()
+        })
+    }
+}
@@ -0,0 +1,810 @@
+//! Minimal QR code (model 2) encoder, `no_std` with `alloc`.
+//!
+//! A string is encoded as a single segment in the most compact of the numeric,
+//! alphanumeric and byte modes, into the smallest version that fits at the
+//! requested error correction level, under the mask with the lowest penalty
+//! score. Structured append, ECI and Kanji mode are not supported.
+
+use alloc::vec::Vec;
+
+/// How much of a damaged code can be recovered, at the cost of capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum EcLevel {
+    /// About 7% of codewords.
+    Low,
+    /// About 15% of codewords.
+    #[default]
+    Medium,
+    /// About 25% of codewords.
+    Quartile,
+    /// About 30% of codewords.
+    High,
+}
+
+impl EcLevel {
+    fn index(self) -> usize {
+        match self {
+            EcLevel::Low => 0,
+            EcLevel::Medium => 1,
+            EcLevel::Quartile => 2,
+            EcLevel::High => 3,
+        }
+    }
+
+    /// The two level bits of the format information.
+    fn format_bits(self) -> u32 {
+        match self {
+            EcLevel::Low => 1,
+            EcLevel::Medium => 0,
+            EcLevel::Quartile => 3,
+            EcLevel::High => 2,
+        }
+    }
+}
+
+/// The string does not fit into a version 40 code at the requested level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct DataTooLong;
+
+// Error correction codewords per block and number of blocks, indexed by
+// `EcLevel::index` and version. Version 0 does not exist.
+const ECC_CODEWORDS_PER_BLOCK: [[u8; 41]; 4] = [
+    [
+        0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28,
+        30, 28, 28, 28, 28, 30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+        30, 30, 30, 30, 30,
+    ],
+    [
+        0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28,
+        26, 26, 26, 26, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+        28, 28, 28, 28, 28,
+    ],
+    [
+        0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28,
+        28, 26, 30, 28, 30, 30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+        30, 30, 30, 30, 30,
+    ],
+    [
+        0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28,
+        28, 26, 28, 30, 24, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+        30, 30, 30, 30, 30,
+    ],
+];
+
+const ECC_BLOCKS: [[u8; 41]; 4] = [
+    [
+        0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9,
+        10, 12, 12, 12, 13, 14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25,
+    ],
+    [
+        0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16,
+        17, 17, 18, 20, 21, 23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45,
+        47, 49,
+    ],
+    [
+        0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20,
+        23, 23, 25, 27, 29, 34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62,
+        65, 68,
+    ],
+    [
+        0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25,
+        25, 25, 34, 30, 32, 35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70,
+        74, 77, 81,
+    ],
+];
+
+const ALPHANUMERIC: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Numeric,
+    Alphanumeric,
+    Byte,
+}
+
+impl Mode {
+    fn of(text: &str) -> Self {
+        if text.bytes().all(|byte| byte.is_ascii_digit()) {
+            Mode::Numeric
+        } else if text.bytes().all(|byte| ALPHANUMERIC.contains(&byte)) {
+            Mode::Alphanumeric
+        } else {
+            Mode::Byte
+        }
+    }
+
+    fn indicator(self) -> u32 {
+        match self {
+            Mode::Numeric => 0b0001,
+            Mode::Alphanumeric => 0b0010,
+            Mode::Byte => 0b0100,
+        }
+    }
+
+    /// Length of the character count field.
+    fn count_bits(self, version: u8) -> usize {
+        let range = match version {
+            1..=9 => 0,
+            10..=26 => 1,
+            _ => 2,
+        };
+        match self {
+            Mode::Numeric => [10, 12, 14][range],
+            Mode::Alphanumeric => [9, 11, 13][range],
+            Mode::Byte => [8, 16, 16][range],
+        }
+    }
+
+    /// Length of `count` characters (bytes in byte mode) of encoded data.
+    fn data_bits(self, count: usize) -> usize {
+        match self {
+            Mode::Numeric => count / 3 * 10 + [0, 4, 7][count % 3],
+            Mode::Alphanumeric => count / 2 * 11 + count % 2 * 6,
+            Mode::Byte => count * 8,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Bits(Vec<bool>);
+
+impl Bits {
+    fn push(&mut self, value: u32, len: usize) {
+        self.0
+            .extend((0..len).rev().map(|bit| (value >> bit) & 1 != 0));
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        self.0
+            .chunks(8)
+            .map(|byte| {
+                byte.iter().fold(0, |acc, &bit| (acc << 1) | u8::from(bit))
+            })
+            .collect()
+    }
+}
+
+/// Modules of a version not taken by function patterns, format and version
+/// information: the bits of all codewords, plus a few remainder bits.
+fn raw_data_modules(version: u8) -> usize {
+    let version = version as usize;
+    let mut modules = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let alignments = version / 7 + 2;
+        modules -= (25 * alignments - 10) * alignments - 55;
+        if version >= 7 {
+            modules -= 36;
+        }
+    }
+    modules
+}
+
+fn data_codewords(version: u8, ec_level: EcLevel) -> usize {
+    let level = ec_level.index();
+    let version = version as usize;
+    raw_data_modules(version as u8) / 8
+        - ECC_CODEWORDS_PER_BLOCK[level][version] as usize
+            * ECC_BLOCKS[level][version] as usize
+}
+
+/// Product in GF(2^8) modulo the QR polynomial `x^8 + x^4 + x^3 + x^2 + 1`.
+fn gf_mul(x: u8, y: u8) -> u8 {
+    let mut product = 0u32;
+    for bit in (0..8).rev() {
+        product = (product << 1) ^ ((product >> 7) * 0x11D);
+        product ^= ((y as u32 >> bit) & 1) * x as u32;
+    }
+    product as u8
+}
+
+/// Reed-Solomon generator polynomial of `degree`, highest coefficient (always
+/// 1) dropped.
+fn rs_divisor(degree: usize) -> Vec<u8> {
+    let mut divisor = vec![0; degree];
+    divisor[degree - 1] = 1;
+    let mut root = 1;
+    for _ in 0..degree {
+        for j in 0..degree {
+            divisor[j] = gf_mul(divisor[j], root);
+            if j + 1 < degree {
+                divisor[j] ^= divisor[j + 1];
+            }
+        }
+        root = gf_mul(root, 0x02);
+    }
+    divisor
+}
+
+fn rs_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut remainder = vec![0; divisor.len()];
+    for &byte in data {
+        let factor = byte ^ remainder.remove(0);
+        remainder.push(0);
+        for (rem, &coef) in remainder.iter_mut().zip(divisor) {
+            *rem ^= gf_mul(coef, factor);
+        }
+    }
+    remainder
+}
+
+/// Split `data` into blocks, append error correction to each and interleave
+/// them into the final codeword sequence.
+fn add_ecc_and_interleave(
+    data: &[u8],
+    version: u8,
+    ec_level: EcLevel,
+) -> Vec<u8> {
+    let level = ec_level.index();
+    let blocks = ECC_BLOCKS[level][version as usize] as usize;
+    let ecc_len = ECC_CODEWORDS_PER_BLOCK[level][version as usize] as usize;
+    let raw_codewords = raw_data_modules(version) / 8;
+    // Blocks are `short_len` long, the last `raw_codewords % blocks` one
+    // data codeword longer.
+    let short_blocks = blocks - raw_codewords % blocks;
+    let short_len = raw_codewords / blocks;
+
+    let divisor = rs_divisor(ecc_len);
+    let mut at = 0;
+    let blocks = (0..blocks)
+        .map(|index| {
+            let data_len =
+                short_len - ecc_len + usize::from(index >= short_blocks);
+            let mut block = data[at..at + data_len].to_vec();
+            at += data_len;
+            let ecc = rs_remainder(&block, &divisor);
+            if index < short_blocks {
+                // Padding keeping columns aligned, skipped when interleaving.
+                block.push(0);
+            }
+            block.extend(ecc);
+            block
+        })
+        .collect::<Vec<_>>();
+
+    let mut codewords = Vec::with_capacity(raw_codewords);
+    for column in 0..=short_len {
+        for (index, block) in blocks.iter().enumerate() {
+            if column != short_len - ecc_len || index >= short_blocks {
+                codewords.push(block[column]);
+            }
+        }
+    }
+    codewords
+}
+
+/// A code under construction, with the function modules kept apart from the
+/// data modules masks apply to.
+struct Canvas {
+    size: usize,
+    modules: Vec<bool>,
+    function: Vec<bool>,
+}
+
+impl Canvas {
+    fn new(version: u8) -> Self {
+        let size = version as usize * 4 + 17;
+        Self {
+            size,
+            modules: vec![false; size * size],
+            function: vec![false; size * size],
+        }
+    }
+
+    fn get(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+
+    fn set_function(&mut self, x: i32, y: i32, dark: bool) {
+        let index = y as usize * self.size + x as usize;
+        self.modules[index] = dark;
+        self.function[index] = true;
+    }
+
+    fn draw_function_patterns(&mut self, version: u8, ec_level: EcLevel) {
+        let size = self.size as i32;
+
+        for at in 0..size {
+            self.set_function(6, at, at % 2 == 0);
+            self.set_function(at, 6, at % 2 == 0);
+        }
+
+        // Finders, with their separators.
+        for (x, y) in [(3, 3), (size - 4, 3), (3, size - 4)] {
+            for dy in -4i32..=4 {
+                for dx in -4i32..=4 {
+                    let (x, y) = (x + dx, y + dy);
+                    if (0..size).contains(&x) && (0..size).contains(&y) {
+                        let ring = dx.abs().max(dy.abs());
+                        self.set_function(x, y, ring != 2 && ring != 4);
+                    }
+                }
+            }
+        }
+
+        let alignments = alignment_positions(version);
+        let last = alignments.len().saturating_sub(1);
+        for (i, &x) in alignments.iter().enumerate() {
+            for (j, &y) in alignments.iter().enumerate() {
+                // Corners taken by finders.
+                if (i, j) == (0, 0)
+                    || (i, j) == (0, last)
+                    || (i, j) == (last, 0)
+                {
+                    continue;
+                }
+                for dy in -2i32..=2 {
+                    for dx in -2i32..=2 {
+                        let ring = dx.abs().max(dy.abs());
+                        self.set_function(x + dx, y + dy, ring != 1);
+                    }
+                }
+            }
+        }
+
+        // Reserved now, drawn for real with the chosen mask.
+        self.draw_format_bits(ec_level, 0);
+
+        if version >= 7 {
+            let version = version as u32;
+            let mut rem = version;
+            for _ in 0..12 {
+                rem = (rem << 1) ^ ((rem >> 11) * 0x1F25);
+            }
+            let bits = version << 12 | rem;
+            for i in 0..18 {
+                let dark = (bits >> i) & 1 != 0;
+                let a = size - 11 + i % 3;
+                let b = i / 3;
+                self.set_function(a, b, dark);
+                self.set_function(b, a, dark);
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, ec_level: EcLevel, mask: u8) {
+        let size = self.size as i32;
+        let data = ec_level.format_bits() << 3 | mask as u32;
+        let mut rem = data;
+        for _ in 0..10 {
+            rem = (rem << 1) ^ ((rem >> 9) * 0x537);
+        }
+        let bits = (data << 10 | rem) ^ 0x5412;
+        let bit = |i: i32| (bits >> i) & 1 != 0;
+
+        // Around the top left finder.
+        for i in 0..=5 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+
+        // Split between the other two finders.
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        // Always dark.
+        self.set_function(8, size - 8, true);
+    }
+
+    /// Place codeword bits in the zigzag of two module wide columns, from
+    /// the bottom right corner, skipping function modules.
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let size = self.size;
+        let mut bit = 0;
+        let mut right = size - 1;
+        while right >= 1 {
+            // The vertical timing pattern is never part of a column pair.
+            if right == 6 {
+                right = 5;
+            }
+            for vertical in 0..size {
+                for x in [right, right - 1] {
+                    let upward = (right + 1) & 2 == 0;
+                    let y = if upward { size - 1 - vertical } else { vertical };
+                    let index = y * size + x;
+                    if !self.function[index] && bit < codewords.len() * 8 {
+                        self.modules[index] =
+                            (codewords[bit / 8] >> (7 - bit % 8)) & 1 != 0;
+                        bit += 1;
+                    }
+                }
+            }
+            if right < 2 {
+                break;
+            }
+            right -= 2;
+        }
+    }
+
+    /// XOR data modules with `mask`, so applying it twice undoes it.
+    fn apply_mask(&mut self, mask: u8) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                let index = y * self.size + x;
+                self.modules[index] ^= invert && !self.function[index];
+            }
+        }
+    }
+
+    /// Penalty score of the current modules: long runs, 2x2 blocks,
+    /// finder-like patterns and dark/light imbalance.
+    fn penalty(&self) -> u32 {
+        let size = self.size;
+        let mut penalty = 0;
+
+        for transposed in [false, true] {
+            for line in 0..size {
+                let module = |at: usize| {
+                    if transposed {
+                        self.get(line, at)
+                    } else {
+                        self.get(at, line)
+                    }
+                };
+                let mut history = RunHistory::new(size);
+                let mut run_dark = false;
+                let mut run = 0;
+                for at in 0..size {
+                    if module(at) == run_dark {
+                        run += 1;
+                        if run == 5 {
+                            penalty += 3;
+                        } else if run > 5 {
+                            penalty += 1;
+                        }
+                    } else {
+                        history.push(run);
+                        if !run_dark {
+                            penalty += history.finder_like() * 40;
+                        }
+                        run_dark = module(at);
+                        run = 1;
+                    }
+                }
+                penalty += history.terminate(run_dark, run) * 40;
+            }
+        }
+
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let dark = self.get(x, y);
+                if dark == self.get(x + 1, y)
+                    && dark == self.get(x, y + 1)
+                    && dark == self.get(x + 1, y + 1)
+                {
+                    penalty += 3;
+                }
+            }
+        }
+
+        let total = (size * size) as i32;
+        let dark = self.modules.iter().filter(|&&dark| dark).count() as i32;
+        // Each 5% of deviation from half dark, rounded up, past the first.
+        let k = ((dark * 20 - total * 10).abs() + total - 1) / total - 1;
+        penalty + k as u32 * 10
+    }
+}
+
+/// Centers of alignment patterns along either axis, ascending.
+fn alignment_positions(version: u8) -> Vec<i32> {
+    if version == 1 {
+        return Vec::new();
+    }
+    let version = version as i32;
+    let count = version / 7 + 2;
+    let step = (version * 8 + count * 3 + 5) / (count * 4 - 4) * 2;
+    let size = version * 4 + 17;
+    let mut positions = (0..count - 1)
+        .map(|i| size - 7 - i * step)
+        .collect::<Vec<_>>();
+    positions.push(6);
+    positions.reverse();
+    positions
+}
+
+/// Lengths of the last seven runs of a line, for finder-like patterns
+/// (dark:light:dark:light:dark = 1:1:3:1:1 with light space around).
+struct RunHistory {
+    size: usize,
+    runs: [usize; 7],
+}
+
+impl RunHistory {
+    fn new(size: usize) -> Self {
+        Self { size, runs: [0; 7] }
+    }
+
+    fn push(&mut self, mut run: usize) {
+        // The light border before the line counts as part of the first run.
+        if self.runs[0] == 0 {
+            run += self.size;
+        }
+        self.runs.copy_within(0..6, 1);
+        self.runs[0] = run;
+    }
+
+    /// Finder-like patterns ending with the last pushed run, at most two.
+    fn finder_like(&self) -> u32 {
+        let runs = &self.runs;
+        let n = runs[1];
+        let core = n > 0
+            && runs[2] == n
+            && runs[3] == n * 3
+            && runs[4] == n
+            && runs[5] == n;
+        u32::from(core && runs[0] >= n * 4 && runs[6] >= n)
+            + u32::from(core && runs[6] >= n * 4 && runs[0] >= n)
+    }
+
+    /// Finish the line with the light border after it.
+    fn terminate(mut self, run_dark: bool, mut run: usize) -> u32 {
+        if run_dark {
+            self.push(run);
+            run = 0;
+        }
+        self.push(run + self.size);
+        self.finder_like()
+    }
+}
+
+/// An encoded QR code: a square of dark and light modules, without the quiet
+/// zone around it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrMatrix {
+    size: u32,
+    modules: Vec<bool>,
+}
+
+impl QrMatrix {
+    /// Encode `text` into the smallest code that fits it at `ec_level`.
+    pub fn encode(text: &str, ec_level: EcLevel) -> Result<Self, DataTooLong> {
+        let mode = Mode::of(text);
+        // All characters of numeric and alphanumeric text are ASCII, so the
+        // count is the byte count in every mode.
+        let count = text.len();
+        // Empty text is encoded as no segment at all rather than an empty one.
+        let header =
+            |count_bits| if text.is_empty() { 0 } else { 4 + count_bits };
+
+        let (version, capacity, count_bits) = (1..=40)
+            .find_map(|version| {
+                let capacity = data_codewords(version, ec_level) * 8;
+                let count_bits = mode.count_bits(version);
+                let used = header(count_bits) + mode.data_bits(count);
+                (count < 1 << count_bits && used <= capacity)
+                    .then_some((version, capacity, count_bits))
+            })
+            .ok_or(DataTooLong)?;
+
+        let mut bits = Bits::default();
+        if !text.is_empty() {
+            bits.push(mode.indicator(), 4);
+            bits.push(count as u32, count_bits);
+        }
+        let bytes = text.as_bytes();
+        match mode {
+            Mode::Numeric => {
+                for digits in bytes.chunks(3) {
+                    let value = digits.iter().fold(0, |value, digit| {
+                        value * 10 + (digit - b'0') as u32
+                    });
+                    bits.push(value, digits.len() * 3 + 1);
+                }
+            },
+            Mode::Alphanumeric => {
+                let value = |char: &u8| {
+                    ALPHANUMERIC.iter().position(|c| c == char).unwrap_or(0)
+                        as u32
+                };
+                for pair in bytes.chunks(2) {
+                    match pair {
+                        [first, second] => {
+                            bits.push(value(first) * 45 + value(second), 11)
+                        },
+                        [single] => bits.push(value(single), 6),
+                        _ => unreachable!(),
+                    }
+                }
+            },
+            Mode::Byte => {
+                for &byte in bytes {
+                    bits.push(byte as u32, 8);
+                }
+            },
+        }
+
+        // Terminator, byte alignment, then alternating pad bytes.
+        bits.push(0, (capacity - bits.len()).min(4));
+        bits.push(0, (8 - bits.len() % 8) % 8);
+        for pad in [0xEC, 0x11].into_iter().cycle() {
+            if bits.len() >= capacity {
+                break;
+            }
+            bits.push(pad, 8);
+        }
+
+        let codewords =
+            add_ecc_and_interleave(&bits.into_bytes(), version, ec_level);
+
+        let mut canvas = Canvas::new(version);
+        canvas.draw_function_patterns(version, ec_level);
+        canvas.draw_codewords(&codewords);
+
+        let mut best = (u32::MAX, 0);
+        for mask in 0..8 {
+            canvas.apply_mask(mask);
+            canvas.draw_format_bits(ec_level, mask);
+            best = best.min((canvas.penalty(), mask));
+            canvas.apply_mask(mask);
+        }
+        let mask = best.1;
+        canvas.apply_mask(mask);
+        canvas.draw_format_bits(ec_level, mask);
+
+        Ok(Self { size: canvas.size as u32, modules: canvas.modules })
+    }
+
+    /// Width and height in modules, from 21 for version 1 to 177 for
+    /// version 40.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Whether the module at column `x` and row `y` is dark. Modules outside
+    /// of the code are light.
+    pub fn is_dark(&self, x: u32, y: u32) -> bool {
+        x < self.size
+            && y < self.size
+            && self.modules[(y * self.size + x) as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    fn size(text: &str, ec_level: EcLevel) -> u32 {
+        QrMatrix::encode(text, ec_level).unwrap().size()
+    }
+
+    #[test]
+    fn picks_smallest_version_for_mode() {
+        // Capacities of version 1 at the medium level.
+        assert_eq!(size(&"7".repeat(34), EcLevel::Medium), 21);
+        assert_eq!(size(&"7".repeat(35), EcLevel::Medium), 25);
+        assert_eq!(size(&"A".repeat(20), EcLevel::Medium), 21);
+        assert_eq!(size(&"A".repeat(21), EcLevel::Medium), 25);
+        assert_eq!(size(&"a".repeat(14), EcLevel::Medium), 21);
+        assert_eq!(size(&"a".repeat(15), EcLevel::Medium), 25);
+        // Higher levels trade capacity for recovery.
+        assert_eq!(size(&"a".repeat(14), EcLevel::High), 25);
+    }
+
+    #[test]
+    fn too_long_for_version_40() {
+        let text: String = "a".repeat(2953);
+        assert_eq!(size(&text, EcLevel::Low), 177);
+        assert_eq!(
+            QrMatrix::encode(&(text + "a"), EcLevel::Low),
+            Err(DataTooLong)
+        );
+    }
+
+    #[test]
+    fn finders_and_format_information() {
+        for ec_level in
+            [EcLevel::Low, EcLevel::Medium, EcLevel::Quartile, EcLevel::High]
+        {
+            let code = QrMatrix::encode("HELLO WORLD", ec_level).unwrap();
+            let size = code.size();
+
+            // Finder corners and centers, and their light separators.
+            for (x, y) in [(0, 0), (size - 7, 0), (0, size - 7)] {
+                assert!(code.is_dark(x, y) && code.is_dark(x + 3, y + 3));
+                assert!(!code.is_dark(x + 1, y + 1));
+            }
+            assert!(!code.is_dark(7, 7));
+
+            // Both copies of the format information agree on the level.
+            let first = (0..15).fold(0, |bits, i| {
+                let (x, y) = match i {
+                    0..=5 => (8, i),
+                    6 => (8, 7),
+                    7 => (8, 8),
+                    8 => (7, 8),
+                    _ => (14 - i, 8),
+                };
+                bits | (code.is_dark(x, y) as u32) << i
+            });
+            let second = (0..15).fold(0, |bits, i| {
+                let (x, y) =
+                    if i < 8 { (size - 1 - i, 8) } else { (8, size - 15 + i) };
+                bits | (code.is_dark(x, y) as u32) << i
+            });
+            assert_eq!(first, second);
+            assert_eq!((first ^ 0x5412) >> 13, ec_level.format_bits());
+            assert!(code.is_dark(8, size - 8));
+        }
+    }
+
+    /// The well-known "HELLO WORLD" 1-Q worked example, a single block.
+    #[test]
+    fn hello_world_codewords() {
+        let data = [32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236];
+        let ecc = [168, 72, 22, 82, 217, 54, 156, 0, 46, 15, 180, 122, 16];
+        assert_eq!(
+            add_ecc_and_interleave(&data, 1, EcLevel::Quartile),
+            [&data[..], &ecc[..]].concat()
+        );
+    }
+
+    /// "HELLO WORLD" at the quartile level as Nayuki's reference encoder
+    /// draws it, mask 0 included.
+    #[test]
+    fn hello_world_matrix() {
+        const EXPECTED: [&str; 21] = [
+            "#######.##....#######",
+            "#.....#.#..#..#.....#",
+            "#.###.#.#..##.#.###.#",
+            "#.###.#.#.....#.###.#",
+            "#.###.#.#.#...#.###.#",
+            "#.....#...#...#.....#",
+            "#######.#.#.#.#######",
+            "........#............",
+            ".##.#.##....#.#.#####",
+            ".#......####....#...#",
+            "..##.###.##...#.##...",
+            ".##.##.#..##.#.#.###.",
+            "#...#.#.#.###.###.#.#",
+            "........##.#..#...#.#",
+            "#######.#.#....#.##..",
+            "#.....#..#.##.##.#...",
+            "#.###.#.#.#...#######",
+            "#.###.#..#.#.#.#...#.",
+            "#.###.#.#..#.###.#..#",
+            "#.....#.#.####...#.##",
+            "#######....#.###....#",
+        ];
+
+        let code = QrMatrix::encode("HELLO WORLD", EcLevel::Quartile).unwrap();
+        let rows = (0..code.size())
+            .map(|y| {
+                (0..code.size())
+                    .map(|x| if code.is_dark(x, y) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(rows, EXPECTED);
+    }
+
+    #[test]
+    fn outside_is_light() {
+        let code = QrMatrix::encode("", EcLevel::default()).unwrap();
+        assert_eq!(code.size(), 21);
+        assert!(!code.is_dark(21, 0) && !code.is_dark(0, 21));
+    }
+}
@@ -0,0 +1,250 @@
+mod encode;
+
+use super::prelude::*;
+use crate::layout::length::LengthSize;
+pub use encode::{DataTooLong, EcLevel, QrMatrix};
+
+declare_widget_style! {
+    QrCodeStyle () {
+        container: container,
+        // Scanners expect dark modules on a light background, whatever the
+        // theme of the rest of the UI.
+        dark_color: color {
+            transparent_dark_color: transparent,
+        } = ColorStyle::DefaultForeground,
+        // Quiet zone and light modules.
+        light_color: color {
+            transparent_light_color: transparent,
+        } = ColorStyle::DefaultBackground,
+    }
+}
+
+#[derive(Builder)]
+#[builds(QrCode<W>)]
+pub struct QrCodeBuilder<W: WidgetCtx> {
+    #[widget]
+    layout: Layout,
+    #[widget]
+    matrix: Memo<Result<QrMatrix, DataTooLong>>,
+    #[widget]
+    quiet_zone: u32,
+    #[widget]
+    style: WidgetStyleFn<QrCodeStyle<W::Color>>,
+    ec_level: Signal<EcLevel>,
+}
+
+/// A QR code of a string, drawn with square modules of the largest integer
+/// scale that fits the layout, centered in it.
+///
+/// The string is encoded again only when it changes. A string too long for
+/// the largest code at the error correction level draws nothing.
+///
+/// ```ignore
+/// QrCode::new(pairing_url).ec_level(EcLevel::Quartile).size(96u32)
+/// ```
+pub struct QrCode<W: WidgetCtx> {
+    layout: Layout,
+    matrix: Memo<Result<QrMatrix, DataTooLong>>,
+    quiet_zone: u32,
+    style: WidgetStyleFn<QrCodeStyle<W::Color>>,
+}
+
+impl<W: WidgetCtx + 'static> QrCode<W> {
+    pub fn new(content: impl IntoMaybeReactive<String>) -> QrCodeBuilder<W> {
+        // Setting a signal to an equal string still notifies, the memo in
+        // between keeps that from encoding it again.
+        let content = content.maybe_reactive().map(String::clone);
+        let ec_level = create_signal(EcLevel::default());
+
+        let matrix = create_memo(move || {
+            content.with(|content| {
+                QrMatrix::encode(content, ec_level.get()).inspect_err(|_| {
+                    log::warn!(
+                        "QR code of {} bytes does not fit, nothing is drawn",
+                        content.len()
+                    )
+                })
+            })
+        });
+
+        QrCodeBuilder {
+            layout: Layout::edge(LengthSize::new_equal(Length::fill())),
+            matrix,
+            quiet_zone: 4,
+            style: None,
+            ec_level,
+        }
+    }
+}
+
+impl<W: WidgetCtx + 'static> QrCodeBuilder<W> {
+    /// Error correction level, [`EcLevel::Medium`] by default. Higher levels
+    /// survive more damage but need more modules for the same string.
+    pub fn ec_level(mut self, ec_level: EcLevel) -> Self {
+        self.ec_level.set(ec_level);
+        self
+    }
+
+    /// Light margin around the code, in modules. `4` by default, as the
+    /// standard requires; a light background around the widget allows less.
+    pub fn quiet_zone(mut self, modules: u32) -> Self {
+        self.quiet_zone = modules;
+        self
+    }
+
+    pub fn style(mut self, style: impl StyleFn<QrCodeStyle<W::Color>>) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+}
+
+impl<W: WidgetCtx> LayoutWidget<W> for QrCodeBuilder<W> {
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+
+impl<W: WidgetCtx> SizedWidget<W> for QrCodeBuilder<W> {}
+
+/// Pixels per module and the top left corner of the code with its quiet zone
+/// of `modules` per side, centered in `bounds`. The scale is `0` if the code
+/// does not fit.
+fn placement(bounds: Rect, modules: u32) -> (u32, Point) {
+    let side = bounds.size.max_square().width;
+    let scale = side / modules.max(1);
+    let extent = scale * modules;
+    let top_left = bounds.top_left
+        + Point::new(
+            ((bounds.size.width - extent) / 2) as i32,
+            ((bounds.size.height - extent) / 2) as i32,
+        );
+
+    (scale, top_left)
+}
+
+impl<W: WidgetCtx> Widget<W> for QrCode<W> {
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    #[track_caller]
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|ctx| {
+            let style = ctx.get_style(self.style.as_deref());
+
+            Block::from_layout_style(
+                ctx.layout.outer,
+                BlockModel::zero(),
+                style.container,
+            )
+            .render(ctx.renderer)?;
+
+            self.matrix.with(|matrix| {
+                let Ok(matrix) = matrix else {
+                    return Ok(());
+                };
+
+                let modules = matrix.size() + self.quiet_zone * 2;
+                let (scale, top_left) = placement(ctx.layout.inner, modules);
+                if scale == 0 {
+                    return Ok(());
+                }
+
+                if let Some(light) = style.light_color.get() {
+                    ctx.renderer.fill_solid(
+                        Rect::new(top_left, Size::new_equal(scale * modules)),
+                        light,
+                    )?;
+                }
+
+                let Some(dark) = style.dark_color.get() else {
+                    return Ok(());
+                };
+                let origin = top_left
+                    + Point::new_equal((self.quiet_zone * scale) as i32);
+
+                // One fill per horizontal run of dark modules.
+                for y in 0..matrix.size() {
+                    let mut x = 0;
+                    while x < matrix.size() {
+                        if !matrix.is_dark(x, y) {
+                            x += 1;
+                            continue;
+                        }
+
+                        let start = x;
+                        while matrix.is_dark(x, y) {
+                            x += 1;
+                        }
+                        ctx.renderer.fill_solid(
+                            Rect::new(
+                                origin
+                                    + Point::new(
+                                        (start * scale) as i32,
+                                        (y * scale) as i32,
+                                    ),
+                                Size::new((x - start) * scale, scale),
+                            ),
+                            dark,
+                        )?;
+                    }
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    fn on_event(&mut self, ctx: EventCtx<'_, W>) -> EventResponse {
+        ctx.ignore()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsact_render::renderer::NullRenderer;
+
+    #[test]
+    fn placement_takes_largest_scale_that_fits_and_centers() {
+        // 21 modules and a quiet zone of 4 on each side.
+        let (scale, top_left) =
+            placement(Rect::new(Point::new(10, 20), Size::new(100, 64)), 29);
+        assert_eq!(scale, 2);
+        assert_eq!(top_left, Point::new(10 + 21, 20 + 3));
+
+        assert_eq!(
+            placement(Rect::new(Point::zero(), Size::new_equal(28)), 29).0,
+            0
+        );
+    }
+
+    #[test]
+    fn matrix_changes_only_with_string() {
+        use rsact_reactive::runtime::with_new_runtime;
+
+        with_new_runtime(|_| {
+            let mut content = create_signal(String::from("1"));
+            let qr = QrCode::<Wtf<NullRenderer, (), (), ()>>::new(content);
+            let matrix = qr.matrix;
+
+            let mut runs = 0;
+            let seen = create_memo(move || {
+                matrix.with(|_| ());
+                runs += 1;
+                runs
+            });
+            assert_eq!(seen.get(), 1);
+            assert_eq!(matrix.with(|m| m.as_ref().unwrap().size()), 21);
+
+            // An equal string leaves the code as it is.
+            content.set(String::from("1"));
+            assert_eq!(seen.get(), 1);
+
+            // 100 bytes take version 6 at the medium level.
+            content.set("x".repeat(100));
+            assert_eq!(seen.get(), 2);
+            assert_eq!(matrix.with(|m| m.as_ref().unwrap().size()), 41);
+        });
+    }
+}
@@ -0,0 +1,282 @@
+use super::{label::Label, select::SelectOption};
+use crate::{layout::LayoutKind, widget::prelude::*};
+use core::marker::PhantomData;
+use rsact_reactive::prelude::*;
+
+/// Diameter of an option's ring, smaller if the label is lower.
+const RING_SIZE: u32 = 10;
+
+/// Space between an option's ring and its label.
+const RING_GAP: u32 = 4;
+
+declare_widget_style! {
+    RadioGroupStyle () {
+        container: container,
+        option: container {
+            option_background_color: background_color,
+            option_border_color: border_color,
+            option_border_radius: border_radius,
+        },
+        ring_color: color {
+            transparent_ring_color: transparent,
+        } = ColorStyle::DefaultForeground,
+        dot_color: color {
+            transparent_dot_color: transparent,
+        } = ColorStyle::DefaultForeground,
+    }
+}
+
+/// One option of a [`RadioGroup`]: a ring, filled while the option is the
+/// selected one, and the option's label.
+///
+/// Options are what is focused and clicked. Like `Select`'s options they
+/// read whether they are selected through a [`Selector`], so changing the
+/// selection redraws the previous and the new option only. Like `Tabs`' cells
+/// they call the group's style function with their own state.
+#[derive(Builder)]
+#[builds(RadioOption<W, K>)]
+#[flags(hoverable, clickable, focusable)]
+struct RadioOptionBuilder<W: WidgetCtx, K: PartialEq + Clone + 'static> {
+    #[widget]
+    layout: Layout,
+    #[child(single)]
+    label: El<W>,
+    #[widget]
+    key: K,
+    #[widget]
+    index: usize,
+    #[widget]
+    value: Binding<K>,
+    #[widget]
+    selector: Selector<Option<usize>>,
+    #[widget]
+    style: SharedStyleFn<RadioGroupStyle<W::Color>>,
+    #[widget]
+    ctx: PhantomData<W>,
+}
+
+struct RadioOption<W: WidgetCtx, K: PartialEq + Clone + 'static> {
+    layout: Layout,
+    key: K,
+    index: usize,
+    value: Binding<K>,
+    selector: Selector<Option<usize>>,
+    style: SharedStyleFn<RadioGroupStyle<W::Color>>,
+    // Same as `Flex`: `W` is otherwise unused on the retained widget.
+    ctx: PhantomData<W>,
+}
+
+impl<W: WidgetCtx, K: PartialEq + Clone + 'static> RadioOption<W, K> {
+    fn new(
+        option: SelectOption<W, K>,
+        index: usize,
+        value: Binding<K>,
+        selector: Selector<Option<usize>>,
+        style: SharedStyleFn<RadioGroupStyle<W::Color>>,
+    ) -> RadioOptionBuilder<W, K> {
+        let label = Label::new(String::from(option.label()).inert()).into_el();
+
+        let mut container = ContainerLayout::base(label.layout()).block_model(
+            BlockModel::zero().padding(Padding::new(
+                1,
+                2,
+                1,
+                2 + RING_SIZE + RING_GAP,
+            )),
+        );
+        container.vertical_align = Align::Center;
+
+        RadioOptionBuilder {
+            layout: Layout::shrink(LayoutKind::Container(container)),
+            label,
+            key: option.key().clone(),
+            index,
+            value,
+            selector,
+            style,
+            ctx: PhantomData,
+        }
+    }
+}
+
+impl<W: WidgetCtx, K: PartialEq + Clone + 'static> Widget<W>
+    for RadioOption<W, K>
+{
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|mut ctx| {
+            let style = ctx.get_style(self.style.borrow().as_deref());
+            let outer = ctx.layout.outer;
+
+            Block::from_layout_style(outer, BlockModel::zero(), style.option)
+                .render(ctx.renderer)?;
+
+            ctx.render_focus_outline(ctx.id)?;
+
+            let diameter = RING_SIZE.min(outer.size.height.saturating_sub(2));
+            let ring = outer.top_left
+                + Point::new(2, ((outer.size.height - diameter) / 2) as i32);
+
+            if let Some(color) = style.ring_color.get() {
+                ctx.renderer.circle(
+                    ring,
+                    diameter,
+                    &DrawStyle::default().stroke(color).stroke_width(1),
+                )?;
+            }
+
+            if self.selector.is_selected(&Some(self.index))
+                && let Some(color) = style.dot_color.get()
+            {
+                let dot = diameter / 2;
+                ctx.renderer.circle(
+                    ring + Point::new_equal(((diameter - dot) / 2) as i32),
+                    dot,
+                    &DrawStyle::default().fill(color),
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn on_event(&mut self, mut ctx: EventCtx<'_, W>) -> EventResponse {
+        ctx.handle()?; // hover + press claim + pointer capture (automatic)
+        ctx.handle_click(|ctx| {
+            if self.value.with(|value| *value != self.key) {
+                self.value.set(self.key.clone());
+            }
+            ctx.capture()
+        })
+    }
+}
+
+#[derive(Builder)]
+#[builds(RadioGroup<W>)]
+pub struct RadioGroupBuilder<W: WidgetCtx> {
+    #[widget]
+    layout: Layout,
+    #[children(reactive)]
+    options: MaybeSignal<Vec<El<W>>>,
+    #[widget]
+    style: SharedStyleFn<RadioGroupStyle<W::Color>>,
+}
+
+/// Exclusive choice between labeled options, bound to a signal of the chosen
+/// option's key.
+///
+/// Each option is focusable and clickable on its own; choosing one sets the
+/// signal. The signal may hold a key none of the options has, then no option
+/// is marked.
+///
+/// ```ignore
+/// RadioGroup::vertical(
+///     mode,
+///     vec![
+///         SelectOption::labeled(Mode::Auto, "Auto"),
+///         SelectOption::labeled(Mode::Manual, "Manual"),
+///     ],
+/// )
+/// ```
+pub struct RadioGroup<W: WidgetCtx> {
+    layout: Layout,
+    style: SharedStyleFn<RadioGroupStyle<W::Color>>,
+}
+
+impl<W: WidgetCtx + 'static> RadioGroup<W> {
+    pub fn vertical<K: PartialEq + Clone + 'static>(
+        value: impl IntoBinding<K>,
+        options: impl IntoIterator<Item = SelectOption<W, K>>,
+    ) -> RadioGroupBuilder<W> {
+        Self::new(Axis::Y, value, options)
+    }
+
+    pub fn horizontal<K: PartialEq + Clone + 'static>(
+        value: impl IntoBinding<K>,
+        options: impl IntoIterator<Item = SelectOption<W, K>>,
+    ) -> RadioGroupBuilder<W> {
+        Self::new(Axis::X, value, options)
+    }
+
+    pub fn new<K: PartialEq + Clone + 'static>(
+        axis: Axis,
+        value: impl IntoBinding<K>,
+        options: impl IntoIterator<Item = SelectOption<W, K>>,
+    ) -> RadioGroupBuilder<W> {
+        let value = value.binding();
+        let options = options.into_iter().collect::<Vec<_>>();
+
+        let keys = options
+            .iter()
+            .map(|option| option.key().clone())
+            .collect::<Vec<_>>();
+        let selector = create_selector(
+            value.map(move |value| keys.iter().position(|key| key == value)),
+        );
+
+        let style = SharedStyleFn::default();
+        let options = options
+            .into_iter()
+            .enumerate()
+            .map(|(index, option)| {
+                RadioOption::new(option, index, value, selector, style.clone())
+                    .into_el()
+            })
+            .collect::<Vec<_>>();
+        let options_layout = MaybeReactive::new_inert(
+            options.iter().map(|option| option.layout()).collect(),
+        );
+
+        RadioGroupBuilder {
+            layout: Layout::shrink(LayoutKind::Flex(
+                FlexLayout::base(axis, options_layout)
+                    .gap(axis.canon(2, 0))
+                    .align_cross(Align::Start),
+            )),
+            options: MaybeSignal::new_inert(options),
+            style,
+        }
+    }
+}
+
+impl<W: WidgetCtx + 'static> RadioGroupBuilder<W> {
+    pub fn style(self, style: impl StyleFn<RadioGroupStyle<W::Color>>) -> Self {
+        *self.style.borrow_mut() = Some(Box::new(style));
+        self
+    }
+}
+
+impl<W: WidgetCtx + 'static> LayoutWidget<W> for RadioGroupBuilder<W> {
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+impl<W: WidgetCtx + 'static> SizedWidget<W> for RadioGroupBuilder<W> {}
+impl<W: WidgetCtx + 'static> BlockModelWidget<W> for RadioGroupBuilder<W> {}
+impl<W: WidgetCtx + 'static> FontSettingWidget<W> for RadioGroupBuilder<W> {}
+
+impl<W: WidgetCtx + 'static> Widget<W> for RadioGroup<W> {
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|ctx| {
+            let style = ctx.get_style(self.style.borrow().as_deref());
+
+            Block::from_layout_style(
+                ctx.layout.outer,
+                self.layout.with(|layout| layout.block_model()),
+                style.container,
+            )
+            .render(ctx.renderer)
+        })
+    }
+
+    fn on_event(&mut self, ctx: EventCtx<'_, W>) -> EventResponse {
+        ctx.ignore()
+    }
+}
@@ -0,0 +1,272 @@
+use super::{FontSettingWidget, prelude::*};
+use crate::font::{
+    TextHorizontalAlign, TextRun,
+    measure::{self, FlowBreak},
+};
+use layout::ContentLayout;
+#[cfg(feature = "tiny-icons")]
+use rsact_tiny_icons::IconRaw;
+
+declare_widget_style! {
+    RichTextStyle () {
+        text_color: color {
+            transparent: transparent
+        },
+        horizontal_align: TextHorizontalAlign = TextHorizontalAlign::Left,
+    }
+}
+
+#[derive(Clone, PartialEq)]
+enum SpanContent {
+    Text(String),
+    #[cfg(feature = "tiny-icons")]
+    Icon(IconRaw),
+}
+
+/// A span of a [`RichText`] paragraph: text, or an inline icon, in its own
+/// font and color.
+///
+/// Font properties and the color left unset are the paragraph's.
+#[derive(Clone, PartialEq)]
+pub struct TextSpan<C: Color> {
+    content: SpanContent,
+    font_props: FontProps,
+    color: Option<C>,
+}
+
+impl<C: Color> TextSpan<C> {
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: SpanContent::Text(content.into()),
+            font_props: FontProps::default(),
+            color: None,
+        }
+    }
+
+    /// An icon sitting on the line like a word. Whitespace in the text spans
+    /// around separates them from it.
+    #[cfg(feature = "tiny-icons")]
+    pub fn icon(icon: IconRaw) -> Self {
+        Self {
+            content: SpanContent::Icon(icon),
+            font_props: FontProps::default(),
+            color: None,
+        }
+    }
+
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font_props.font = Some(font.into());
+        self
+    }
+
+    /// Note that font size does nothing for fixed size fonts.
+    pub fn font_size(mut self, font_size: impl Into<FontSize>) -> Self {
+        self.font_props.font_size = Some(font_size.into());
+        self
+    }
+
+    pub fn font_style(mut self, font_style: FontStyle) -> Self {
+        self.font_props.font_style = Some(font_style);
+        self
+    }
+
+    pub fn color(mut self, color: C) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    fn run(&self) -> TextRun {
+        match &self.content {
+            SpanContent::Text(content) => TextRun::Text {
+                content: content.clone(),
+                font_props: self.font_props,
+            },
+            #[cfg(feature = "tiny-icons")]
+            SpanContent::Icon(icon) => TextRun::Box(Size::new_equal(icon.size)),
+        }
+    }
+}
+
+impl<C: Color> From<&str> for TextSpan<C> {
+    fn from(content: &str) -> Self {
+        Self::new(content)
+    }
+}
+
+impl<C: Color> From<String> for TextSpan<C> {
+    fn from(content: String) -> Self {
+        Self::new(content)
+    }
+}
+
+#[derive(Builder)]
+#[builds(RichText<W>)]
+pub struct RichTextBuilder<W: WidgetCtx> {
+    #[widget]
+    spans: MaybeReactive<Vec<TextSpan<W::Color>>>,
+    #[widget]
+    runs: MaybeReactive<Vec<TextRun>>,
+    #[widget]
+    layout: Layout,
+    #[widget]
+    style: WidgetStyleFn<RichTextStyle<W::Color>>,
+}
+
+/// A paragraph of differently styled spans, wrapped as a whole.
+///
+/// Lines break at whitespace only, so a word split between spans, like a
+/// value and its unit, stays together. Spans on a line share its bottom edge.
+///
+/// ```ignore
+/// RichText::new(vec![
+///     "Temp: ".into(),
+///     TextSpan::new("215°C").font_style(FontStyle::Bold).color(Rgb888::RED),
+///     " (target 220)".into(),
+/// ])
+/// ```
+pub struct RichText<W: WidgetCtx> {
+    spans: MaybeReactive<Vec<TextSpan<W::Color>>>,
+    /// What layout measures of `spans`, kept to wrap them the same way.
+    runs: MaybeReactive<Vec<TextRun>>,
+    layout: Layout,
+    style: WidgetStyleFn<RichTextStyle<W::Color>>,
+}
+
+impl<W: WidgetCtx> RichText<W> {
+    pub fn new(
+        spans: impl IntoMaybeReactive<Vec<TextSpan<W::Color>>>,
+    ) -> RichTextBuilder<W> {
+        let spans = spans.maybe_reactive();
+        let runs = spans.map(|spans| spans.iter().map(TextSpan::run).collect());
+
+        // Shrink like `Label`: the paragraph hugs its widest line and wraps
+        // once clamped to the available width.
+        let layout = Layout::shrink(LayoutKind::Content(ContentLayout::rich(
+            runs.clone(),
+        )));
+
+        RichTextBuilder { spans, runs, layout, style: None }
+    }
+}
+
+impl<W: WidgetCtx> RichTextBuilder<W> {
+    pub fn style(
+        mut self,
+        style: impl StyleFn<RichTextStyle<W::Color>>,
+    ) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+}
+
+impl<W: WidgetCtx> LayoutWidget<W> for RichTextBuilder<W> {
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+
+/// Font settings of the builder apply to spans that do not set their own.
+impl<W: WidgetCtx> FontSettingWidget<W> for RichTextBuilder<W> {}
+
+impl<W: WidgetCtx> Widget<W> for RichText<W> {
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    #[track_caller]
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|mut ctx| {
+            let style = ctx.get_style(self.style.as_deref());
+            let parent = ctx.visual.font_props;
+            let viewport = ctx.shared.viewport.get();
+            let inner = ctx.layout.inner;
+            let text_color =
+                style.text_color.get().unwrap_or_else(W::default_foreground);
+
+            // Same as `RenderCtx::render_font`: skip rather than panic if the
+            // font provider is gone.
+            let Some(pieces) = ctx.shared.fonts.try_with(|fonts| {
+                self.runs
+                    .with(|runs| fonts.flow_pieces(runs, &parent, viewport))
+            }) else {
+                log::error!("text render skipped: font provider was disposed");
+                return Ok(());
+            };
+
+            self.spans.with(|spans| {
+                let mut top = inner.top_left.y;
+                for line in measure::flow_lines(&pieces, inner.size.width) {
+                    let free = inner.size.width.saturating_sub(line.width);
+                    let mut left = inner.top_left.x
+                        + match style.horizontal_align {
+                            TextHorizontalAlign::Left => 0,
+                            TextHorizontalAlign::Center => free / 2,
+                            TextHorizontalAlign::Right => free,
+                        } as i32;
+
+                    for (index, piece) in
+                        pieces[line.pieces.clone()].iter().enumerate()
+                    {
+                        // Spaces a line wrapped at are not drawn.
+                        if let FlowBreak::Space(gap) = piece.before
+                            && index != 0
+                        {
+                            left += gap as i32;
+                        }
+
+                        let span = &spans[piece.run];
+                        let color = span.color.unwrap_or(text_color);
+                        let top_left = Point::new(
+                            left,
+                            top + (line.height - piece.height) as i32,
+                        );
+
+                        match &span.content {
+                            SpanContent::Text(content) => {
+                                if !piece.text.is_empty() {
+                                    let props =
+                                        span.font_props.inherited(&parent);
+                                    ctx.render_font(
+                                        props.font(),
+                                        &content[piece.text.clone()],
+                                        props.resolve(viewport),
+                                        Rect::new(
+                                            top_left,
+                                            Size::new(
+                                                piece.width,
+                                                piece.height,
+                                            ),
+                                        ),
+                                        color,
+                                    )?;
+                                }
+                            },
+                            #[cfg(feature = "tiny-icons")]
+                            SpanContent::Icon(icon) => {
+                                let icon = rsact_tiny_icons::Icon::new(
+                                    *icon,
+                                    top_left,
+                                    None,
+                                    Some(color),
+                                );
+                                for pixel in icon.iter() {
+                                    ctx.renderer.pixel(pixel.0, pixel.1)?;
+                                }
+                            },
+                        }
+
+                        left += piece.width as i32;
+                    }
+
+                    top += line.height as i32;
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    fn on_event(&mut self, ctx: EventCtx<'_, W>) -> EventResponse {
+        ctx.ignore()
+    }
+}
@@ -0,0 +1,452 @@
+use super::prelude::*;
+use crate::{layout::length::LengthSize, render::geometry::*};
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+// Segment bits, in the usual fourteen-segment order. Seven-segment cells use
+// `A`..`F` and both halves of the middle bar.
+const A: u16 = 1 << 0;
+const B: u16 = 1 << 1;
+const C: u16 = 1 << 2;
+const D: u16 = 1 << 3;
+const E: u16 = 1 << 4;
+const F: u16 = 1 << 5;
+const G1: u16 = 1 << 6;
+const G2: u16 = 1 << 7;
+/// Top-left diagonal.
+const H: u16 = 1 << 8;
+/// Top vertical.
+const J: u16 = 1 << 9;
+/// Top-right diagonal.
+const K: u16 = 1 << 10;
+/// Bottom-left diagonal.
+const L: u16 = 1 << 11;
+/// Bottom vertical.
+const M: u16 = 1 << 12;
+/// Bottom-right diagonal.
+const N: u16 = 1 << 13;
+/// Decimal point.
+const DP: u16 = 1 << 14;
+
+const SEVEN_SEGMENTS: u16 = A | B | C | D | E | F | G1 | G2 | DP;
+const FOURTEEN_SEGMENTS: u16 = SEVEN_SEGMENTS | H | J | K | L | M | N;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SegmentKind {
+    /// Digits and a few letters.
+    Seven,
+    /// Digits and the whole Latin alphabet.
+    Fourteen,
+}
+
+impl SegmentKind {
+    fn segments(&self) -> u16 {
+        match self {
+            SegmentKind::Seven => SEVEN_SEGMENTS,
+            SegmentKind::Fourteen => FOURTEEN_SEGMENTS,
+        }
+    }
+
+    /// Segments lit for `char`, blank for characters the kind cannot show.
+    fn mask(&self, char: char) -> u16 {
+        match self {
+            SegmentKind::Seven => seven_segment_mask(char),
+            SegmentKind::Fourteen => {
+                fourteen_segment_mask(char.to_ascii_uppercase())
+            },
+        }
+    }
+}
+
+fn seven_segment_mask(char: char) -> u16 {
+    let mask: u16 = match char {
+        '0' | 'O' => 0x3F,
+        '1' | 'I' => 0x06,
+        '2' | 'Z' => 0x5B,
+        '3' => 0x4F,
+        '4' => 0x66,
+        '5' | 'S' | 's' => 0x6D,
+        '6' => 0x7D,
+        '7' => 0x07,
+        '8' | 'B' => 0x7F,
+        '9' | 'g' => 0x6F,
+        'A' | 'a' => 0x77,
+        'b' => 0x7C,
+        'C' => 0x39,
+        'c' => 0x58,
+        'd' | 'D' => 0x5E,
+        'E' | 'e' => 0x79,
+        'F' | 'f' => 0x71,
+        'G' => 0x3D,
+        'H' => 0x76,
+        'h' => 0x74,
+        'i' => 0x04,
+        'J' | 'j' => 0x1E,
+        'L' | 'l' => 0x38,
+        'n' | 'N' => 0x54,
+        'o' => 0x5C,
+        'P' | 'p' => 0x73,
+        'r' | 'R' => 0x50,
+        't' | 'T' => 0x78,
+        'U' => 0x3E,
+        'u' => 0x1C,
+        'y' | 'Y' => 0x6E,
+        '-' => 0x40,
+        '_' => 0x08,
+        '=' => 0x48,
+        '°' => 0x63,
+        _ => 0,
+    };
+
+    // Seven-segment bit 6 is the whole middle bar.
+    (mask & 0x3F) | if mask & 0x40 != 0 { G1 | G2 } else { 0 }
+}
+
+fn fourteen_segment_mask(char: char) -> u16 {
+    match char {
+        '0' => 0x0C3F,
+        '1' => 0x0006,
+        '2' => 0x00DB,
+        '3' => 0x008F,
+        '4' => 0x00E6,
+        '5' => 0x2069,
+        '6' => 0x00FD,
+        '7' => 0x0007,
+        '8' => 0x00FF,
+        '9' => 0x00EF,
+        'A' => 0x00F7,
+        'B' => 0x128F,
+        'C' => 0x0039,
+        'D' => 0x120F,
+        'E' => 0x00F9,
+        'F' => 0x0071,
+        'G' => 0x00BD,
+        'H' => 0x00F6,
+        'I' => 0x1209,
+        'J' => 0x001E,
+        'K' => 0x2470,
+        'L' => 0x0038,
+        'M' => 0x0536,
+        'N' => 0x2136,
+        'O' => 0x003F,
+        'P' => 0x00F3,
+        'Q' => 0x203F,
+        'R' => 0x20F3,
+        'S' => 0x018D,
+        'T' => 0x1201,
+        'U' => 0x003E,
+        'V' => 0x0C30,
+        'W' => 0x2836,
+        'X' => 0x2D00,
+        'Y' => 0x1500,
+        'Z' => 0x0C09,
+        '-' => 0x00C0,
+        '+' => 0x12C0,
+        '*' => 0x3FC0,
+        '/' => 0x0C00,
+        '_' => 0x0008,
+        '=' => 0x00C8,
+        '°' => 0x00E3,
+        _ => 0,
+    }
+}
+
+/// Segment masks of the cells showing `text`. A `.` lights the point of the
+/// cell before it instead of taking its own cell.
+fn cells(text: &str, kind: SegmentKind) -> Vec<u16> {
+    let mut cells: Vec<u16> = Vec::new();
+    for char in text.chars() {
+        match cells.last_mut() {
+            Some(last) if char == '.' && *last & DP == 0 => *last |= DP,
+            _ if char == '.' => cells.push(DP),
+            _ => cells.push(kind.mask(char)),
+        }
+    }
+    cells
+}
+
+/// A segment of a cell: straight ones are bars, diagonals are lines.
+enum Segment {
+    Bar(Rect),
+    Slash(Point, Point),
+}
+
+/// Segments of a cell in `cell` with segments `thickness` pixels thick, and
+/// the bit of each.
+fn segments(cell: Rect, thickness: u32) -> [(u16, Segment); 15] {
+    let t = thickness as i32;
+    let Point { x, y } = cell.top_left;
+    let (w, h) = (cell.size.width as i32, cell.size.height as i32);
+    // Top of the middle bar and the center column.
+    let middle = y + (h - t) / 2;
+    let center = x + w / 2;
+    let half = (w - 2 * t) / 2;
+
+    let bar = |x: i32, y: i32, w: i32, h: i32| {
+        Segment::Bar(Rect::new(
+            Point::new(x, y),
+            Size::new(w.max(0) as u32, h.max(0) as u32),
+        ))
+    };
+    let slash = |from: (i32, i32), to: (i32, i32)| {
+        Segment::Slash(Point::new(from.0, from.1), Point::new(to.0, to.1))
+    };
+
+    [
+        (A, bar(x + t, y, w - 2 * t, t)),
+        (B, bar(x + w - t, y + t, t, middle - y - t)),
+        (C, bar(x + w - t, middle + t, t, y + h - t - middle - t)),
+        (D, bar(x + t, y + h - t, w - 2 * t, t)),
+        (E, bar(x, middle + t, t, y + h - t - middle - t)),
+        (F, bar(x, y + t, t, middle - y - t)),
+        (G1, bar(x + t, middle, half, t)),
+        (G2, bar(x + t + half, middle, w - 2 * t - half, t)),
+        (H, slash((x + t, y + t), (center - t / 2 - 1, middle - 1))),
+        (J, bar(center - t / 2, y + t, t, middle - y - t)),
+        (K, slash((x + w - t - 1, y + t), (center + t / 2 + 1, middle - 1))),
+        (L, slash((x + t, y + h - t - 1), (center - t / 2 - 1, middle + t))),
+        (M, bar(center - t / 2, middle + t, t, y + h - t - middle - t)),
+        (
+            N,
+            slash(
+                (x + w - t - 1, y + h - t - 1),
+                (center + t / 2 + 1, middle + t),
+            ),
+        ),
+        // In the gap after the cell.
+        (DP, bar(x + w + 1, y + h - t, t, t)),
+    ]
+}
+
+declare_widget_style! {
+    SegmentDisplayStyle () {
+        container: container,
+        on_color: color {
+            transparent_on_color: transparent,
+        },
+        // Unlit segments, like the ghost segments of a real display.
+        off_color: color {
+            transparent_off_color: transparent,
+        },
+        thickness: u32 = 3,
+    }
+}
+
+#[derive(Builder)]
+#[builds(SegmentDisplay<W, V>)]
+pub struct SegmentDisplayBuilder<W: WidgetCtx, V: Display + PartialEq + 'static>
+{
+    #[widget]
+    layout: Layout,
+    #[widget]
+    value: MaybeReactive<V>,
+    #[widget]
+    kind: SegmentKind,
+    #[widget]
+    digits: u32,
+    #[widget]
+    digit_size: Size,
+    #[widget]
+    style: WidgetStyleFn<SegmentDisplayStyle<W::Color>>,
+}
+
+/// A numeric display of seven- or fourteen-segment cells, like the ones on
+/// instrument panels.
+///
+/// The value is shown right-aligned in `digits` cells, cut on the right if
+/// it is longer. Points light the decimal point of the cell before them.
+///
+/// ```ignore
+/// SegmentDisplay::seven(voltage.map(|v| format!("{v:.2}"))).digits(5)
+/// ```
+pub struct SegmentDisplay<W: WidgetCtx, V: Display + PartialEq + 'static> {
+    layout: Layout,
+    value: MaybeReactive<V>,
+    kind: SegmentKind,
+    digits: u32,
+    digit_size: Size,
+    style: WidgetStyleFn<SegmentDisplayStyle<W::Color>>,
+}
+
+/// Gap between cells, by cell width.
+fn cell_gap(digit_size: Size) -> u32 {
+    digit_size.width / 3
+}
+
+fn display_size(digits: u32, digit_size: Size) -> LengthSize {
+    let gap = cell_gap(digit_size);
+    LengthSize::fixed_length(
+        (digit_size.width + gap) * digits,
+        digit_size.height,
+    )
+}
+
+impl<W: WidgetCtx, V: Display + PartialEq + 'static> SegmentDisplay<W, V> {
+    pub fn seven(
+        value: impl IntoMaybeReactive<V>,
+    ) -> SegmentDisplayBuilder<W, V> {
+        Self::new(SegmentKind::Seven, value)
+    }
+
+    pub fn fourteen(
+        value: impl IntoMaybeReactive<V>,
+    ) -> SegmentDisplayBuilder<W, V> {
+        Self::new(SegmentKind::Fourteen, value)
+    }
+
+    pub fn new(
+        kind: SegmentKind,
+        value: impl IntoMaybeReactive<V>,
+    ) -> SegmentDisplayBuilder<W, V> {
+        let digits = 4;
+        let digit_size = Size::new(12, 20);
+        SegmentDisplayBuilder {
+            layout: Layout::edge(display_size(digits, digit_size)),
+            value: value.maybe_reactive(),
+            kind,
+            digits,
+            digit_size,
+            style: None,
+        }
+    }
+}
+
+impl<W: WidgetCtx, V: Display + PartialEq + 'static>
+    SegmentDisplayBuilder<W, V>
+{
+    /// Number of cells, `4` by default.
+    pub fn digits(mut self, digits: u32) -> Self {
+        self.digits = digits;
+        self.update_size();
+        self
+    }
+
+    /// Size of a cell, `12x20` by default.
+    pub fn digit_size(mut self, width: u32, height: u32) -> Self {
+        self.digit_size = Size::new(width, height);
+        self.update_size();
+        self
+    }
+
+    pub fn style(
+        mut self,
+        style: impl StyleFn<SegmentDisplayStyle<W::Color>>,
+    ) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+
+    fn update_size(&mut self) {
+        let size = display_size(self.digits, self.digit_size);
+        self.layout.update_untracked(|layout| layout.size = size);
+    }
+}
+
+impl<W: WidgetCtx, V: Display + PartialEq + 'static> LayoutWidget<W>
+    for SegmentDisplayBuilder<W, V>
+{
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+
+impl<W: WidgetCtx, V: Display + PartialEq + 'static> Widget<W>
+    for SegmentDisplay<W, V>
+{
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    #[track_caller]
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|ctx| {
+            let style = ctx.get_style(self.style.as_deref());
+
+            Block::from_layout_style(
+                ctx.layout.outer,
+                BlockModel::zero(),
+                style.container,
+            )
+            .render(ctx.renderer)?;
+
+            let mut cells = self
+                .value
+                .with(|value| cells(&format!("{value}"), self.kind));
+            cells.truncate(self.digits as usize);
+            let blank = self.digits as usize - cells.len();
+
+            let on = style.on_color.get();
+            let off = style.off_color.get();
+            let step =
+                (self.digit_size.width + cell_gap(self.digit_size)) as i32;
+            let line_width = (style.thickness / 2).max(1);
+
+            for index in 0..self.digits as usize {
+                let mask =
+                    index.checked_sub(blank).map_or(0, |index| cells[index]);
+                let cell = Rect::new(
+                    ctx.layout.inner.top_left
+                        + Point::new(step * index as i32, 0),
+                    self.digit_size,
+                );
+
+                for (bit, segment) in segments(cell, style.thickness) {
+                    if bit & self.kind.segments() == 0 {
+                        continue;
+                    }
+                    let Some(color) = (if mask & bit != 0 { on } else { off })
+                    else {
+                        continue;
+                    };
+                    match segment {
+                        Segment::Bar(rect) => {
+                            ctx.renderer.fill_solid(rect, color)?
+                        },
+                        Segment::Slash(from, to) => ctx.renderer.line(
+                            from,
+                            to,
+                            &DrawStyle::default()
+                                .stroke(color)
+                                .stroke_width(line_width),
+                        )?,
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    fn on_event(&mut self, ctx: EventCtx<'_, W>) -> EventResponse {
+        ctx.ignore()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_light_the_cell_before_them() {
+        assert_eq!(
+            cells("12.5", SegmentKind::Seven),
+            [0x06, 0x5B & 0x3F | G1 | G2 | DP, 0x6D & 0x3F | G1 | G2]
+        );
+        assert_eq!(cells(".5.", SegmentKind::Seven), [DP, 0x2D | G1 | G2 | DP]);
+        assert_eq!(cells("1..", SegmentKind::Seven), [0x06 | DP, DP]);
+    }
+
+    #[test]
+    fn fourteen_segments_spell_letters_in_any_case() {
+        assert_eq!(
+            cells("Ok", SegmentKind::Fourteen),
+            [0x003F, K | N | E | F | G1]
+        );
+        assert_eq!(cells("?", SegmentKind::Fourteen), [0]);
+        // Seven segments cannot show a `K` but can show a `b`.
+        assert_eq!(
+            cells("Kb", SegmentKind::Seven),
+            [0, C | D | E | F | G1 | G2]
+        );
+    }
+}
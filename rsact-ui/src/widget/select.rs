@@ -1,11 +1,7 @@
 use super::{container::Container, label::Label};
-use crate::{
-    declare_widget_style,
-    layout::{LayoutKind, model::LayoutModelNode},
-    widget::prelude::*,
-};
-use alloc::{rc::Rc, string::ToString};
-use core::fmt::Display;
+use crate::{declare_widget_style, layout::LayoutKind, widget::prelude::*};
+use alloc::string::{String, ToString};
+use core::{fmt::Display, marker::PhantomData};
 use rsact_reactive::prelude::*;
 
 #[derive(Clone, Copy)]
@@ -20,28 +16,6 @@ impl SelectState {
     pub fn initial(selected: Option<usize>) -> Self {
         Self { active: false, selected }
     }
-
-    fn options_offset(
-        &self,
-        inner: Rect,
-        children_layouts: &[LayoutModelNode<'_>],
-    ) -> (Point, Option<usize>) {
-        // Prefer the selected option, but fall back to the first if the stored
-        // index is stale: reactive `options` can shrink below `selected`
-        // between an `on_event` clamp and the next render, so a bare
-        // `.get(selected).unwrap()` would panic on the render path.
-        if let Some((selected, layout)) = self.selected.and_then(|selected| {
-            children_layouts
-                .get(selected)
-                .map(|layout| (selected, layout))
-        }) {
-            (inner.center_offset_of(layout.inner), Some(selected))
-        } else if let Some(first_option) = children_layouts.first() {
-            (inner.center_offset_of(first_option.inner), None)
-        } else {
-            (Point::zero(), None)
-        }
-    }
 }
 
 declare_widget_style! {
@@ -61,9 +35,12 @@ declare_widget_style! {
     }
 }
 
+/// An option of a [`Select`] (or of a drop-down built on it): the option's key
+/// and the label shown for it.
 pub struct SelectOption<W: WidgetCtx, K: PartialEq> {
     key: K,
-    el: El<W>,
+    label: String,
+    ctx: PhantomData<W>,
 }
 
 impl<W: WidgetCtx, K: PartialEq> SelectOption<W, K> {
@@ -71,13 +48,28 @@ impl<W: WidgetCtx, K: PartialEq> SelectOption<W, K> {
     where
         K: Display,
     {
-        let string = key.to_string();
-        SelectOption {
-            key,
-            el: Container::new(Label::new(string.inert()).into_el())
-                .padding(5u32)
-                .into_el(),
-        }
+        let label = key.to_string();
+        Self::labeled(key, label)
+    }
+
+    /// Option shown as `label` rather than by its key's [`Display`].
+    pub fn labeled(key: K, label: impl Into<String>) -> Self {
+        SelectOption { key, label: label.into(), ctx: PhantomData }
+    }
+
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// The option's element inside a [`Select`]: its padded label.
+    pub fn el(&self) -> El<W> {
+        Container::new(Label::new(self.label.clone().inert()).into_el())
+            .padding(5u32)
+            .into_el()
     }
 }
 
@@ -87,42 +79,108 @@ impl<W: WidgetCtx, K: PartialEq> PartialEq for SelectOption<W, K> {
     }
 }
 
-// WS13.4 (Task 5.13): mechanical split like Label/Edge/Bar/Slider/Knob —
-// `layout`/`state`/`style`/`options` are ALL read by `render`/`on_event`
-// post-build, so there is no build-only field to drop (a `size_of` `<`
-// assertion would be false, not true, same as those widgets' fallback
-// shape test). Unlike Button/Container/Flex/Scrollable, `Select` never
-// registers any arena children via `ctx.set_single_child`/
-// `ctx.set_children` in the first place — each `SelectOption::el` lives
-// only inside the `options` `Rc` and is iterated manually in
-// `render_part` (rendering it is itself `TODO(unimplemented)` below), so
-// there is no `#[child]`/`#[children]` field on `SelectBuilder` either;
-// `Build::build` here is a pure by-name field move with zero child
-// wiring, same shape as Label/Edge/Bar.
+/// One option of a [`Select`]: the option's element plus the highlight drawn
+/// behind it while it is the selected one.
+///
+/// The highlight is read through [`Selector::is_selected`], so the option's
+/// render probe depends on its own index only: a selection change redraws the
+/// previously and the newly selected options, not the whole `Select`.
+///
+/// Its highlight is styled by the `Select`'s style fn with the select's
+/// `active` state, which the option's render probe then depends on.
+#[derive(Builder)]
+#[builds(SelectOptionCell<W>)]
+struct SelectOptionCellBuilder<W: WidgetCtx> {
+    #[widget]
+    layout: Layout,
+    #[child(single)]
+    content: El<W>,
+    #[widget]
+    index: usize,
+    #[widget]
+    selector: Selector<Option<usize>>,
+    #[widget]
+    active: Memo<bool>,
+    #[widget]
+    style: SharedStyleFn<SelectStyle<W::Color>>,
+}
+
+struct SelectOptionCell<W: WidgetCtx> {
+    layout: Layout,
+    index: usize,
+    selector: Selector<Option<usize>>,
+    active: Memo<bool>,
+    style: SharedStyleFn<SelectStyle<W::Color>>,
+}
+
+impl<W: WidgetCtx> SelectOptionCell<W> {
+    fn new(
+        content: El<W>,
+        index: usize,
+        selector: Selector<Option<usize>>,
+        active: Memo<bool>,
+        style: SharedStyleFn<SelectStyle<W::Color>>,
+        axis: Axis,
+    ) -> SelectOptionCellBuilder<W> {
+        // Fill the cross axis, so the highlight spans the whole `Select`.
+        let layout = Layout::new(
+            LayoutKind::Container(ContainerLayout::base(content.layout())),
+            axis.canon(Length::Shrink, Length::fill()),
+        );
+
+        SelectOptionCellBuilder {
+            layout,
+            content,
+            index,
+            selector,
+            active,
+            style,
+        }
+    }
+}
+
+impl<W: WidgetCtx> Widget<W> for SelectOptionCell<W> {
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|ctx| {
+            if !self.selector.is_selected(&Some(self.index)) {
+                return Ok(());
+            }
+
+            let style = ctx.get_style_for(
+                ctx.pseudoclass().active(self.active.get()),
+                self.style.borrow().as_deref(),
+            );
+            Block::from_layout_style(
+                ctx.layout.outer,
+                BlockModel::zero().border_width(1),
+                style.selected,
+            )
+            .render(ctx.renderer)
+        })
+    }
+
+    fn on_event(&mut self, ctx: EventCtx<'_, W>) -> EventResponse {
+        ctx.ignore()
+    }
+}
+
+// WS13.4 (Task 5.13): split like Label/Edge/Bar/Slider/Knob. The options are
+// real arena children (one `SelectOptionCell` per option, wired by
+// `#[children(reactive)]` like `Flex`'s), rebuilt whenever the reactive option
+// list changes. `options` on the retained `Select` keeps only the keys, read by
+// `on_event` to clamp the selection and by the `selected` setter effect.
 //
-// `Dir: Direction` is de-genericized into a runtime `axis: Axis` field,
-// same 7.2 rule as Bar/Slider/Scrollable: `render` reads `Dir::AXIS`
-// (`.inverted()`/cross-size) for the selected-option highlight box, not
-// just `new()`'s one-shot layout construction, so it can't be dropped
-// like `Space`'s ctor-only tag.
+// Highlighting goes through a `Selector` over `state.selected` rather than
+// being drawn by `Select` itself: drawing it in `Select`'s own render part
+// would make every selection change redraw the `Select` and therefore all of
+// its options (a dirty parent forces its children to redraw).
 //
 // `K: PartialEq` stays generic — it's `SelectOption`'s key type, with no
 // canonical concrete choice, same call as Bar's undecided `V`.
-//
-// WS4.1/A18 (`docs/plans/2026-07-05-rsact-evolution-roadmap.md`'s A18
-// backlog row, maintainer: "looks horrible"): the
-// `options: Rc<MaybeReactive<Vec<SelectOption<W, K>>>>` storage and the
-// `selected.setter(...)` reactive-effect wiring below (in `new()`) are
-// UNTOUCHED by this split — both are constructed exactly as before,
-// still inside `new()` on the "effect path" (not deferred into
-// `Build::build`'s generated child-wiring), just now assembled into a
-// `SelectBuilder` literal instead of a bare `Select`; the derive's
-// by-name `#[widget]` move only relocates the already-built `Rc`
-// handle/effect closures, it does not rebuild or rewire them. This is a
-// pure identity-preserving mechanical split (assessment (a) of the
-// 5.13 checklist row) — it does not touch, and does not attempt to fix,
-// the A18 entanglement (options storage / selected-wiring awkwardness),
-// which stays exactly as backlogged as before.
 #[derive(Builder)]
 #[builds(Select<W, K>)]
 #[flags(focusable)]
@@ -132,23 +190,24 @@ pub struct SelectBuilder<W: WidgetCtx, K: PartialEq + 'static> {
     #[widget]
     state: Signal<SelectState>,
     #[widget]
-    style: WidgetStyleFn<SelectStyle<W::Color>>,
-    // TODO: Can we do fixed size?
-    // WS4.1: `Rc` because `MaybeReactive<Vec<SelectOption>>` lost blanket `Copy`
-    // (SelectOption holds an `El` — neither Copy nor Clone) yet is read by both
-    // the `selected` setter effect and this widget. See `Select::new`.
+    style: SharedStyleFn<SelectStyle<W::Color>>,
     #[widget]
-    options: Rc<MaybeReactive<Vec<SelectOption<W, K>>>>,
+    active: Memo<bool>,
+    #[children(reactive)]
+    cells: MaybeSignal<Vec<El<W>>>,
+    // TODO: Can we do fixed size?
     #[widget]
-    axis: Axis,
+    options: MaybeReactive<Vec<K>>,
 }
 
 pub struct Select<W: WidgetCtx, K: PartialEq + 'static> {
     layout: Layout,
     state: Signal<SelectState>,
-    style: WidgetStyleFn<SelectStyle<W::Color>>,
-    options: Rc<MaybeReactive<Vec<SelectOption<W, K>>>>,
-    axis: Axis,
+    style: SharedStyleFn<SelectStyle<W::Color>>,
+    /// `state.active` alone, so that a selection change does not redraw the
+    /// `Select`.
+    active: Memo<bool>,
+    options: MaybeReactive<Vec<K>>,
 }
 
 impl<W: WidgetCtx, K> Select<W, K>
@@ -157,82 +216,92 @@ where
 {
     pub fn vertical(
         selected: impl IntoMaybeSignal<K>,
-        options: impl SignalMapRefMaybeReactive<[K], Vec<SelectOption<W, K>>>
-        + PartialEq,
+        options: impl SignalMapRefMaybeReactive<[K], Vec<K>> + PartialEq,
     ) -> SelectBuilder<W, K> {
         Self::new(Axis::Y, selected, options)
     }
 
     pub fn horizontal(
         selected: impl IntoMaybeSignal<K>,
-        options: impl SignalMapRefMaybeReactive<[K], Vec<SelectOption<W, K>>>,
+        options: impl SignalMapRefMaybeReactive<[K], Vec<K>>,
     ) -> SelectBuilder<W, K> {
         Self::new(Axis::X, selected, options)
     }
 
-    // TODO: Inert options?
-    // TODO: MaybeReactive options
     pub fn new(
         axis: Axis,
         selected: impl IntoMaybeSignal<K>,
-        options: impl SignalMapRefMaybeReactive<[K], Vec<SelectOption<W, K>>>,
+        options: impl SignalMapRefMaybeReactive<[K], Vec<K>>,
     ) -> SelectBuilder<W, K> {
-        let options = options.map_ref_maybe_reactive(|options| {
-            options
-                .into_iter()
-                .cloned()
-                .map(|opt| SelectOption::new(opt))
-                .collect::<Vec<_>>()
-        });
-        // WS4.1: `MaybeReactive<Vec<SelectOption>>` is no longer `Copy` (its
-        // `SelectOption`s hold `El`s — neither `Copy` nor `Clone`), yet the
-        // options are read from two long-lived owners: the `selected` setter
-        // effect and the widget itself (render/on_event). Share them via `Rc`.
-        let options = Rc::new(options);
+        let options =
+            options.map_ref_maybe_reactive(|options| options.to_vec());
 
         let mut selected = selected.maybe_signal();
 
-        // TODO: This maybe-reactive optimization not working, as when selected
-        // is inert, it is then converted into a signal inside
-        // `selected.setter`, but this signal is unavailable outside, user still
-        // holds they Inert value and selected signal doesn't need to be
-        // tracked. So we either do runtime check like `if selected.is_inert() {
-        // ... }` or we just require selected to always be a signal. Or we can
-        // do two constructors: one for inert selected and one for reactive
-        // selected. TODO: ... For this to work as expected we need
-        // `SelectState` to be Signal still its `selected` to be mapped as
-        // MaybeReactive. Select widget stylist expects full `SelectState` to be
-        // a signal. TODO: ... What idea I like is just to remove
-        // `SignalSetter` implementation from `MaybeSignal` to avoid such
-        // problems and just dynamically check if `selected` is reactive or
-        // inert and create setter effect depending on that.
-        // TODO: ... Wait wait wait. We receive selected and then make a setter
-        // for it, why not just put it inside the `SelectState`? It's not a
-        // problem to pass this when accepting styles as it's just a copy-type
-        // boolean.
         let state = SelectState::initial(with!(|selected, options| {
-            options.iter().position(|opt| &opt.key == selected)
+            options.iter().position(|opt| opt == selected)
         }))
         .signal();
 
-        let options_layout = options
-            .map(|options| options.iter().map(|opt| opt.el.layout()).collect());
+        let selected_index = state.map(|state| state.selected);
+        let selector = create_selector(selected_index);
+        let active = state.map(|state| state.active);
+        let style = SharedStyleFn::default();
+
+        let cells_style = style.clone();
+        let build_cells = move |options: &[K]| {
+            options
+                .iter()
+                .cloned()
+                .enumerate()
+                .map(|(index, key)| {
+                    let option = SelectOption::<W, K>::new(key);
+                    SelectOptionCell::new(
+                        option.el(),
+                        index,
+                        selector,
+                        active,
+                        cells_style.clone(),
+                        axis,
+                    )
+                    .into_el()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let cells = match &options {
+            MaybeReactive::Inert(_) => MaybeSignal::new_inert(
+                options.with(|options| build_cells(options)),
+            ),
+            MaybeReactive::Memo(memo) => {
+                // Cells are rebuilt on each options change. Built inside the
+                // effect, so the nodes of the previous cells are disposed on
+                // its next run.
+                let memo = *memo;
+                let mut cells = create_signal(Vec::new());
+                create_effect(move |_| {
+                    let new = memo.with(|options| build_cells(options));
+                    cells.update(|cells| *cells = new);
+                });
+                MaybeSignal::from(cells)
+            },
+        };
+
+        let cells_layout =
+            cells.map(|cells| cells.iter().map(|cell| cell.layout()).collect());
 
         // WS4.5: only wire the `state.selected -> selected` feedback when
         // `selected` is a genuine reactive Signal. For an inert `selected` the
         // caller holds a plain value with no signal to push into, so `setter`
-        // would promote it to an orphan Signal + build a Memo (state.map) + an
-        // Effect that nothing observes (the pre-existing TODO above). Skipping
-        // it saves 3 nodes per static Select with no observable behavior change.
+        // would promote it to an orphan Signal + an Effect that nothing
+        // observes.
         if selected.as_signal().is_some() {
-            let setter_options = Rc::clone(&options);
+            let setter_options = options.clone();
             selected.setter(
-                state.map(|state| state.selected).maybe_reactive(),
+                selected_index.maybe_reactive(),
                 move |selected, position| {
                     if let Some(option) = position.and_then(|pos| {
-                        setter_options.with(|options| {
-                            options.get(pos).map(|opt| opt.key.clone())
-                        })
+                        setter_options.with(|options| options.get(pos).cloned())
                     }) {
                         *selected = option;
                     }
@@ -243,7 +312,7 @@ where
         SelectBuilder {
             layout: Layout::new(
                 LayoutKind::Flex(
-                    FlexLayout::base(axis, options_layout)
+                    FlexLayout::base(axis, cells_layout)
                         .block_model(BlockModel::zero().padding(1u32))
                         .gap(axis.canon(5, 0))
                         .align_main(Align::Center)
@@ -255,39 +324,20 @@ where
                 ),
             ),
             state,
-            style: None,
+            style,
+            active,
+            cells,
             options,
-            axis,
         }
     }
+}
 
-    // fn option_position(&self, key: &K) -> Option<usize> {
-    //     self.options
-    //         .with(|options| options.iter().position(|opt| &opt.key == key))
-    // }
-
-    // TODO: Use lenses
-    // pub fn use_value(
-    //     self,
-    //     value: impl WriteSignal<K> + ReadSignal<K> + 'static,
-    // ) -> Self {
-    //     value.with(|initial| {
-    //         self.selected.set(self.option_position(initial));
-    //     });
-
-    //     let options = self.options;
-    //     value.setter(self.selected, move |pos, value| {
-    //         if let &Some(pos) = pos {
-    //             if let Some(opt) = options
-    //                 .with(|options| options.get(pos).map(|opt|
-    // opt.key.clone()))             {
-    //                 *value = opt
-    //             }
-    //         }
-    //     });
-
-    //     self
-    // }
+impl<W: WidgetCtx, K: PartialEq + 'static> SelectBuilder<W, K> {
+    /// Style of the select, and of the highlight of its selected option.
+    pub fn style(self, style: impl StyleFn<SelectStyle<W::Color>>) -> Self {
+        *self.style.borrow_mut() = Some(Box::new(style));
+        self
+    }
 }
 
 impl<W: WidgetCtx, K> LayoutWidget<W> for SelectBuilder<W, K>
@@ -327,86 +377,23 @@ impl<W: WidgetCtx, K: PartialEq + 'static> Widget<W> for Select<W, K> {
 
     #[track_caller]
     fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
-        let children_layouts = ctx.layout.children().collect::<Vec<_>>();
-
+        // Must not read `state.selected`: the options draw their own
+        // highlight (see `SelectOptionCell`).
         ctx.render_self(|mut ctx| {
-            let style = ctx.get_style(self.style.as_deref());
-            let state = self.state.get();
-
-            if let (options_offset, Some(selected)) =
-                state.options_offset(ctx.layout.inner, &children_layouts)
-            {
-                let selected_child_layout =
-                    children_layouts.get(selected).unwrap();
-
-                Block::from_layout_style(
-                    selected_child_layout
-                        .outer
-                        .translate(options_offset)
-                        .resized_axis(
-                            self.axis.inverted(),
-                            ctx.layout.inner.size.cross(self.axis),
-                            Anchor::Center,
-                        ),
-                    BlockModel::zero().border_width(1),
-                    style.selected,
-                )
-                .render(ctx.renderer)?;
-            }
+            let style = ctx.get_style_for(
+                ctx.pseudoclass().active(self.active.get()),
+                self.style.borrow().as_deref(),
+            );
+
+            Block::from_layout_style(
+                ctx.layout.outer,
+                self.layout.with(|layout| layout.block_model()),
+                style.container,
+            )
+            .render(ctx.renderer)?;
 
             // TODO: Review if focus outline visible
             ctx.render_focus_outline(ctx.id)
-        })?;
-
-        ctx.render_part("options", |mut ctx| {
-            let state = self.state.get();
-            let _style = ctx.get_style(self.style.as_deref());
-            let (_options_offset, _) =
-                state.options_offset(ctx.layout.inner, &children_layouts);
-
-            self.options.with(move |options| {
-                ctx.clip_inner(|_ctx| {
-                    options
-                        .iter()
-                        .zip(children_layouts.iter())
-                        .enumerate()
-                        .try_for_each(|(_index, (_option, _option_layout))| {
-                            // TODO: Need to thing how to properly handle select
-                            // widget. Should options be real widgets or hidden
-                            // inside Select just to render? Maybe we even don't
-                            // need to have real Text widgets, instead storing
-                            // only text and rendering it through renderer, but
-                            // then we'll probably lose some text properties
-                            // handling.
-                            //
-                            // TODO(unimplemented): render option text. Degrade
-                            // to a no-op instead of `todo!()` so a Select with
-                            // options does not abort the device on render.
-                            Ok(())
-                            // ctx.with_tree_style(
-                            //     |tree_style| {
-                            //         tree_style.text_color(
-                            //             (if Some(index) == state.selected {
-                            //                 style.selected_text_color
-                            //             } else {
-                            //                 style.text_color
-                            //             })
-                            //             .get(),
-                            //         )
-                            //     },
-                            //     |mut ctx| {
-                            //         let option = &option.el;
-                            //         ctx.for_child(
-                            //             option.id(),
-                            //             &option_layout
-                            //                 .translate(options_offset),
-                            //             |ctx| option.render(ctx),
-                            //         )
-                            //     },
-                            // )
-                        })
-                })
-            })
         })
     }
 
@@ -453,3 +440,35 @@ impl<W: WidgetCtx, K: PartialEq + 'static> Widget<W> for Select<W, K> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Select;
+    use crate::{
+        el::{View, arena::ElArena, build::BuildCtx},
+        test_support::NullWtf,
+    };
+    use alloc::vec;
+    use rsact_reactive::prelude::*;
+
+    /// Options are real arena children, following a reactive option list.
+    #[test]
+    fn options_are_arena_children() {
+        with_new_runtime(|_| {
+            let selected = create_signal(2u32);
+            let mut options = create_signal(vec![1u32, 2, 3]);
+
+            let mut root =
+                Select::<NullWtf, u32>::vertical(selected, options).into_el();
+            let arena = create_signal(ElArena::new());
+            let root_id = BuildCtx::run(&mut root, arena);
+
+            let count =
+                || arena.with(|arena| arena.children(root_id).unwrap().len());
+            assert_eq!(count(), 3);
+
+            options.update(|options| options.push(4));
+            assert_eq!(count(), 4);
+        });
+    }
+}
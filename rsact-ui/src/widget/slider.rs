@@ -72,7 +72,7 @@ impl SliderState {
 // by `on_event` (`value`/`range`/`step`/`state`), so — like `Bar`/
 // `Checkbox` — there is no build-only field to drop; `SliderBuilder` moves
 // all seven fields into the retained `Slider` unchanged (a `size_of` `<`
-// assertion would be false, not true). `value: Signal<f32>` and `state:
+// assertion would be false, not true). `value: Binding<f32>` and `state:
 // SliderState` are the widget's job (WS4.5: the live value + local
 // value-adjust mode ARE what a Slider is), so both stay retained fields.
 #[derive(Builder)]
@@ -80,7 +80,7 @@ impl SliderState {
 #[flags(focusable)]
 pub struct SliderBuilder<W: WidgetCtx> {
     #[widget]
-    value: Signal<f32>,
+    value: Binding<f32>,
     #[widget]
     range: MaybeReactive<RangeInclusive<f32>>,
     #[widget]
@@ -102,7 +102,7 @@ pub struct SliderBuilder<W: WidgetCtx> {
 // TODO: Floating label?
 // TODO: Exponential
 pub struct Slider<W: WidgetCtx> {
-    value: Signal<f32>,
+    value: Binding<f32>,
     range: MaybeReactive<RangeInclusive<f32>>,
     step: MaybeReactive<f32>,
     state: SliderState,
@@ -114,7 +114,7 @@ pub struct Slider<W: WidgetCtx> {
 impl<W: WidgetCtx> Slider<W> {
     pub fn new(
         axis: Axis,
-        value: impl IntoSignal<f32>,
+        value: impl IntoBinding<f32>,
         range: impl IntoMaybeReactive<RangeInclusive<f32>>,
     ) -> SliderBuilder<W> {
         let range = range.maybe_reactive();
@@ -122,7 +122,7 @@ impl<W: WidgetCtx> Slider<W> {
 
         SliderBuilder {
             state: SliderState::none(),
-            value: value.signal(),
+            value: value.binding(),
             range,
             step,
             layout: Layout::edge(axis.canon(Length::fill(), Length::Fixed(13))),
@@ -132,14 +132,14 @@ impl<W: WidgetCtx> Slider<W> {
     }
 
     pub fn vertical(
-        value: impl IntoSignal<f32>,
+        value: impl IntoBinding<f32>,
         range: impl IntoMaybeReactive<RangeInclusive<f32>>,
     ) -> SliderBuilder<W> {
         Self::new(Axis::Y, value, range)
     }
 
     pub fn horizontal(
-        value: impl IntoSignal<f32>,
+        value: impl IntoBinding<f32>,
         range: impl IntoMaybeReactive<RangeInclusive<f32>>,
     ) -> SliderBuilder<W> {
         Self::new(Axis::X, value, range)
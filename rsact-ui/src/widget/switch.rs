@@ -0,0 +1,170 @@
+use crate::{
+    anim::{Anim, AnimHandle, easing::Easing},
+    event::message::UiQueue,
+    widget::prelude::*,
+};
+use num::Float;
+use rsact_reactive::prelude::*;
+
+/// Gap between the track border and the thumb.
+const THUMB_INSET: u32 = 2;
+
+/// Duration of the thumb slide of an animated switch.
+const SLIDE_MILLIS: u32 = 150;
+
+declare_widget_style! {
+    SwitchStyle () {
+        container: container,
+        checked: container {
+            checked_background_color: background_color,
+            checked_border_color: border_color,
+            checked_border_radius: border_radius,
+        },
+        thumb_color: color {
+            transparent_thumb_color: transparent,
+        } = ColorStyle::DefaultForeground,
+        checked_thumb_color: color {
+            transparent_checked_thumb_color: transparent,
+        } = ColorStyle::DefaultForeground,
+    }
+}
+
+#[derive(Builder)]
+#[builds(Switch<W>)]
+#[flags(hoverable, clickable, focusable)]
+pub struct SwitchBuilder<W: WidgetCtx> {
+    #[widget]
+    layout: Layout,
+    #[widget]
+    value: Binding<bool>,
+    #[widget]
+    slide: Option<AnimHandle>,
+    #[widget]
+    style: WidgetStyleFn<SwitchStyle<W::Color>>,
+}
+
+/// An on/off toggle: a track with a thumb at its start when off and at its
+/// end when on.
+///
+/// The thumb jumps between the ends unless the switch is
+/// [`animated`](SwitchBuilder::animated).
+///
+/// ```ignore
+/// Switch::new(wifi_enabled).animated(queue)
+/// ```
+pub struct Switch<W: WidgetCtx> {
+    layout: Layout,
+    value: Binding<bool>,
+    /// Progress of the last slide, `1.0` once it is done.
+    slide: Option<AnimHandle>,
+    style: WidgetStyleFn<SwitchStyle<W::Color>>,
+}
+
+impl<W: WidgetCtx> Switch<W> {
+    pub fn new(value: impl IntoBinding<bool>) -> SwitchBuilder<W> {
+        SwitchBuilder {
+            layout: Layout::edge(LengthSize::fixed_length(28, 16)),
+            // Same as `Checkbox`: a passed signal or lens keeps two-way
+            // binding.
+            value: value.binding(),
+            slide: None,
+            style: None,
+        }
+    }
+}
+
+impl<W: WidgetCtx> SwitchBuilder<W> {
+    /// Slide the thumb on toggle. Animations run on the clock of the `queue`,
+    /// so they need [`UI::tick_time`](crate::ui::UI::tick_time) to be called.
+    pub fn animated(mut self, queue: UiQueue<W>) -> Self {
+        let mut slide = queue.anim(
+            Anim::new()
+                .duration(SLIDE_MILLIS)
+                .easing(Easing::EaseOutQuad),
+        );
+        // A stopped animation reads as finished, so the thumb starts at rest.
+        slide.stop();
+        self.slide = Some(slide);
+        self
+    }
+
+    pub fn style(mut self, style: impl StyleFn<SwitchStyle<W::Color>>) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+}
+
+impl<W: WidgetCtx> LayoutWidget<W> for SwitchBuilder<W> {
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+impl<W: WidgetCtx> SizedWidget<W> for SwitchBuilder<W> {}
+
+impl<W: WidgetCtx> Widget<W> for Switch<W> {
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|mut ctx| {
+            let style = ctx.get_style(self.style.as_deref());
+            let checked = self.value.get();
+
+            Block::from_layout_style(
+                ctx.layout.outer,
+                BlockModel::zero().border_width(1),
+                if checked { style.checked } else { style.container },
+            )
+            .render(ctx.renderer)?;
+
+            ctx.render_focus_outline(ctx.id)?;
+
+            let thumb_color = if checked {
+                style.checked_thumb_color.get()
+            } else {
+                style.thumb_color.get()
+            };
+            let Some(thumb_color) = thumb_color else {
+                return Ok(());
+            };
+
+            // Reading the slide tracks the clock only while it runs.
+            let progress = self
+                .slide
+                .as_ref()
+                .map_or(1.0, |slide| slide.value.get().clamp(0.0, 1.0));
+            let position = if checked { progress } else { 1.0 - progress };
+
+            let inner = ctx.layout.inner;
+            let diameter = inner.size.height.saturating_sub(THUMB_INSET * 2);
+            let travel =
+                inner.size.width.saturating_sub(diameter + THUMB_INSET * 2);
+
+            ctx.renderer.circle(
+                inner.top_left
+                    + Point::new(
+                        (THUMB_INSET as f32
+                            + Float::round(travel as f32 * position))
+                            as i32,
+                        THUMB_INSET as i32,
+                    ),
+                diameter,
+                &DrawStyle::default().fill(thumb_color),
+            )?;
+
+            Ok(())
+        })
+    }
+
+    fn on_event(&mut self, mut ctx: EventCtx<'_, W>) -> EventResponse {
+        ctx.handle()?; // hover + press claim + pointer capture (automatic)
+        ctx.handle_click(|ctx| {
+            self.value.update(|value| *value = !*value);
+            if let Some(slide) = &mut self.slide {
+                slide.start();
+            }
+            ctx.capture()
+        })
+    }
+}
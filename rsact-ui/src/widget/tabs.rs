@@ -0,0 +1,507 @@
+#[cfg(feature = "tiny-icons")]
+use super::icon::Icon;
+use super::{flex::Flex, label::Label};
+use crate::{event::MouseEvent, widget::prelude::*};
+use rsact_reactive::scope::{ScopeHandle, new_scope};
+#[cfg(feature = "tiny-icons")]
+use rsact_tiny_icons::IconRaw;
+
+declare_widget_style! {
+    TabsStyle () {
+        strip: container,
+        tab: container,
+        active_tab: container {
+            active_tab_background_color: background_color,
+            active_tab_border_color: border_color,
+            active_tab_border_radius: border_radius,
+        },
+    }
+}
+
+/// One tab of [`Tabs`]: its title in the tab strip and the factory of its
+/// body.
+pub struct Tab<W: WidgetCtx> {
+    title: El<W>,
+    body: Box<dyn Fn() -> El<W>>,
+}
+
+impl<W: WidgetCtx + 'static> Tab<W> {
+    /// Tab titled by a text.
+    pub fn new<V: View<W>>(
+        title: impl SignalMapRefMaybeReactive<str, String>,
+        body: impl Fn() -> V + 'static,
+    ) -> Self {
+        Self::custom(Label::new(title), body)
+    }
+
+    /// Tab titled by an icon.
+    #[cfg(feature = "tiny-icons")]
+    pub fn icon<V: View<W>>(
+        icon: IconRaw,
+        body: impl Fn() -> V + 'static,
+    ) -> Self {
+        Self::custom(Icon::inert(icon), body)
+    }
+
+    /// Tab titled by an icon followed by a text.
+    #[cfg(feature = "tiny-icons")]
+    pub fn icon_text<V: View<W>>(
+        icon: IconRaw,
+        title: impl SignalMapRefMaybeReactive<str, String>,
+        body: impl Fn() -> V + 'static,
+    ) -> Self {
+        Self::custom(
+            Flex::row((Icon::inert(icon), Label::new(title)))
+                .gap(2u32)
+                .vertical_align(Align::Center),
+            body,
+        )
+    }
+
+    /// Tab titled by any element.
+    pub fn custom<V: View<W>>(
+        title: impl View<W>,
+        body: impl Fn() -> V + 'static,
+    ) -> Self {
+        Self {
+            title: title.into_el(),
+            body: Box::new(move || body().into_el()),
+        }
+    }
+}
+
+/// A tab title in the strip. Draws the tab (the active one differently) and
+/// switches to its tab on click.
+///
+/// Like `SelectOptionCell`, the highlight is read through a [`Selector`] over
+/// the active tab, so switching tabs redraws the two titles involved only.
+#[derive(Builder)]
+#[builds(TabCell<W>)]
+#[flags(hoverable, clickable)]
+struct TabCellBuilder<W: WidgetCtx> {
+    #[widget]
+    layout: Layout,
+    #[child(single)]
+    title: El<W>,
+    #[widget]
+    index: usize,
+    #[widget]
+    active: Binding<usize>,
+    #[widget]
+    selector: Selector<usize>,
+    #[widget]
+    style: SharedStyleFn<TabsStyle<W::Color>>,
+}
+
+struct TabCell<W: WidgetCtx> {
+    layout: Layout,
+    index: usize,
+    active: Binding<usize>,
+    selector: Selector<usize>,
+    style: SharedStyleFn<TabsStyle<W::Color>>,
+}
+
+impl<W: WidgetCtx> TabCell<W> {
+    fn new(
+        title: El<W>,
+        index: usize,
+        active: Binding<usize>,
+        selector: Selector<usize>,
+        style: SharedStyleFn<TabsStyle<W::Color>>,
+    ) -> TabCellBuilder<W> {
+        let layout = Layout::shrink(LayoutKind::Container(ContainerLayout {
+            block_model: BlockModel::zero().padding(3).border_width(1),
+            horizontal_align: Align::Center,
+            vertical_align: Align::Center,
+            content: title.layout(),
+            font_props: Default::default(),
+        }));
+
+        TabCellBuilder { layout, title, index, active, selector, style }
+    }
+}
+
+impl<W: WidgetCtx + 'static> Widget<W> for TabCell<W> {
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|ctx| {
+            let style = ctx.get_style(self.style.borrow().as_deref());
+            let block = if self.selector.is_selected(&self.index) {
+                style.active_tab
+            } else {
+                style.tab
+            };
+
+            Block::from_layout_style(
+                ctx.layout.outer,
+                self.layout.with(|layout| layout.block_model()),
+                block,
+            )
+            .render(ctx.renderer)
+        })
+    }
+
+    fn on_event(&mut self, mut ctx: EventCtx<'_, W>) -> EventResponse {
+        ctx.handle()?; // hover + press claim + pointer capture (automatic)
+        ctx.handle_click(|ctx| {
+            if self.active.get() != self.index {
+                self.active.set(self.index);
+            }
+            ctx.capture()
+        })
+    }
+}
+
+/// A tab strip over the body of the active tab.
+///
+/// Tabs are switched by clicking their titles, by the mouse wheel over the
+/// strip and, once focused `Tabs` are activated by press (like [`Select`]),
+/// by encoder rotation or [`MoveDir`](crate::event::MoveDir) moves. The
+/// active tab index is a signal or a lens shared with the caller.
+///
+/// A tab body is built when its tab is first shown, in its own reactive scope.
+/// By default it is disposed when another tab is shown and built anew when
+/// it is shown again. With [`keep_alive`](Tabs::keep_alive) a hidden body is
+/// kept built out of the tree, so its widgets and signals keep their state.
+///
+/// [`Select`]: super::select::Select
+///
+/// ```ignore
+/// let active = create_signal(0);
+/// Tabs::new(active)
+///     .tab(Tab::new("WiFi", || wifi_settings()))
+///     .tab(Tab::new("Display", || display_settings()))
+///     .keep_alive();
+/// ```
+#[derive(View)]
+pub struct Tabs<W: WidgetCtx> {
+    layout: Layout,
+    /// Layouts of the strip and of the shown body, as `For` mirrors its rows.
+    layouts: Signal<Vec<Layout>>,
+    active: Binding<usize>,
+    /// Whether rotation switches tabs, toggled by press.
+    selecting: bool,
+    keep_alive: bool,
+    style: SharedStyleFn<TabsStyle<W::Color>>,
+    count: usize,
+    /// Tabs to build. Empty once built.
+    tabs: Vec<Tab<W>>,
+}
+
+impl<W: WidgetCtx + 'static> Tabs<W> {
+    pub fn new(active: impl IntoBinding<usize>) -> Self {
+        let layouts = create_signal(Vec::<Layout>::new());
+
+        let layout = Layout::fill(LayoutKind::Flex(FlexLayout::base(
+            Axis::Y,
+            layouts.maybe_reactive(),
+        )));
+
+        Self {
+            layout,
+            layouts,
+            active: active.binding(),
+            selecting: false,
+            keep_alive: false,
+            style: SharedStyleFn::default(),
+            count: 0,
+            tabs: Vec::new(),
+        }
+    }
+
+    pub fn tab(mut self, tab: Tab<W>) -> Self {
+        self.tabs.push(tab);
+        self.count += 1;
+        self
+    }
+
+    /// Keep the bodies of hidden tabs built instead of disposing them.
+    pub fn keep_alive(mut self) -> Self {
+        self.keep_alive = true;
+        self
+    }
+
+    pub fn style(self, style: impl StyleFn<TabsStyle<W::Color>>) -> Self {
+        *self.style.borrow_mut() = Some(Box::new(style));
+        self
+    }
+
+    pub fn active(&self) -> Binding<usize> {
+        self.active
+    }
+
+    fn switch_by(&mut self, delta: i32) {
+        let last = self.count.saturating_sub(1);
+        let current = self.active.get().min(last);
+        let new =
+            (current as i64 + delta as i64).clamp(0, last as i64) as usize;
+
+        if new != self.active.get() {
+            self.active.set(new);
+        }
+    }
+}
+
+/// A built tab body.
+struct Body {
+    /// Owns the nodes the body created, dropping it disposes them.
+    scope: ScopeHandle,
+    id: ElId,
+    layout: Layout,
+}
+
+/// Show the body of `tab` as the second child, after the strip, in place of
+/// the `shown` one. Returns the layout of the body.
+fn show_tab<W: WidgetCtx + 'static>(
+    mut ctx: BuildCtx<W>,
+    shown: &mut Option<(usize, Body)>,
+    kept: &mut [Option<Body>],
+    tab: usize,
+    body: &dyn Fn() -> El<W>,
+    keep_alive: bool,
+) -> Layout {
+    if let Some((previous_tab, previous)) = shown.take() {
+        if keep_alive {
+            ctx.park_child(1);
+            kept[previous_tab] = Some(previous);
+        } else {
+            // Dropping the scope disposes the body's nodes, after its element
+            // already left the arena (and disposed its probes).
+            ctx.remove_child(1);
+            drop(previous.scope);
+        }
+    }
+
+    let body = match kept[tab].take() {
+        Some(body) => {
+            ctx.unpark_child(1, body.id);
+            body
+        },
+        None => {
+            let scope = new_scope();
+            let mut el = body();
+            let layout = el.layout();
+            let id = ctx.insert_child(1, &mut el);
+            scope.leave();
+            Body { scope, id, layout }
+        },
+    };
+
+    let layout = body.layout;
+    *shown = Some((tab, body));
+    layout
+}
+
+impl<W: WidgetCtx + 'static> LayoutWidget<W> for Tabs<W> {
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+impl<W: WidgetCtx + 'static> SizedWidget<W> for Tabs<W> {}
+impl<W: WidgetCtx + 'static> FontSettingWidget<W> for Tabs<W> {}
+
+impl<W: WidgetCtx + 'static> Widget<W> for Tabs<W> {
+    fn flags(&self) -> WidgetFlags {
+        WidgetFlags::default().focusable()
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Tabs"
+    }
+
+    fn build(&mut self, mut ctx: BuildCtx<W>) {
+        let (titles, bodies): (Vec<_>, Vec<_>) =
+            core::mem::take(&mut self.tabs)
+                .into_iter()
+                .map(|tab| (tab.title, tab.body))
+                .unzip();
+
+        let active = self.active;
+        let selector = create_selector(active);
+        let cells = titles
+            .into_iter()
+            .enumerate()
+            .map(|(index, title)| {
+                TabCell::new(title, index, active, selector, self.style.clone())
+                    .into_el()
+            })
+            .collect::<Vec<_>>();
+
+        let mut strip = Flex::row(cells).gap(2u32).width_fill().into_el();
+        let strip_layout = strip.layout();
+        ctx.insert_child(0, &mut strip);
+
+        let mut layouts = self.layouts;
+        layouts.set(vec![strip_layout]);
+
+        if bodies.is_empty() {
+            return;
+        }
+
+        let keep_alive = self.keep_alive;
+        let mut shown = None::<(usize, Body)>;
+        let mut kept =
+            (0..bodies.len()).map(|_| None::<Body>).collect::<Vec<_>>();
+
+        create_effect(move |_| {
+            let tab = active.get().min(bodies.len() - 1);
+            if shown.as_ref().is_some_and(|(shown, _)| *shown == tab) {
+                return;
+            }
+
+            // Bodies must neither subscribe this effect to what they read nor
+            // be owned by it: each is owned by its own scope.
+            untrack(|| {
+                let body_layout = show_tab(
+                    ctx,
+                    &mut shown,
+                    &mut kept,
+                    tab,
+                    &*bodies[tab],
+                    keep_alive,
+                );
+                layouts.set(vec![strip_layout, body_layout]);
+            });
+        });
+    }
+
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|mut ctx| {
+            let style = ctx.get_style(self.style.borrow().as_deref());
+
+            if let Some(strip) = ctx.layout.children().next() {
+                Block::from_layout_style(
+                    strip.outer,
+                    BlockModel::zero(),
+                    style.strip,
+                )
+                .render(ctx.renderer)?;
+            }
+
+            ctx.render_focus_outline(ctx.id)
+        })
+    }
+
+    fn on_event(&mut self, mut ctx: EventCtx<'_, W>) -> EventResponse {
+        let wheel_over_strip =
+            matches!(ctx.event, Event::Mouse(MouseEvent::Wheel(..)))
+                && ctx
+                    .page_state
+                    .pointer
+                    .pos
+                    .zip(ctx.layout.children().next())
+                    .is_some_and(|(pos, strip)| strip.outer.contains(pos));
+
+        if (wheel_over_strip || (self.selecting && ctx.is_focused()))
+            && let Some(delta) = ctx.event.interpret_as_rotation()
+        {
+            self.switch_by(delta);
+            return ctx.capture();
+        }
+
+        ctx.handle()?; // focus press claim (encoder), automatic
+        ctx.handle_click(|ctx| {
+            self.selecting = !self.selecting;
+            ctx.capture()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Tab, Tabs};
+    use crate::{
+        el::{arena::ElArena, build::BuildCtx},
+        test_support::NullWtf,
+        widget::{Widget, combinators::Unit},
+    };
+    use alloc::{rc::Rc, vec::Vec};
+    use core::cell::Cell;
+    use rsact_reactive::prelude::*;
+
+    /// Tabs whose bodies record a signal per build.
+    fn tabs(created: &Rc<Cell<Vec<Signal<usize>>>>) -> Tabs<NullWtf> {
+        (0..3).fold(Tabs::new(0), |tabs, index| {
+            let sink = created.clone();
+            tabs.tab(Tab::custom(Unit.el(), move || {
+                let mut all = sink.take();
+                all.push(create_signal(index));
+                sink.set(all);
+                Unit.el()
+            }))
+        })
+    }
+
+    /// Without keep-alive only the shown body is built, a hidden one is
+    /// disposed with its nodes.
+    #[test]
+    fn hidden_body_is_disposed() {
+        with_new_runtime(|_| {
+            let created = Rc::new(Cell::new(Vec::new()));
+            let tabs = tabs(&created);
+            let mut active = tabs.active();
+            let arena = create_signal(ElArena::new());
+            let root = BuildCtx::run(&mut tabs.el(), arena);
+
+            active.set(1);
+            active.set(0);
+
+            let signals = created.take();
+            assert_eq!(signals.len(), 3, "shown body was not rebuilt");
+            assert!(!signals[0].is_alive() && !signals[1].is_alive());
+            assert_eq!(signals[2].get(), 0);
+            arena.with(|arena| {
+                assert_eq!(arena.children(root).unwrap().len(), 2);
+            });
+        });
+    }
+
+    /// With keep-alive a hidden body stays built out of the tree and is shown
+    /// again as it was.
+    #[test]
+    fn keep_alive_keeps_hidden_body() {
+        with_new_runtime(|_| {
+            let created = Rc::new(Cell::new(Vec::new()));
+            let tabs = tabs(&created).keep_alive();
+            let mut active = tabs.active();
+            let arena = create_signal(ElArena::new());
+            let root = BuildCtx::run(&mut tabs.el(), arena);
+            let body = || arena.with(|arena| arena.children(root).unwrap()[1]);
+
+            let first = body();
+            active.set(2);
+            assert_ne!(body(), first);
+            active.set(0);
+            assert_eq!(body(), first);
+
+            let signals = created.take();
+            assert_eq!(signals.len(), 2, "kept body was rebuilt");
+            assert!(signals.iter().all(|signal| signal.is_alive()));
+            arena.with(|arena| {
+                assert_eq!(arena.children(root).unwrap().len(), 2);
+            });
+        });
+    }
+
+    /// An out of range active tab shows the last one.
+    #[test]
+    fn active_tab_is_clamped() {
+        with_new_runtime(|_| {
+            let created = Rc::new(Cell::new(Vec::new()));
+            let tabs = tabs(&created);
+            let mut active = tabs.active();
+            BuildCtx::run(&mut tabs.el(), create_signal(ElArena::new()));
+
+            active.set(10);
+            let signals = created.take();
+            assert_eq!(signals.last().map(|s| s.get()), Some(2));
+        });
+    }
+}
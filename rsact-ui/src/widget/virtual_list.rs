@@ -0,0 +1,537 @@
+use crate::{
+    layout::{VirtualLayout, model::LayoutModelNode},
+    widget::{SizedWidget, prelude::*, scrollable::ScrollableState},
+};
+use core::ops::Range;
+use rsact_reactive::scope::{ScopeHandle, new_scope};
+
+/// Height of the rows of a [`VirtualList`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowHeight {
+    /// Every row has this height.
+    Fixed(u32),
+    /// Rows are measured once laid out, rows not seen yet count as this
+    /// estimate.
+    Measured(u32),
+}
+
+/// Row geometry of a list: the row height and the heights measured so far.
+/// Rows past `measured` were never laid out.
+#[derive(Clone, PartialEq)]
+struct Rows {
+    height: RowHeight,
+    measured: Vec<u32>,
+}
+
+impl Rows {
+    fn new(height: RowHeight) -> Self {
+        Self { height, measured: Vec::new() }
+    }
+
+    fn height(&self, row: usize) -> u32 {
+        match self.height {
+            RowHeight::Fixed(height) => height,
+            RowHeight::Measured(estimate) => {
+                self.measured.get(row).copied().unwrap_or(estimate)
+            },
+        }
+    }
+
+    fn top(&self, row: usize) -> u32 {
+        match self.height {
+            RowHeight::Fixed(height) => row as u32 * height,
+            RowHeight::Measured(estimate) => {
+                let measured = row.min(self.measured.len());
+                self.measured[..measured].iter().sum::<u32>()
+                    + (row - measured) as u32 * estimate
+            },
+        }
+    }
+
+    /// The row containing `pos`, the last one if `pos` is past the end.
+    fn row_at(&self, pos: u32, count: usize) -> usize {
+        let last = count.saturating_sub(1);
+        match self.height {
+            RowHeight::Fixed(height) => {
+                ((pos / height.max(1)) as usize).min(last)
+            },
+            RowHeight::Measured(_) => {
+                let mut bottom = 0;
+                (0..last)
+                    .find(|&row| {
+                        bottom += self.height(row);
+                        bottom > pos
+                    })
+                    .unwrap_or(last)
+            },
+        }
+    }
+
+    /// Returns whether the height changed.
+    fn measure(&mut self, row: usize, height: u32) -> bool {
+        let RowHeight::Measured(estimate) = self.height else {
+            return false;
+        };
+        if self.measured.len() <= row {
+            self.measured.resize(row + 1, estimate);
+        }
+        core::mem::replace(&mut self.measured[row], height) != height
+    }
+
+    /// The rows intersecting the `viewport` long window at `offset`, with
+    /// `margin` rows on each side.
+    fn window(
+        &self,
+        count: usize,
+        offset: u32,
+        viewport: u32,
+        margin: usize,
+    ) -> Window {
+        if count == 0 {
+            return Window::default();
+        }
+
+        let offset = offset.min(self.top(count).saturating_sub(viewport));
+        let first = self.row_at(offset, count).saturating_sub(margin);
+        let last = self.row_at(offset + viewport.saturating_sub(1), count);
+        let end = (last + 1 + margin).min(count);
+
+        Window {
+            first,
+            len: end - first,
+            start: self.top(first) as i32 - offset as i32,
+        }
+    }
+}
+
+/// The materialized rows and where the first one starts relative to the list
+/// top.
+#[derive(Clone, Copy, Default, PartialEq)]
+struct Window {
+    first: usize,
+    len: usize,
+    start: i32,
+}
+
+impl Window {
+    fn rows(&self) -> Range<usize> {
+        self.first..self.first + self.len
+    }
+}
+
+/// Vertical list of `count` rows of which only the ones intersecting the
+/// scroll window (plus [`margin`](VirtualList::margin) rows around it) are
+/// built.
+///
+/// Unlike a [`Flex`] column in a [`Scrollable`], a list of thousands of rows
+/// keeps a few elements in the arena: as [`ScrollableState::offset`] changes,
+/// rows leaving the window are removed together with their reactive scope
+/// and the rows entering it are built by `row`, the others are kept.
+///
+/// With [`RowHeight::Measured`] rows are placed by the heights they got in the
+/// last layout, learned as the list renders or gets events, and by the
+/// estimate until then. The window height is learned the same way, it is known
+/// from the start only for a fixed list height.
+///
+/// When focused and activated by press, encoder rotation moves the focused row
+/// and scrolls it into view.
+///
+/// ```ignore
+/// let logs = create_signal(vec![...]);
+/// VirtualList::fixed(logs.map(Vec::len), 12, move |row| {
+///     Label::new(logs.with(|logs| logs[row].clone()))
+/// })
+/// .height(120);
+/// ```
+#[derive(View)]
+pub struct VirtualList<W: WidgetCtx> {
+    layout: Layout,
+    count: MaybeReactive<usize>,
+    rows: Signal<Rows>,
+    state: Signal<ScrollableState>,
+    viewport: Signal<u32>,
+    window: Signal<Window>,
+    focused: Signal<Option<usize>>,
+    margin: usize,
+    /// Builds a row by its index. `None` once built.
+    row: Option<Box<dyn Fn(usize) -> El<W>>>,
+}
+
+impl<W: WidgetCtx + 'static> VirtualList<W> {
+    pub fn new<V: View<W>>(
+        count: impl IntoMaybeReactive<usize>,
+        row_height: RowHeight,
+        row: impl Fn(usize) -> V + 'static,
+    ) -> Self {
+        let window = create_signal(Window::default());
+
+        let mut layout =
+            Layout::fill(LayoutKind::Virtual(VirtualLayout::default()));
+        layout.setter(window.maybe_reactive(), |layout, window| {
+            let virtual_layout = layout.expect_virtual_mut();
+            virtual_layout.first = window.first;
+            virtual_layout.len = window.len;
+            virtual_layout.start = window.start;
+        });
+
+        Self {
+            layout,
+            count: count.maybe_reactive(),
+            rows: create_signal(Rows::new(row_height)),
+            state: create_signal(ScrollableState::none()),
+            viewport: create_signal(0),
+            window,
+            focused: create_signal(None),
+            margin: 2,
+            row: Some(Box::new(move |index| row(index).into_el())),
+        }
+    }
+
+    pub fn fixed<V: View<W>>(
+        count: impl IntoMaybeReactive<usize>,
+        row_height: u32,
+        row: impl Fn(usize) -> V + 'static,
+    ) -> Self {
+        Self::new(count, RowHeight::Fixed(row_height), row)
+    }
+
+    pub fn measured<V: View<W>>(
+        count: impl IntoMaybeReactive<usize>,
+        estimate: u32,
+        row: impl Fn(usize) -> V + 'static,
+    ) -> Self {
+        Self::new(count, RowHeight::Measured(estimate), row)
+    }
+
+    /// Number of rows built beyond each edge of the window, 2 by default.
+    pub fn margin(mut self, rows: usize) -> Self {
+        self.margin = rows;
+        self
+    }
+
+    pub fn scroll_state(&self) -> Signal<ScrollableState> {
+        self.state
+    }
+
+    /// The row focused by encoder rotation.
+    pub fn focused_row(&self) -> Signal<Option<usize>> {
+        self.focused
+    }
+
+    /// Learn the window height and the heights of the built rows from the
+    /// last layout.
+    fn measure(&self, layout: &LayoutModelNode<'_>) {
+        let (mut viewport, mut rows) = (self.viewport, self.rows);
+
+        let height = layout.inner.size.height;
+        if viewport.get() != height {
+            viewport.set(height);
+        }
+
+        if let RowHeight::Measured(_) = rows.with(|rows| rows.height) {
+            let first = self.window.with(|window| window.first);
+            let heights = layout
+                .children()
+                .map(|row| row.outer.size.height)
+                .collect::<Vec<_>>();
+            let changed = rows.with(|rows| {
+                heights.iter().enumerate().any(|(index, &height)| {
+                    rows.height(first + index) != height
+                })
+            });
+            if changed {
+                rows.update(|rows| {
+                    heights.into_iter().enumerate().for_each(
+                        |(index, height)| {
+                            rows.measure(first + index, height);
+                        },
+                    )
+                });
+            }
+        }
+    }
+
+    fn scroll_into_view(&mut self, row: usize) {
+        let viewport = self.viewport.get();
+        let (top, bottom) = self.rows.with(|rows| {
+            let top = rows.top(row);
+            (top, top + rows.height(row))
+        });
+        let offset = self.state.with(|state| state.offset);
+
+        let new_offset = if top < offset {
+            top
+        } else if bottom > offset + viewport {
+            bottom - viewport
+        } else {
+            offset
+        };
+
+        if new_offset != offset {
+            self.state.update(|state| state.offset = new_offset);
+        }
+    }
+}
+
+fn materialize<W: WidgetCtx + 'static>(
+    mut ctx: BuildCtx<W>,
+    scopes: &mut Vec<ScopeHandle>,
+    current: Range<usize>,
+    target: Range<usize>,
+    row: &dyn Fn(usize) -> El<W>,
+) {
+    // `BuildCtx` is a `Copy` handle: the closure gets its own copy.
+    let build_row = move |index: usize, row_index: usize| {
+        let mut ctx = ctx;
+        let scope = new_scope();
+        let mut el = row(row_index);
+        ctx.insert_child(index, &mut el);
+        scope.leave();
+        scope
+    };
+
+    // Dropping a row's scope disposes its nodes, after its element already
+    // left the arena (and disposed its probes).
+    if target.start >= current.end || target.end <= current.start {
+        ctx.clear_children();
+        scopes.clear();
+        scopes.extend(
+            target
+                .enumerate()
+                .map(|(index, row_index)| build_row(index, row_index)),
+        );
+        return;
+    }
+
+    for _ in target.end..current.end {
+        ctx.remove_child(scopes.len() - 1);
+        scopes.pop();
+    }
+    for _ in current.start..target.start {
+        ctx.remove_child(0);
+        drop(scopes.remove(0));
+    }
+
+    for row_index in (target.start..current.start).rev() {
+        scopes.insert(0, build_row(0, row_index));
+    }
+    for row_index in current.end..target.end {
+        scopes.push(build_row(scopes.len(), row_index));
+    }
+}
+
+impl<W: WidgetCtx + 'static> LayoutWidget<W> for VirtualList<W> {
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+impl<W: WidgetCtx + 'static> SizedWidget<W> for VirtualList<W> {}
+impl<W: WidgetCtx + 'static> FontSettingWidget<W> for VirtualList<W> {}
+
+impl<W: WidgetCtx + 'static> Widget<W> for VirtualList<W> {
+    fn flags(&self) -> WidgetFlags {
+        WidgetFlags::default().focusable().clip_inner()
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "VirtualList"
+    }
+
+    fn build(&mut self, ctx: BuildCtx<W>) {
+        let Some(row) = self.row.take() else {
+            log::error!("VirtualList widget built twice");
+            return;
+        };
+
+        if let Length::Fixed(height) =
+            self.layout.with(|layout| layout.size.height())
+        {
+            self.viewport.set(height);
+        }
+
+        let count = self.count;
+        let rows = self.rows;
+        let state = self.state;
+        let viewport = self.viewport;
+        let mut window = self.window;
+        let margin = self.margin;
+        let mut scopes = Vec::<ScopeHandle>::new();
+
+        create_effect(move |_| {
+            let count = count.get();
+            let offset = state.with(|state| state.offset);
+            let viewport = viewport.get();
+            let target =
+                rows.with(|rows| rows.window(count, offset, viewport, margin));
+
+            // Rows must neither subscribe this effect to what they read nor be
+            // owned by it (it would dispose them on its next run): each row is
+            // owned by its own scope in `scopes`.
+            untrack(|| {
+                let current = window.with(Window::rows);
+                materialize(ctx, &mut scopes, current, target.rows(), &row);
+                if window.with(|window| *window != target) {
+                    window.set(target);
+                }
+            });
+        });
+    }
+
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        // The page defers the effects, the window follows after this pass.
+        untrack(|| self.measure(ctx.layout));
+
+        ctx.render_self(|mut ctx| {
+            let window = self.window.get();
+
+            if let Some(row) = self.focused.get()
+                && window.rows().contains(&row)
+                && let Some(row_layout) =
+                    ctx.layout.children().nth(row - window.first)
+            {
+                Block::from_layout_style(
+                    row_layout.outer,
+                    BlockModel::zero(),
+                    BlockStyle::base().outline(
+                        OutlineStyle::base()
+                            .width(1)
+                            .color(<W::Color as Color>::accents()[1]),
+                    ),
+                )
+                .render(ctx.renderer)?;
+            }
+
+            ctx.render_focus_outline(ctx.id)
+        })
+    }
+
+    fn on_event(&mut self, mut ctx: EventCtx<'_, W>) -> EventResponse {
+        self.measure(ctx.layout);
+
+        if self.state.with(|state| state.active)
+            && ctx.is_focused()
+            && let Some(delta) = ctx.event.interpret_as_rotation()
+        {
+            let count = self.count.get();
+            if count > 0 {
+                let row = match self.focused.get() {
+                    Some(row) => (row as i64 + delta as i64)
+                        .clamp(0, count as i64 - 1)
+                        as usize,
+                    None => self.window.with(|window| window.first),
+                };
+                self.focused.set(Some(row));
+                self.scroll_into_view(row);
+            }
+
+            return ctx.capture();
+        }
+
+        ctx.handle()?; // focus press claim (encoder), automatic
+        ctx.handle_click(|ctx| {
+            self.state.update(|state| state.active = !state.active);
+            ctx.capture()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RowHeight, Rows, VirtualList};
+    use crate::{
+        el::{arena::ElArena, build::BuildCtx},
+        test_support::NullWtf,
+        widget::{SizedWidget as _, Widget, combinators::Unit},
+    };
+    use alloc::{rc::Rc, vec::Vec};
+    use core::cell::Cell;
+    use rsact_reactive::prelude::*;
+
+    #[test]
+    fn window_covers_visible_rows_and_margin() {
+        let rows = Rows::new(RowHeight::Fixed(10));
+        let window = rows.window(500, 205, 50, 2);
+        assert_eq!(window.rows(), 18..28);
+        assert_eq!(window.start, 180 - 205);
+
+        // Scrolled past the end: clamped to the last full window.
+        assert_eq!(rows.window(10, 1000, 50, 1).rows(), 4..10);
+
+        let mut rows = Rows::new(RowHeight::Measured(10));
+        rows.measure(0, 30);
+        assert_eq!(rows.top(2), 40);
+        assert_eq!(rows.row_at(35, 100), 1);
+        assert_eq!(rows.window(100, 0, 25, 0).rows(), 0..1);
+    }
+
+    /// Only the window is built and scrolling keeps the rows still in it.
+    #[test]
+    fn builds_only_visible_rows() {
+        with_new_runtime(|_| {
+            let builds = Rc::new(Cell::new(0));
+            let counter = builds.clone();
+            let list = VirtualList::<NullWtf>::fixed(500, 10, move |_| {
+                counter.set(counter.get() + 1);
+                Unit.el()
+            })
+            .height(50u32);
+            let mut state = list.scroll_state();
+            let mut root = list.el();
+
+            let arena = create_signal(ElArena::new());
+            let root_id = BuildCtx::run(&mut root, arena);
+            let children = || {
+                arena.with(|arena| arena.children(root_id).unwrap().to_vec())
+            };
+
+            let initial = children();
+            assert_eq!(initial.len(), 7, "5 visible rows and 2 margin rows");
+            assert_eq!(builds.get(), 7);
+
+            state.update(|state| state.offset = 20);
+            let scrolled = children();
+            assert_eq!(scrolled.len(), 9);
+            assert_eq!(&scrolled[..7], &initial[..], "kept rows not rebuilt");
+            assert_eq!(builds.get(), 9);
+
+            // Past the end: the last 5 rows and the margin above them.
+            state.update(|state| state.offset = 4990);
+            assert_eq!(children().len(), 7);
+            arena.with(|arena| {
+                assert!(!arena.contains(initial[0]), "left row disposed");
+                assert_eq!(arena.el_count(), 8);
+            });
+        });
+    }
+
+    /// A row's nodes are disposed when it leaves the window.
+    #[test]
+    fn row_leaving_window_disposes_its_nodes() {
+        with_new_runtime(|_| {
+            let created = Rc::new(Cell::new(Vec::<Signal<usize>>::new()));
+            let sink = created.clone();
+            let list = VirtualList::<NullWtf>::fixed(100, 10, move |row| {
+                let mut all = sink.take();
+                all.push(create_signal(row));
+                sink.set(all);
+                Unit.el()
+            })
+            .height(10u32)
+            .margin(0);
+            let mut state = list.scroll_state();
+            let mut root = list.el();
+            BuildCtx::run(&mut root, create_signal(ElArena::new()));
+
+            state.update(|state| state.offset = 50);
+
+            let signals = created.take();
+            assert_eq!(signals.len(), 2);
+            assert!(!signals[0].is_alive(), "left row leaked its signal");
+            assert!(signals[1].is_alive());
+        });
+    }
+}
@@ -0,0 +1,467 @@
+use crate::menu::{MenuRect, MenuRow, render_menu};
+use core::cell::Cell;
+use rsact_ui::{
+    event::{MouseButton, MouseEvent, PressEvent},
+    prelude::*,
+};
+
+/// An entry of a [`ContextMenu`]: an action or a nested menu.
+pub struct MenuItem {
+    label: String,
+    kind: MenuItemKind,
+}
+
+enum MenuItemKind {
+    Action(Box<dyn FnMut()>),
+    Submenu(Vec<MenuItem>),
+}
+
+impl MenuItem {
+    /// Item running `action` and closing the menu when chosen.
+    pub fn action(
+        label: impl Into<String>,
+        action: impl FnMut() + 'static,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            kind: MenuItemKind::Action(Box::new(action)),
+        }
+    }
+
+    /// Item opening `items` next to it when chosen.
+    pub fn submenu(
+        label: impl Into<String>,
+        items: impl IntoIterator<Item = MenuItem>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            kind: MenuItemKind::Submenu(items.into_iter().collect()),
+        }
+    }
+}
+
+/// The item at `path` of highlighted rows, one per open list.
+fn item_mut<'a>(
+    items: &'a mut [MenuItem],
+    path: &[usize],
+) -> Option<&'a mut MenuItem> {
+    let (&index, rest) = path.split_first()?;
+    let item = items.get_mut(index)?;
+
+    if rest.is_empty() {
+        return Some(item);
+    }
+
+    match &mut item.kind {
+        MenuItemKind::Submenu(items) => item_mut(items, rest),
+        MenuItemKind::Action(_) => None,
+    }
+}
+
+/// An open list of a [`ContextMenu`]: the root menu or a submenu.
+struct Level {
+    origin: Point,
+    highlighted: usize,
+    /// Where the list was last drawn.
+    menu: Cell<Option<MenuRect>>,
+}
+
+impl Level {
+    fn new(origin: Point) -> Self {
+        Self { origin, highlighted: 0, menu: Cell::new(None) }
+    }
+}
+
+/// Wraps `content` with a menu of actions and nested submenus, opened above
+/// the surrounding content by a right click at the cursor, or by a press while
+/// the wrapper itself is focused.
+///
+/// Rotation, the wheel or the pointer move the highlight in the innermost
+/// list. Choosing an item runs its action and closes the menu, or opens its
+/// submenu next to it. `Exit` closes the innermost list, and a click outside
+/// of the menu closes it all.
+///
+/// ```ignore
+/// ContextMenu::new(
+///     file_row,
+///     [
+///         MenuItem::action("Open", move || open(file)),
+///         MenuItem::submenu(
+///             "Sort by",
+///             [
+///                 MenuItem::action("Name", move || sort.set(Sort::Name)),
+///                 MenuItem::action("Size", move || sort.set(Sort::Size)),
+///             ],
+///         ),
+///     ],
+/// )
+/// ```
+#[derive(View)]
+pub struct ContextMenu<W: WidgetCtx> {
+    layout: Layout,
+    /// Wrapped content, moved into the arena on build.
+    content: Option<El<W>>,
+    items: Vec<MenuItem>,
+    /// Open lists, the root menu first. Empty while closed.
+    levels: Vec<Level>,
+    style: WidgetStyleFn<SelectStyle<W::Color>>,
+}
+
+impl<W: WidgetCtx + 'static> ContextMenu<W> {
+    pub fn new(
+        content: impl View<W>,
+        items: impl IntoIterator<Item = MenuItem>,
+    ) -> Self {
+        let content = content.into_el();
+        let layout = Layout::shrink(LayoutKind::Container(
+            ContainerLayout::base(content.layout()),
+        ));
+
+        Self {
+            layout,
+            content: Some(content),
+            items: items.into_iter().collect(),
+            levels: Vec::new(),
+            style: None,
+        }
+    }
+
+    pub fn style(mut self, style: impl StyleFn<SelectStyle<W::Color>>) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+
+    /// Items of the open list at `depth`.
+    fn items(&self, depth: usize) -> &[MenuItem] {
+        let mut items = self.items.as_slice();
+        for level in &self.levels[..depth] {
+            items = match items.get(level.highlighted).map(|item| &item.kind) {
+                Some(MenuItemKind::Submenu(submenu)) => submenu,
+                _ => &[],
+            };
+        }
+        items
+    }
+
+    /// The open list and row under `point`, the innermost list first.
+    fn row_at(&self, point: Option<Point>) -> Option<(usize, usize)> {
+        let point = point?;
+        self.levels
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(depth, level)| {
+                level
+                    .menu
+                    .get()
+                    .and_then(|menu| menu.row_at(point))
+                    .filter(|row| *row < self.items(depth).len())
+                    .map(|row| (depth, row))
+            })
+    }
+
+    fn open(&mut self, origin: Point, ctx: &mut EventCtx<'_, W>) {
+        if self.items.is_empty() {
+            return;
+        }
+
+        self.levels.clear();
+        self.levels.push(Level::new(origin));
+        ctx.open_overlay();
+    }
+
+    fn close(&mut self, ctx: &mut EventCtx<'_, W>) {
+        self.levels.clear();
+        ctx.close_overlay();
+    }
+
+    /// Choose the highlighted item of the innermost list.
+    fn activate(&mut self, ctx: &mut EventCtx<'_, W>) {
+        let Some(level) = self.levels.last() else {
+            return;
+        };
+        let origin = level.menu.get().map_or(level.origin, |menu| {
+            menu.submenu_origin(level.highlighted)
+        });
+        let path = self
+            .levels
+            .iter()
+            .map(|level| level.highlighted)
+            .collect::<Vec<_>>();
+
+        // `Some(opens)` for a submenu, `None` for an action that has run.
+        let submenu =
+            match item_mut(&mut self.items, &path).map(|item| &mut item.kind) {
+                Some(MenuItemKind::Action(action)) => {
+                    action();
+                    None
+                },
+                Some(MenuItemKind::Submenu(items)) => Some(!items.is_empty()),
+                None => Some(false),
+            };
+
+        match submenu {
+            None => self.close(ctx),
+            Some(true) => self.levels.push(Level::new(origin)),
+            Some(false) => {},
+        }
+    }
+
+    fn on_menu_event(&mut self, mut ctx: EventCtx<'_, W>) -> EventResponse {
+        match ctx.event {
+            Event::Exit => {
+                self.levels.pop();
+                if self.levels.is_empty() {
+                    ctx.close_overlay();
+                }
+            },
+            Event::Press(PressEvent::Press) => {},
+            Event::Press(PressEvent::Release) => self.activate(&mut ctx),
+            &Event::Mouse(MouseEvent::MouseMove(point)) => {
+                // Keep the submenu of the hovered row open.
+                if let Some((depth, row)) = self.row_at(Some(point))
+                    && self.levels[depth].highlighted != row
+                {
+                    self.levels.truncate(depth + 1);
+                    self.levels[depth].highlighted = row;
+                }
+            },
+            Event::Mouse(MouseEvent::ButtonDown(..)) => {
+                match self.row_at(ctx.cursor_pos()) {
+                    Some((depth, row)) => {
+                        self.levels.truncate(depth + 1);
+                        self.levels[depth].highlighted = row;
+                        self.activate(&mut ctx);
+                    },
+                    None => self.close(&mut ctx),
+                }
+            },
+            Event::Mouse(MouseEvent::ButtonUp(..)) => {},
+            event => {
+                let Some(delta) = event.interpret_as_rotation() else {
+                    return ctx.ignore();
+                };
+                let last = self.items(self.levels.len() - 1).len() - 1;
+                if let Some(level) = self.levels.last_mut() {
+                    level.highlighted = (level.highlighted as i64
+                        + delta as i64)
+                        .clamp(0, last as i64)
+                        as usize;
+                }
+            },
+        }
+
+        ctx.capture()
+    }
+}
+
+impl<W: WidgetCtx + 'static> LayoutWidget<W> for ContextMenu<W> {
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+impl<W: WidgetCtx + 'static> SizedWidget<W> for ContextMenu<W> {}
+impl<W: WidgetCtx + 'static> FontSettingWidget<W> for ContextMenu<W> {}
+
+impl<W: WidgetCtx + 'static> Widget<W> for ContextMenu<W> {
+    fn flags(&self) -> WidgetFlags {
+        WidgetFlags::default().focusable()
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "ContextMenu"
+    }
+
+    fn build(&mut self, mut ctx: BuildCtx<W>) {
+        if let Some(mut content) = self.content.take() {
+            ctx.set_single_child(&mut content);
+        }
+    }
+
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|mut ctx| ctx.render_focus_outline(ctx.id))
+    }
+
+    fn render_overlay(
+        &self,
+        mut ctx: RenderCtx<'_, W, CtxReady>,
+    ) -> RenderResult {
+        let style = ctx.get_style(self.style.as_deref());
+
+        for (depth, level) in self.levels.iter().enumerate() {
+            let menu = render_menu(
+                &mut ctx,
+                level.origin,
+                0,
+                self.items(depth).iter().map(|item| MenuRow {
+                    label: &item.label,
+                    submenu: matches!(item.kind, MenuItemKind::Submenu(_)),
+                }),
+                Some(level.highlighted),
+                style,
+            )?;
+            level.menu.set(Some(menu));
+        }
+
+        Ok(())
+    }
+
+    fn on_event(&mut self, mut ctx: EventCtx<'_, W>) -> EventResponse {
+        if ctx.owns_overlay() {
+            return self.on_menu_event(ctx);
+        }
+
+        if let Event::Mouse(MouseEvent::ButtonDown(MouseButton::Right, _)) =
+            ctx.event
+            && ctx.cursor_in_bounds()
+            && let Some(point) = ctx.cursor_pos()
+        {
+            self.open(point, &mut ctx);
+            return ctx.capture();
+        }
+
+        ctx.handle()?;
+        ctx.handle_click(|ctx| {
+            let center = ctx.layout.outer.center();
+            self.open(center, ctx);
+            ctx.capture()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ContextMenu, MenuItem};
+    use crate::menu::tests::row_height;
+    use rsact_reactive::prelude::*;
+    use rsact_ui::{
+        event::{Event, MouseButton, MouseEvent, MoveDir, PressEvent},
+        prelude::*,
+        test_support::focused_page,
+    };
+
+    const VIEWPORT: Size = Size::new_equal(128);
+
+    fn press() -> [Event; 2] {
+        [Event::Press(PressEvent::Press), Event::Press(PressEvent::Release)]
+    }
+
+    /// A menu of "Add" (+1) and a "More" submenu with "Add ten" (+10).
+    fn menu(
+        count: Signal<u32>,
+    ) -> ContextMenu<rsact_ui::test_support::NullWtf> {
+        let mut add = count;
+        let mut add_ten = count;
+        ContextMenu::new(
+            Edge::new().width(100u32).height(100u32),
+            [
+                MenuItem::action("Add", move || {
+                    add.update(|count| *count += 1)
+                }),
+                MenuItem::submenu(
+                    "More",
+                    [MenuItem::action("Add ten", move || {
+                        add_ten.update(|count| *count += 10)
+                    })],
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn encoder_walks_into_submenu_and_runs_action() {
+        with_new_runtime(|_| {
+            let count = create_signal(0u32);
+            let mut page = focused_page(menu(count), VIEWPORT);
+
+            let _ = page.handle_events(
+                press()
+                    .into_iter()
+                    .chain([Event::move_1(MoveDir::Right)])
+                    .chain(press())
+                    .chain(press()),
+            );
+            assert_eq!(count.get(), 10);
+
+            // The action closed the menu: a press reopens it.
+            let _ = page.handle_events(press().into_iter().chain(press()));
+            assert_eq!(count.get(), 11);
+        });
+    }
+
+    #[test]
+    fn exit_closes_innermost_list_first() {
+        with_new_runtime(|_| {
+            let count = create_signal(0u32);
+            let mut page = focused_page(menu(count), VIEWPORT);
+
+            let _ = page.handle_events(
+                press()
+                    .into_iter()
+                    .chain([Event::move_1(MoveDir::Right)])
+                    .chain(press())
+                    .chain([Event::Exit]),
+            );
+            // Back in the root menu, on "More": opens the submenu again.
+            let _ = page.handle_events(press().into_iter().chain(press()));
+            assert_eq!(count.get(), 10);
+
+            let _ = page.handle_events(
+                press().into_iter().chain([Event::Exit, Event::Exit]),
+            );
+            let responses = page.handle_events([Event::Exit].into_iter());
+            assert_eq!(responses.len(), 1, "closed menu leaves Exit unhandled");
+        });
+    }
+
+    #[test]
+    fn right_click_opens_at_cursor_and_outside_click_closes() {
+        with_new_runtime(|_| {
+            let count = create_signal(0u32);
+            let mut page = focused_page(menu(count), VIEWPORT);
+
+            let at = Point::new(10, 10);
+            let _ = page.handle_events(
+                [Event::Mouse(MouseEvent::ButtonDown(
+                    MouseButton::Right,
+                    Some(at),
+                ))]
+                .into_iter(),
+            );
+            let _ = page.use_renderer(|_| {});
+
+            let outside = Point::new(120, 120);
+            let _ = page.handle_events(
+                [Event::Mouse(MouseEvent::ButtonDown(
+                    MouseButton::Left,
+                    Some(outside),
+                ))]
+                .into_iter(),
+            );
+            assert_eq!(count.get(), 0);
+
+            let _ = page.handle_events(
+                [Event::Mouse(MouseEvent::ButtonDown(
+                    MouseButton::Right,
+                    Some(at),
+                ))]
+                .into_iter(),
+            );
+            let _ = page.use_renderer(|_| {});
+
+            let first = at + Point::new(2, row_height(VIEWPORT) / 2);
+            let _ = page.handle_events(
+                [Event::Mouse(MouseEvent::ButtonDown(
+                    MouseButton::Left,
+                    Some(first),
+                ))]
+                .into_iter(),
+            );
+            assert_eq!(count.get(), 1);
+        });
+    }
+}
@@ -1,24 +1,376 @@
-use rsact_ui::{prelude::SelectOption, widget::prelude::*};
-
-// pub struct DropDown<W: WidgetCtx, K: PartialEq> {
-//     id: ElId,
-//     layout: Layout,
-//     list: Memo<Vec<SelectOption<W, K>>>,
-//     selected: Signal<Option<usize>>,
-// }
-
-// impl<W: WidgetCtx, K: PartialEq> DropDown<W, K> {
-//     pub fn new(list: impl IntoMemo<Vec<SelectOption<W, K>>>) -> Self {
-//         Self {
-//             id: ElId::unique(),
-//             layout: Layout::shrink(LayoutKind::Container(ContainerLayout {
-//                 block_model: (),
-//                 horizontal_align: (),
-//                 vertical_align: (),
-//                 content_size: (),
-//             })),
-//             list,
-//             selected,
-//         }
-//     }
-// }
+use crate::menu::{MenuRect, MenuRow, render_menu};
+use core::cell::Cell;
+use rsact_ui::{
+    event::{MouseButton, MouseEvent, PressEvent},
+    prelude::*,
+};
+
+/// Shown in the trigger while no option is selected.
+const PLACEHOLDER: &str = "-";
+
+/// A compact [`Select`]: a trigger showing the label of the selected option,
+/// which opens the list of options above the surrounding content.
+///
+/// A press or a click on the trigger opens the list. Rotation, the wheel or
+/// the pointer move the highlight, a press or a click on a row selects it, and
+/// `Exit` or a click outside of the list closes it keeping the value.
+///
+/// [`Select`]: rsact_ui::widget::select::Select
+///
+/// ```ignore
+/// let mode = create_signal(Mode::Auto);
+/// DropDown::new(
+///     mode,
+///     [
+///         SelectOption::labeled(Mode::Auto, "Auto"),
+///         SelectOption::labeled(Mode::Manual, "Manual"),
+///     ],
+/// )
+/// ```
+#[derive(View)]
+pub struct DropDown<W: WidgetCtx, K: PartialEq + Clone + 'static> {
+    layout: Layout,
+    /// The trigger's label, moved into the arena on build.
+    label: Option<El<W>>,
+    selected: Signal<Option<usize>>,
+    options: Vec<SelectOption<W, K>>,
+    /// Row highlighted in the open list.
+    highlighted: usize,
+    /// Where the open list was last drawn.
+    menu: Cell<Option<MenuRect>>,
+    style: WidgetStyleFn<SelectStyle<W::Color>>,
+}
+
+impl<W, K> DropDown<W, K>
+where
+    W: WidgetCtx + 'static,
+    K: PartialEq + Clone + 'static,
+{
+    pub fn new(
+        selected: impl IntoMaybeSignal<K>,
+        options: impl IntoIterator<Item = SelectOption<W, K>>,
+    ) -> Self {
+        let options = options.into_iter().collect::<Vec<_>>();
+        let mut selected = selected.maybe_signal();
+
+        let index = create_signal(selected.with(|selected| {
+            options.iter().position(|option| option.key() == selected)
+        }));
+
+        // Same as `Select`: only push the choice back into a genuine signal.
+        if selected.as_signal().is_some() {
+            let keys = options
+                .iter()
+                .map(|option| option.key().clone())
+                .collect::<Vec<_>>();
+            selected.setter(index.maybe_reactive(), move |selected, index| {
+                if let Some(key) = index.and_then(|index| keys.get(index)) {
+                    *selected = key.clone();
+                }
+            });
+        }
+
+        let labels = options
+            .iter()
+            .map(|option| String::from(option.label()))
+            .collect::<Vec<_>>();
+        let label = Label::new(index.map(move |index| {
+            String::from(
+                index
+                    .and_then(|index| labels.get(index))
+                    .map_or(PLACEHOLDER, String::as_str),
+            )
+        }))
+        .into_el();
+
+        let layout = Layout::shrink(LayoutKind::Container(
+            ContainerLayout::base(label.layout())
+                .block_model(BlockModel::zero().border_width(1).padding(2u32)),
+        ));
+
+        Self {
+            layout,
+            label: Some(label),
+            selected: index,
+            options,
+            highlighted: 0,
+            menu: Cell::new(None),
+            style: None,
+        }
+    }
+
+    pub fn style(mut self, style: impl StyleFn<SelectStyle<W::Color>>) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+
+    fn open(&mut self, ctx: &mut EventCtx<'_, W>) {
+        if self.options.is_empty() {
+            return;
+        }
+
+        self.highlighted = self.selected.get().unwrap_or(0);
+        // Not drawn yet: a click before the first overlay frame closes it.
+        self.menu.set(None);
+        ctx.open_overlay();
+    }
+
+    fn choose(&mut self, index: usize, ctx: &mut EventCtx<'_, W>) {
+        if self.selected.get() != Some(index) {
+            self.selected.set(Some(index));
+        }
+        ctx.close_overlay();
+    }
+
+    fn on_list_event(&mut self, mut ctx: EventCtx<'_, W>) -> EventResponse {
+        let row_at = |point: Option<Point>| {
+            self.menu.get().zip(point).and_then(|(menu, point)| {
+                menu.row_at(point).filter(|row| *row < self.options.len())
+            })
+        };
+
+        match ctx.event {
+            Event::Exit => ctx.close_overlay(),
+            Event::Press(PressEvent::Press) => {},
+            Event::Press(PressEvent::Release) => {
+                self.choose(self.highlighted, &mut ctx)
+            },
+            &Event::Mouse(MouseEvent::MouseMove(point)) => {
+                if let Some(row) = row_at(Some(point)) {
+                    self.highlighted = row;
+                }
+            },
+            Event::Mouse(MouseEvent::ButtonDown(MouseButton::Left, _)) => {
+                match row_at(ctx.cursor_pos()) {
+                    Some(row) => self.choose(row, &mut ctx),
+                    None => ctx.close_overlay(),
+                }
+            },
+            Event::Mouse(MouseEvent::ButtonDown(..))
+            | Event::Mouse(MouseEvent::ButtonUp(..)) => {},
+            event => {
+                let Some(delta) = event.interpret_as_rotation() else {
+                    return ctx.ignore();
+                };
+                self.highlighted = (self.highlighted as i64 + delta as i64)
+                    .clamp(0, self.options.len() as i64 - 1)
+                    as usize;
+            },
+        }
+
+        ctx.capture()
+    }
+}
+
+impl<W, K> LayoutWidget<W> for DropDown<W, K>
+where
+    W: WidgetCtx + 'static,
+    K: PartialEq + Clone + 'static,
+{
+    fn layout_mut(&mut self) -> &mut Layout {
+        &mut self.layout
+    }
+}
+impl<W, K> SizedWidget<W> for DropDown<W, K>
+where
+    W: WidgetCtx + 'static,
+    K: PartialEq + Clone + 'static,
+{
+}
+impl<W, K> BlockModelWidget<W> for DropDown<W, K>
+where
+    W: WidgetCtx + 'static,
+    K: PartialEq + Clone + 'static,
+{
+}
+impl<W, K> FontSettingWidget<W> for DropDown<W, K>
+where
+    W: WidgetCtx + 'static,
+    K: PartialEq + Clone + 'static,
+{
+}
+
+impl<W, K> Widget<W> for DropDown<W, K>
+where
+    W: WidgetCtx + 'static,
+    K: PartialEq + Clone + 'static,
+{
+    fn flags(&self) -> WidgetFlags {
+        WidgetFlags::default().focusable().clickable().hoverable()
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "DropDown"
+    }
+
+    fn build(&mut self, mut ctx: BuildCtx<W>) {
+        if let Some(mut label) = self.label.take() {
+            ctx.set_single_child(&mut label);
+        }
+    }
+
+    fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    fn render(&self, mut ctx: RenderCtx<'_, W>) -> RenderResult {
+        ctx.render_self(|mut ctx| {
+            let style = ctx.get_style(self.style.as_deref());
+
+            Block::from_layout_style(
+                ctx.layout.outer,
+                self.layout.with(|layout| layout.block_model()),
+                style.container,
+            )
+            .render(ctx.renderer)?;
+
+            ctx.render_focus_outline(ctx.id)
+        })
+    }
+
+    fn render_overlay(
+        &self,
+        mut ctx: RenderCtx<'_, W, CtxReady>,
+    ) -> RenderResult {
+        let style = ctx.get_style(self.style.as_deref());
+        let trigger = ctx.layout.outer;
+        let below =
+            trigger.top_left + Point::new(0, trigger.size.height as i32);
+
+        let menu = render_menu(
+            &mut ctx,
+            below,
+            trigger.size.width,
+            self.options.iter().map(|option| MenuRow {
+                label: option.label(),
+                submenu: false,
+            }),
+            Some(self.highlighted),
+            style,
+        )?;
+        self.menu.set(Some(menu));
+
+        Ok(())
+    }
+
+    fn on_event(&mut self, mut ctx: EventCtx<'_, W>) -> EventResponse {
+        if ctx.owns_overlay() {
+            return self.on_list_event(ctx);
+        }
+
+        ctx.handle()?;
+        ctx.handle_click(|ctx| {
+            self.open(ctx);
+            ctx.capture()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DropDown;
+    use crate::menu::tests::row_height;
+    use alloc::vec::Vec;
+    use rsact_reactive::prelude::*;
+    use rsact_ui::{
+        event::{Event, MouseButton, MouseEvent, MoveDir, PressEvent},
+        prelude::*,
+        test_support::{NullWtf, focused_page},
+    };
+
+    const VIEWPORT: Size = Size::new_equal(128);
+
+    fn options() -> Vec<SelectOption<NullWtf, u32>> {
+        (1..=3).map(SelectOption::new).collect()
+    }
+
+    fn click(point: Point) -> [Event; 2] {
+        [
+            Event::Mouse(MouseEvent::ButtonDown(
+                MouseButton::Left,
+                Some(point),
+            )),
+            Event::Mouse(MouseEvent::ButtonUp(MouseButton::Left, Some(point))),
+        ]
+    }
+
+    fn press() -> [Event; 2] {
+        [Event::Press(PressEvent::Press), Event::Press(PressEvent::Release)]
+    }
+
+    #[test]
+    fn encoder_opens_highlights_and_selects() {
+        with_new_runtime(|_| {
+            let selected = create_signal(1u32);
+            let mut page =
+                focused_page(DropDown::new(selected, options()), VIEWPORT);
+
+            let _ = page.handle_events(press().into_iter());
+            assert_eq!(selected.get(), 1, "press only opens the list");
+
+            let _ = page.handle_events(
+                [
+                    Event::move_1(MoveDir::Right),
+                    Event::move_1(MoveDir::Right),
+                    Event::move_1(MoveDir::Right),
+                ]
+                .into_iter(),
+            );
+            let _ = page.handle_events(press().into_iter());
+            assert_eq!(selected.get(), 3, "highlight is clamped to the list");
+        });
+    }
+
+    #[test]
+    fn exit_and_outside_click_dismiss_keeping_value() {
+        with_new_runtime(|_| {
+            let selected = create_signal(2u32);
+            let mut page =
+                focused_page(DropDown::new(selected, options()), VIEWPORT);
+
+            let _ = page.handle_events(press().into_iter());
+            let _ = page.handle_events(
+                [Event::move_1(MoveDir::Right), Event::Exit].into_iter(),
+            );
+            assert_eq!(selected.get(), 2);
+
+            let _ = page.handle_events(press().into_iter());
+            let _ = page.use_renderer(|_| {});
+            let _ = page.handle_events(
+                [Event::move_1(MoveDir::Left)]
+                    .into_iter()
+                    .chain(click(Point::new(120, 120))),
+            );
+            assert_eq!(selected.get(), 2);
+
+            // Closed again: the next press reopens instead of selecting.
+            let _ = page.handle_events(
+                press()
+                    .into_iter()
+                    .chain([Event::move_1(MoveDir::Right)])
+                    .chain(press()),
+            );
+            assert_eq!(selected.get(), 3);
+        });
+    }
+
+    #[test]
+    fn click_on_a_row_selects_it() {
+        with_new_runtime(|_| {
+            let selected = create_signal(1u32);
+            let mut page =
+                focused_page(DropDown::new(selected, options()), VIEWPORT);
+
+            let _ = page.handle_events(click(Point::new(2, 2)).into_iter());
+            // The list is hit-tested where it was drawn.
+            let _ = page.use_renderer(|_| {});
+
+            // The trigger is a row with a border: 1 + padding 2 on each side,
+            // one pixel taller than a list row.
+            let row = row_height(VIEWPORT);
+            let third = Point::new(2, (row + 2) + row * 2 + row / 2);
+            let _ = page.handle_events(click(third).into_iter());
+            assert_eq!(selected.get(), 3);
+        });
+    }
+}
@@ -1,3 +1,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+pub mod context_menu;
 pub mod drop_down;
+mod menu;
+
+pub mod prelude {
+    pub use crate::{context_menu::*, drop_down::*};
+}
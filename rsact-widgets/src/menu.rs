@@ -0,0 +1,219 @@
+//! Menu lists drawn in the page overlay, shared by [`DropDown`] and
+//! [`ContextMenu`].
+//!
+//! A list has no elements of its own: it is measured and drawn in
+//! `render_overlay`, and the rect it was drawn at is kept to hit-test the
+//! pointer against on the following events.
+//!
+//! [`DropDown`]: crate::drop_down::DropDown
+//! [`ContextMenu`]: crate::context_menu::ContextMenu
+
+use rsact_ui::{
+    font::TextOverflow, widget::prelude::*, widget::select::SelectStyle,
+};
+
+/// Space between a row's edges and its label.
+const ROW_PADDING: u32 = 2;
+
+/// Marker drawn at the right of a row that opens a submenu.
+const SUBMENU_MARK: &str = ">";
+
+/// Where an open list was last drawn.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct MenuRect {
+    pub bounds: Rect,
+    pub row_height: u32,
+}
+
+impl MenuRect {
+    /// The row under `point`, if it is inside the list.
+    pub fn row_at(&self, point: Point) -> Option<usize> {
+        if !self.bounds.contains(point) || self.row_height == 0 {
+            return None;
+        }
+
+        Some(
+            ((point.y - self.bounds.top_left.y) as u32 / self.row_height)
+                as usize,
+        )
+    }
+
+    pub fn row(&self, index: usize) -> Rect {
+        Rect::new(
+            self.bounds.top_left
+                + Point::new(0, (index as u32 * self.row_height) as i32),
+            Size::new(self.bounds.size.width, self.row_height),
+        )
+    }
+
+    /// Where a submenu of the `index`th row opens: next to the row.
+    pub fn submenu_origin(&self, index: usize) -> Point {
+        self.row(index).top_left + Point::new(self.bounds.size.width as i32, 0)
+    }
+}
+
+/// One row of a list.
+#[derive(Clone, Copy)]
+pub(crate) struct MenuRow<'a> {
+    pub label: &'a str,
+    /// Whether the row opens a submenu.
+    pub submenu: bool,
+}
+
+/// Draws `rows` as a list at `origin`, at least `min_width` wide, moved as
+/// little as needed to fit into the viewport, with the `highlighted` row
+/// marked. Returns where the list was drawn.
+pub(crate) fn render_menu<'a, W: WidgetCtx>(
+    ctx: &mut RenderCtx<'_, W, CtxReady>,
+    origin: Point,
+    min_width: u32,
+    rows: impl Iterator<Item = MenuRow<'a>> + Clone,
+    highlighted: Option<usize>,
+    style: SelectStyle<W::Color>,
+) -> Result<MenuRect, ()> {
+    let props = ctx.visual.font_props;
+    let font = props.font();
+    let props = props.resolve(ctx.shared.viewport.get());
+
+    let measure = |fonts: &FontCtx, text: &str| {
+        fonts.measure_text(font, text, props, TextOverflow::Clip)
+    };
+    let (label_width, line_height, mark_width) = ctx
+        .shared
+        .fonts
+        .try_with(|fonts| {
+            let mark = measure(fonts, SUBMENU_MARK);
+            rows.clone().fold(
+                (0, mark.line_height, mark.max_content_width),
+                |(width, height, mark_width), row| {
+                    let label = measure(fonts, row.label);
+                    let width = width.max(
+                        label.max_content_width
+                            + if row.submenu {
+                                mark_width + ROW_PADDING * 2
+                            } else {
+                                0
+                            },
+                    );
+                    (width, height.max(label.line_height), mark_width)
+                },
+            )
+        })
+        .unwrap_or_else(|| {
+            log::error!("menu measure skipped: font provider was disposed");
+            (0, 0, 0)
+        });
+
+    let row_height = line_height + ROW_PADDING * 2;
+    let size = Size::new(
+        min_width.max(label_width + ROW_PADDING * 2),
+        row_height * rows.clone().count() as u32,
+    );
+    let viewport = ctx.shared.viewport.get();
+    let top_left = Point::new(
+        origin
+            .x
+            .min(viewport.width as i32 - size.width as i32)
+            .max(0),
+        origin
+            .y
+            .min(viewport.height as i32 - size.height as i32)
+            .max(0),
+    );
+    let menu = MenuRect { bounds: Rect::new(top_left, size), row_height };
+
+    // The list covers what is under it, even with a transparent container.
+    ctx.renderer
+        .fill_solid(menu.bounds, W::default_background())?;
+    Block::from_layout_style(
+        menu.bounds,
+        BlockModel::zero().border_width(1),
+        style.container,
+    )
+    .render(ctx.renderer)?;
+
+    let text_color =
+        style.text_color.get().unwrap_or_else(W::default_foreground);
+    let selected_text_color = style
+        .selected_text_color
+        .get()
+        .unwrap_or_else(W::default_foreground);
+
+    for (index, row) in rows.enumerate() {
+        let bounds = menu.row(index);
+        let is_highlighted = highlighted == Some(index);
+
+        if is_highlighted {
+            Block::from_layout_style(
+                bounds,
+                BlockModel::zero().border_width(1),
+                style.selected,
+            )
+            .render(ctx.renderer)?;
+        }
+
+        let color =
+            if is_highlighted { selected_text_color } else { text_color };
+
+        let text = Rect::new(
+            bounds.top_left
+                + Point::new(ROW_PADDING as i32, ROW_PADDING as i32),
+            Size::new(
+                bounds.size.width.saturating_sub(ROW_PADDING * 2),
+                line_height,
+            ),
+        );
+        ctx.render_font(font, row.label, props, text, color)?;
+
+        if row.submenu {
+            let mark = Rect::new(
+                text.top_left
+                    + Point::new(
+                        text.size.width.saturating_sub(mark_width) as i32,
+                        0,
+                    ),
+                Size::new(mark_width, line_height),
+            );
+            ctx.render_font(font, SUBMENU_MARK, props, mark, color)?;
+        }
+    }
+
+    Ok(menu)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{MenuRect, ROW_PADDING};
+    use rsact_ui::{
+        font::{Font, FontCtx, FontProps, TextOverflow},
+        prelude::*,
+    };
+
+    /// Height of a row drawn with the default font on a `viewport`.
+    pub(crate) fn row_height(viewport: Size) -> i32 {
+        let line = FontCtx::new()
+            .measure_text(
+                Font::Auto,
+                "1",
+                FontProps::default().resolve(viewport),
+                TextOverflow::Clip,
+            )
+            .line_height;
+        (line + ROW_PADDING * 2) as i32
+    }
+
+    #[test]
+    fn rows_are_hit_by_height() {
+        let menu = MenuRect {
+            bounds: Rect::new(Point::new(10, 20), Size::new(30, 24)),
+            row_height: 8,
+        };
+
+        assert_eq!(menu.row_at(Point::new(10, 20)), Some(0));
+        assert_eq!(menu.row_at(Point::new(39, 35)), Some(1));
+        assert_eq!(menu.row_at(Point::new(20, 43)), Some(2));
+        assert_eq!(menu.row_at(Point::new(20, 44)), None);
+        assert_eq!(menu.row_at(Point::new(40, 30)), None);
+        assert_eq!(menu.submenu_origin(1), Point::new(40, 28));
+    }
+}
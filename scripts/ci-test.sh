@@ -10,7 +10,18 @@ echo "== rsact-reactive =="
 # The 2 known-fails are owned acceptance tests (static_wrapper → WS4,
 # observe_recreates_disposed_child_observer → WS2). Skip them so the job is
 # green-by-baseline and red on any NEW failure; WS2/WS4 drop the skips.
-cargo test -p rsact-reactive --features std --lib -- --test-threads=1 \
+# `async` gates the resource and executor tests.
+cargo test -p rsact-reactive --features std,serde,debug-info,async --lib -- --test-threads=1 \
+    --skip static_wrapper \
+    --skip observe_recreates_disposed_child_observer
+
+echo "== rsact-reactive (fixed-capacity) =="
+# The whole suite again on the fixed-size storage. The wide fan-out and deep
+# chain tests need more than the default 64 nodes of 8 edges, so raise them
+# here; the default-capacity checks run in the metrics-probe job below.
+RSACT_NODES=256 RSACT_FANOUT=64 \
+    cargo test -p rsact-reactive --features std,fixed-capacity,async --lib -- \
+    --test-threads=1 \
     --skip static_wrapper \
     --skip observe_recreates_disposed_child_observer
 
@@ -18,6 +29,12 @@ echo "== rsact-ui (lib) =="
 cargo test -p rsact-ui --lib --features "std,embedded-graphics" -- \
     --test-threads=1
 
+echo "== rsact-widgets =="
+cargo test -p rsact-widgets --features std -- --test-threads=1
+
+echo "== rsact-encoder =="
+cargo test -p rsact-encoder --features std -- --test-threads=1
+
 echo "== rsact-render =="
 cargo test -p rsact-render --features "std,embedded-graphics,tiny-skia" -- \
     --test-threads=1
@@ -25,4 +42,8 @@ cargo test -p rsact-render --features "std,embedded-graphics,tiny-skia" -- \
 echo "== metrics-probe (layout-counters) =="
 cargo test -p metrics-probe --features layout-counters -- --test-threads=1
 
+echo "== metrics-probe (fixed-capacity) =="
+# The canonical pages fit the default fixed-size storage.
+cargo test -p metrics-probe --features fixed-capacity -- --test-threads=1
+
 echo "all test suites green"
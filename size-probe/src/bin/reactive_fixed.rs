@@ -0,0 +1,57 @@
+//! Reactive-only size probe on the `fixed-capacity` storage: the same engine
+//! workload as the `reactive` probe, so the two diff to the cost of the
+//! fixed-size node slab and edge lists. It still needs a heap: value cells and
+//! observer closures are `Rc`s, and the node storage is allocated once when the
+//! runtime is created. An allocator-free probe waits on `Rc`-free storage.
+
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+use core::hint::black_box;
+use cortex_m_rt::entry;
+use rsact_reactive::{
+    effect::create_effect, memo::create_memo, prelude::*,
+    signal::create_signal,
+};
+
+#[entry]
+fn main() -> ! {
+    size_probe::init_heap();
+
+    // Within the default capacity: 20 nodes of at most 8 edges.
+    let sigs: Vec<Signal<i32>> = (0..8).map(|_| create_signal(0i32)).collect();
+
+    let memo_sigs = sigs.clone();
+    let m = create_memo(move || {
+        memo_sigs.iter().map(|s| s.get()).sum::<i32>()
+    });
+    create_effect(move |_: Option<()>| {
+        black_box(m.get());
+    });
+
+    let render_sigs = sigs.clone();
+    let outer = create_probe();
+    let children: Vec<Probe> = (0..8).map(|_| create_probe()).collect();
+    let render = move || {
+        outer.poll(false, || {
+            for (i, s) in render_sigs.iter().enumerate() {
+                let s = *s;
+                children[i].poll(false, move || {
+                    black_box(s.get());
+                });
+            }
+        });
+    };
+    render();
+
+    let mut driver = sigs[0];
+    driver.set(1);
+    render();
+
+    black_box(vec![driver]);
+    black_box(&sigs);
+    loop {}
+}